@@ -0,0 +1,208 @@
+/// Define the discovery error of a backend. The errors of MySQL and Postgres only differ by their
+/// names, so that the location of the error in the schema and the value it is about are kept here
+macro_rules! discovery_error {
+    (
+        $(#[$meta:meta])*
+        pub enum $name:ident {
+            $(#[$row_meta:meta])*
+            UnexpectedRow,
+            $(#[$value_meta:meta])*
+            UnknownValue,
+        }
+    ) => {
+        use crate::{sqlx_types::SqlxError, ExecutorError, SchemaRow, SchemaRowError};
+
+        /// This type simplifies error handling
+        pub type DiscoveryResult<T> = Result<T, $name>;
+
+        $(#[$meta])*
+        /// `table` and `column` locate the error in the schema being discovered, when known
+        #[derive(Debug)]
+        pub enum $name {
+            /// An error of the executor running the queries, e.g. an [SqlxError]
+            ExecutorError {
+                table: Option<String>,
+                error: ExecutorError,
+            },
+            $(#[$row_meta])*
+            UnexpectedRow {
+                table: Option<String>,
+                column: Option<String>,
+                error: SchemaRowError,
+            },
+            $(#[$value_meta])*
+            UnknownValue {
+                table: Option<String>,
+                column: Option<String>,
+                /// What the value is, e.g. `"index type"` or `"constraint type"`
+                kind: &'static str,
+                value: String,
+            },
+            /// A string returned by the database is not valid UTF-8
+            InvalidUtf8 {
+                table: Option<String>,
+                column: Option<String>,
+                bytes: Vec<u8>,
+            },
+        }
+
+        impl $name {
+            /// An error reading `row`, located by the names of the table and column it holds,
+            /// if any
+            pub(crate) fn from_row(
+                error: SchemaRowError,
+                row: &SchemaRow,
+                table_index: Option<usize>,
+                column_index: Option<usize>,
+            ) -> Self {
+                let table = table_index.and_then(|index| row.try_get(index).ok());
+                let column = column_index.and_then(|index| row.try_get(index).ok());
+                match error {
+                    SchemaRowError::InvalidUtf8 { bytes, .. } => Self::InvalidUtf8 {
+                        table,
+                        column,
+                        bytes,
+                    },
+                    error => Self::UnexpectedRow {
+                        table,
+                        column,
+                        error,
+                    },
+                }
+            }
+
+            /// An unknown value found while parsing
+            pub(crate) fn unknown_value(kind: &'static str, value: &str) -> Self {
+                Self::UnknownValue {
+                    table: None,
+                    column: None,
+                    kind,
+                    value: value.to_owned(),
+                }
+            }
+
+            /// Locate the error in `table`, unless it is already located
+            pub fn with_table(mut self, name: &str) -> Self {
+                match &mut self {
+                    Self::ExecutorError { table, .. }
+                    | Self::UnexpectedRow { table, .. }
+                    | Self::UnknownValue { table, .. }
+                    | Self::InvalidUtf8 { table, .. } => {
+                        table.get_or_insert_with(|| name.to_owned());
+                    }
+                }
+                self
+            }
+
+            /// Locate the error in `column`, unless it is already located
+            pub fn with_column(mut self, name: &str) -> Self {
+                match &mut self {
+                    Self::ExecutorError { .. } => (),
+                    Self::UnexpectedRow { column, .. }
+                    | Self::UnknownValue { column, .. }
+                    | Self::InvalidUtf8 { column, .. } => {
+                        column.get_or_insert_with(|| name.to_owned());
+                    }
+                }
+                self
+            }
+
+            /// The table where the error was encountered
+            pub fn table(&self) -> Option<&str> {
+                match self {
+                    Self::ExecutorError { table, .. }
+                    | Self::UnexpectedRow { table, .. }
+                    | Self::UnknownValue { table, .. }
+                    | Self::InvalidUtf8 { table, .. } => table.as_deref(),
+                }
+            }
+
+            /// The column where the error was encountered
+            pub fn column(&self) -> Option<&str> {
+                match self {
+                    Self::ExecutorError { .. } => None,
+                    Self::UnexpectedRow { column, .. }
+                    | Self::UnknownValue { column, .. }
+                    | Self::InvalidUtf8 { column, .. } => column.as_deref(),
+                }
+            }
+
+            /// The value the error is about, as returned by the database
+            pub fn value(&self) -> Option<String> {
+                match self {
+                    Self::ExecutorError { .. } => None,
+                    Self::UnexpectedRow { error, .. } => match error {
+                        SchemaRowError::IndexOutOfBounds { .. } => None,
+                        SchemaRowError::UnexpectedValue { value, .. } => {
+                            Some(format!("{:?}", value))
+                        }
+                        SchemaRowError::InvalidUtf8 { bytes, .. } => {
+                            Some(String::from_utf8_lossy(bytes).into_owned())
+                        }
+                    },
+                    Self::UnknownValue { value, .. } => Some(value.clone()),
+                    Self::InvalidUtf8 { bytes, .. } => {
+                        Some(String::from_utf8_lossy(bytes).into_owned())
+                    }
+                }
+            }
+        }
+
+        #[cfg(feature = "discovery")]
+        impl From<$name> for crate::DiscoveryWarning {
+            fn from(error: $name) -> Self {
+                crate::DiscoveryWarning {
+                    table: error.table().map(Into::into),
+                    column: error.column().map(Into::into),
+                    value: error.value(),
+                    reason: error.to_string(),
+                }
+            }
+        }
+
+        #[cfg(feature = "discovery")]
+        impl crate::warning::Recoverable for $name {
+            /// The errors of the executor are not recoverable, the others are about a single row
+            fn is_recoverable(&self) -> bool {
+                !matches!(self, Self::ExecutorError { .. })
+            }
+        }
+
+        impl From<ExecutorError> for $name {
+            fn from(error: ExecutorError) -> Self {
+                Self::ExecutorError { table: None, error }
+            }
+        }
+
+        impl From<SqlxError> for $name {
+            fn from(error: SqlxError) -> Self {
+                ExecutorError::from(error).into()
+            }
+        }
+
+        impl std::error::Error for $name {}
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                match self {
+                    Self::ExecutorError { error, .. } => write!(f, "Executor Error: {}", error)?,
+                    Self::UnexpectedRow { error, .. } => write!(f, "{}", error)?,
+                    Self::UnknownValue { kind, value, .. } => {
+                        write!(f, "Unknown {}: {:?}", kind, value)?
+                    }
+                    Self::InvalidUtf8 { bytes, .. } => {
+                        write!(f, "Invalid UTF-8: {:?}", String::from_utf8_lossy(bytes))?
+                    }
+                }
+                match (self.table(), self.column()) {
+                    (Some(table), Some(column)) => write!(f, " in column `{}`.`{}`", table, column),
+                    (Some(table), None) => write!(f, " in table `{}`", table),
+                    (None, Some(column)) => write!(f, " in column `{}`", column),
+                    (None, None) => Ok(()),
+                }
+            }
+        }
+    };
+}
+
+pub(crate) use discovery_error;
@@ -0,0 +1,60 @@
+use std::error::Error;
+use std::fmt;
+
+use crate::sqlx_types::SqlxError;
+
+/// An error running a query of the discovery, like a lost connection.
+/// The executors of sqlx report an [SqlxError], any other executor can report its own error
+#[derive(Debug)]
+pub struct ExecutorError(Box<dyn Error + Send + Sync>);
+
+impl ExecutorError {
+    /// An error reported by an executor, e.g. `ExecutorError::new("connection closed")`
+    pub fn new<E>(error: E) -> Self
+    where
+        E: Into<Box<dyn Error + Send + Sync>>,
+    {
+        Self(error.into())
+    }
+
+    /// The error reported by the executor
+    pub fn get_ref(&self) -> &(dyn Error + Send + Sync + 'static) {
+        &*self.0
+    }
+
+    pub fn into_inner(self) -> Box<dyn Error + Send + Sync> {
+        self.0
+    }
+}
+
+impl From<SqlxError> for ExecutorError {
+    fn from(error: SqlxError) -> Self {
+        Self::new(error)
+    }
+}
+
+impl Error for ExecutorError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.0.source()
+    }
+}
+
+impl fmt::Display for ExecutorError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_executor_error() {
+        let error = ExecutorError::new("connection closed");
+        assert_eq!(error.to_string(), "connection closed");
+
+        let error = ExecutorError::from(SqlxError::RowNotFound);
+        assert!(error.get_ref().is::<SqlxError>());
+    }
+}
@@ -0,0 +1,334 @@
+use sea_query::{Condition, Expr, IntoColumnRef, LikeExpr, SelectStatement};
+
+/// Selects the tables to discover by name, glob pattern or regular expression.
+/// By default every table is discovered
+///
+/// ```
+/// use sea_schema::TableFilter;
+///
+/// let filter = TableFilter::new()
+///     .include(["cake", "fruit", "_sqlx_migrations"])
+///     .include_glob(["archive_*"])
+///     .exclude(["_sqlx_migrations"])
+///     .exclude_glob(["archive_199?"]);
+///
+/// assert!(filter.is_included("cake"));
+/// assert!(filter.is_included("archive_2024"));
+/// assert!(!filter.is_included("archive_1999"));
+/// assert!(!filter.is_included("_sqlx_migrations"));
+/// assert!(!filter.is_included("filling"));
+/// ```
+///
+/// The names and glob patterns to include are pushed into the query listing the tables,
+/// so that the database only returns the tables wanted. The rest is checked on every table listed
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct TableFilter {
+    include: Option<Vec<TablePattern>>,
+    exclude: Vec<TablePattern>,
+}
+
+/// Selects the schemas to discover by name, glob pattern or regular expression,
+/// the same way a [TableFilter] selects the tables of a schema
+///
+/// ```
+/// use sea_schema::SchemaFilter;
+///
+/// let filter = SchemaFilter::new()
+///     .exclude(["information_schema"])
+///     .exclude_glob(["pg_*"]);
+///
+/// assert!(filter.is_included("public"));
+/// assert!(!filter.is_included("pg_catalog"));
+/// ```
+pub type SchemaFilter = TableFilter;
+
+#[derive(Debug, Clone)]
+enum TablePattern {
+    Name(String),
+    Glob(String),
+    #[cfg(feature = "with-regex")]
+    Regex(regex::Regex),
+}
+
+/// The escape character of the `LIKE` patterns translated from globs
+const LIKE_ESCAPE: char = '!';
+
+impl TableFilter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only discover these tables. Can be called multiple times
+    pub fn include<I, T>(self, tables: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<String>,
+    {
+        self.include_patterns(tables.into_iter().map(|t| TablePattern::Name(t.into())))
+    }
+
+    /// Only discover the tables matching these glob patterns, where `*` matches any sequence of
+    /// characters and `?` any single character. Can be called multiple times
+    pub fn include_glob<I, T>(self, patterns: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<String>,
+    {
+        self.include_patterns(patterns.into_iter().map(|p| TablePattern::Glob(p.into())))
+    }
+
+    #[cfg(feature = "with-regex")]
+    /// Only discover the tables matching these regular expressions. Can be called multiple times
+    pub fn include_regex<I>(self, regexes: I) -> Self
+    where
+        I: IntoIterator<Item = regex::Regex>,
+    {
+        self.include_patterns(regexes.into_iter().map(TablePattern::Regex))
+    }
+
+    /// Do not discover these tables, even if included. Can be called multiple times
+    pub fn exclude<I, T>(mut self, tables: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<String>,
+    {
+        self.exclude
+            .extend(tables.into_iter().map(|t| TablePattern::Name(t.into())));
+        self
+    }
+
+    /// Do not discover the tables matching these glob patterns, even if included.
+    /// Can be called multiple times
+    pub fn exclude_glob<I, T>(mut self, patterns: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<String>,
+    {
+        self.exclude
+            .extend(patterns.into_iter().map(|p| TablePattern::Glob(p.into())));
+        self
+    }
+
+    #[cfg(feature = "with-regex")]
+    /// Do not discover the tables matching these regular expressions, even if included.
+    /// Can be called multiple times
+    pub fn exclude_regex<I>(mut self, regexes: I) -> Self
+    where
+        I: IntoIterator<Item = regex::Regex>,
+    {
+        self.exclude
+            .extend(regexes.into_iter().map(TablePattern::Regex));
+        self
+    }
+
+    fn include_patterns<I>(mut self, patterns: I) -> Self
+    where
+        I: IntoIterator<Item = TablePattern>,
+    {
+        self.include.get_or_insert_with(Vec::new).extend(patterns);
+        self
+    }
+
+    /// Whether the table passes the filter
+    pub fn is_included(&self, table: &str) -> bool {
+        let included = match &self.include {
+            Some(include) => include.iter().any(|pattern| pattern.matches(table)),
+            None => true,
+        };
+        included && !self.exclude.iter().any(|pattern| pattern.matches(table))
+    }
+
+    /// Restrict a query listing tables to those that may pass the filter.
+    /// The tables listed still have to be checked with [TableFilter::is_included]
+    #[allow(dead_code)]
+    pub(crate) fn apply<C>(&self, select: &mut SelectStatement, column: C)
+    where
+        C: IntoColumnRef + Clone,
+    {
+        if let Some(include) = &self.include {
+            let mut names = Vec::new();
+            let mut condition = Condition::any();
+            for pattern in include {
+                match pattern {
+                    TablePattern::Name(name) => names.push(name.clone()),
+                    TablePattern::Glob(glob) => {
+                        condition = condition.add(
+                            Expr::col(column.clone())
+                                .like(LikeExpr::new(glob_to_like(glob)).escape(LIKE_ESCAPE)),
+                        );
+                    }
+                    // A regular expression can't be written in a way every backend understands
+                    #[cfg(feature = "with-regex")]
+                    TablePattern::Regex(_) => return self.apply_exclude(select, column),
+                }
+            }
+            if !names.is_empty() {
+                condition = condition.add(Expr::col(column.clone()).is_in(names));
+            }
+            select.cond_where(condition);
+        }
+        self.apply_exclude(select, column);
+    }
+
+    /// The patterns to exclude are not pushed, as `LIKE` may ignore the case
+    fn apply_exclude<C>(&self, select: &mut SelectStatement, column: C)
+    where
+        C: IntoColumnRef,
+    {
+        let names: Vec<_> = self
+            .exclude
+            .iter()
+            .filter_map(|pattern| match pattern {
+                TablePattern::Name(name) => Some(name.clone()),
+                _ => None,
+            })
+            .collect();
+        if !names.is_empty() {
+            select.and_where(Expr::col(column).is_not_in(names));
+        }
+    }
+}
+
+impl TablePattern {
+    fn matches(&self, table: &str) -> bool {
+        match self {
+            Self::Name(name) => name == table,
+            Self::Glob(glob) => glob_matches(glob, table),
+            #[cfg(feature = "with-regex")]
+            Self::Regex(regex) => regex.is_match(table),
+        }
+    }
+}
+
+impl PartialEq for TablePattern {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Name(a), Self::Name(b)) => a == b,
+            (Self::Glob(a), Self::Glob(b)) => a == b,
+            #[cfg(feature = "with-regex")]
+            (Self::Regex(a), Self::Regex(b)) => a.as_str() == b.as_str(),
+            _ => false,
+        }
+    }
+}
+
+impl Eq for TablePattern {}
+
+fn glob_matches(glob: &str, name: &str) -> bool {
+    let glob: Vec<char> = glob.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+    let (mut g, mut n) = (0, 0);
+    // The position of the last `*` in the glob, and of the name where it stopped matching
+    let mut star = None;
+    while n < name.len() {
+        match glob.get(g) {
+            Some('*') => {
+                star = Some((g, n));
+                g += 1;
+            }
+            Some('?') => {
+                g += 1;
+                n += 1;
+            }
+            Some(c) if *c == name[n] => {
+                g += 1;
+                n += 1;
+            }
+            _ => match star {
+                Some((star_g, star_n)) => {
+                    star = Some((star_g, star_n + 1));
+                    g = star_g + 1;
+                    n = star_n + 1;
+                }
+                None => return false,
+            },
+        }
+    }
+    glob[g..].iter().all(|c| *c == '*')
+}
+
+fn glob_to_like(glob: &str) -> String {
+    let mut like = String::with_capacity(glob.len());
+    for c in glob.chars() {
+        match c {
+            '*' => like.push('%'),
+            '?' => like.push('_'),
+            '%' | '_' | LIKE_ESCAPE => {
+                like.push(LIKE_ESCAPE);
+                like.push(c);
+            }
+            c => like.push(c),
+        }
+    }
+    like
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sea_query::{Alias, Query};
+
+    #[test]
+    fn test_glob() {
+        assert!(glob_matches("archive_*", "archive_2024"));
+        assert!(glob_matches("archive_*", "archive_"));
+        assert!(glob_matches("*_log", "audit_log"));
+        assert!(glob_matches("a*b*c", "aXbYbZc"));
+        assert!(glob_matches("cake?", "cakes"));
+        assert!(!glob_matches("cake?", "cake"));
+        assert!(!glob_matches("archive_*", "archives"));
+        assert!(!glob_matches("a*b", "aXbY"));
+
+        assert_eq!(glob_to_like("archive_*"), "archive!_%");
+        assert_eq!(glob_to_like("100%?!"), "100!%_!!");
+    }
+
+    #[cfg(feature = "with-regex")]
+    #[test]
+    fn test_regex() {
+        let filter = TableFilter::new()
+            .include_regex([regex::Regex::new(r"^archive_\d{4}$").unwrap()])
+            .exclude_regex([regex::Regex::new("^archive_19").unwrap()]);
+
+        assert!(filter.is_included("archive_2024"));
+        assert!(!filter.is_included("archive_1999"));
+        assert!(!filter.is_included("archive_latest"));
+
+        // Only the excluded names are pushed along with a regular expression
+        let mut select = Query::select().column(Alias::new("name")).to_owned();
+        filter
+            .clone()
+            .include(["cake"])
+            .exclude(["fruit"])
+            .apply(&mut select, Alias::new("name"));
+        assert_eq!(
+            select.to_string(sea_query::PostgresQueryBuilder),
+            r#"SELECT "name" WHERE "name" NOT IN ('fruit')"#
+        );
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn test_apply() {
+        let filter = TableFilter::new()
+            .include(["cake"])
+            .include_glob(["archive_*"])
+            .exclude(["fruit"])
+            .exclude_glob(["archive_199?"]);
+        let mut select = Query::select()
+            .column(Alias::new("name"))
+            .from(Alias::new("tables"))
+            .to_owned();
+        filter.apply(&mut select, Alias::new("name"));
+
+        assert_eq!(
+            select.to_string(sea_query::SqliteQueryBuilder),
+            [
+                r#"SELECT "name" FROM "tables""#,
+                r#"WHERE ("name" LIKE 'archive!_%' ESCAPE '!' OR "name" IN ('cake'))"#,
+                r#"AND "name" NOT IN ('fruit')"#,
+            ]
+            .join(" ")
+        );
+    }
+}
@@ -0,0 +1,281 @@
+//! Record the queries of a schema discovery and replay them without a database.
+//!
+//! Wrap an executor in a [FixtureRecorder] to capture every query and its rows into a [Fixture],
+//! which can be saved to a JSON file with the `with-serde` feature. A [FixtureExecutor] then feeds
+//! the rows back to the `SchemaDiscovery` of the same backend, to reproduce a discovery offline.
+
+use futures::future::BoxFuture;
+use sea_query::SelectStatement;
+#[cfg(feature = "with-serde")]
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "with-serde")]
+use std::path::Path;
+use std::sync::Mutex;
+
+use crate::{ExecutorError, SchemaRow};
+
+/// The queries run by a schema discovery and the rows they returned
+#[derive(Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
+pub struct Fixture {
+    pub queries: Vec<FixtureQuery>,
+}
+
+/// A query, as built by the query builder of the backend with its values inlined, and its rows
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
+pub struct FixtureQuery {
+    pub sql: String,
+    pub rows: Vec<SchemaRow>,
+}
+
+/// Runs the queries on an executor and records them into a [Fixture].
+/// Pass it by reference to the `SchemaDiscovery` to take the fixture back afterwards
+pub struct FixtureRecorder<E> {
+    executor: E,
+    fixture: Mutex<Fixture>,
+}
+
+/// Answers the queries from a [Fixture], failing on any query that was not recorded
+#[derive(Debug, Clone)]
+pub struct FixtureExecutor {
+    fixture: Fixture,
+}
+
+impl Fixture {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The rows recorded for a query
+    pub fn rows(&self, sql: &str) -> Option<&[SchemaRow]> {
+        self.queries
+            .iter()
+            .find(|query| query.sql == sql)
+            .map(|query| query.rows.as_slice())
+    }
+
+    /// Record the rows of a query. A query already recorded is kept as is
+    pub fn record(&mut self, sql: String, rows: Vec<SchemaRow>) {
+        if self.rows(&sql).is_none() {
+            self.queries.push(FixtureQuery { sql, rows });
+        }
+    }
+
+    #[cfg(feature = "with-serde")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "with-serde")))]
+    /// Read a fixture from a JSON file, as written by [Fixture::save]
+    pub fn load<P>(path: P) -> std::io::Result<Self>
+    where
+        P: AsRef<Path>,
+    {
+        let file = std::fs::File::open(path)?;
+        Ok(serde_json::from_reader(std::io::BufReader::new(file))?)
+    }
+
+    #[cfg(feature = "with-serde")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "with-serde")))]
+    /// Write the fixture to a JSON file, one query per line
+    pub fn save<P>(&self, path: P) -> std::io::Result<()>
+    where
+        P: AsRef<Path>,
+    {
+        let mut json = String::from("{\"queries\":[\n");
+        for (i, query) in self.queries.iter().enumerate() {
+            if i > 0 {
+                json.push_str(",\n");
+            }
+            json.push_str(&serde_json::to_string(query)?);
+        }
+        json.push_str("\n]}\n");
+        std::fs::write(path, json)
+    }
+}
+
+impl<E> FixtureRecorder<E> {
+    pub fn new(executor: E) -> Self {
+        Self {
+            executor,
+            fixture: Mutex::new(Fixture::new()),
+        }
+    }
+
+    /// The queries recorded so far
+    pub fn fixture(&self) -> Fixture {
+        self.fixture.lock().unwrap().clone()
+    }
+
+    pub fn into_fixture(self) -> Fixture {
+        self.fixture.into_inner().unwrap()
+    }
+
+    async fn record<'a, F>(&'a self, sql: String, fetch: F) -> Result<Vec<SchemaRow>, ExecutorError>
+    where
+        F: std::future::Future<Output = Result<Vec<SchemaRow>, ExecutorError>> + 'a,
+    {
+        let rows = fetch.await?;
+        self.fixture.lock().unwrap().record(sql, rows.clone());
+        Ok(rows)
+    }
+}
+
+impl FixtureExecutor {
+    pub fn new(fixture: Fixture) -> Self {
+        Self { fixture }
+    }
+
+    fn replay(&self, sql: &str) -> BoxFuture<'_, Result<Vec<SchemaRow>, ExecutorError>> {
+        let rows = match self.fixture.rows(sql) {
+            Some(rows) => Ok(rows.to_vec()),
+            None => Err(ExecutorError::new(format!(
+                "The fixture has no rows for the query: {sql}"
+            ))),
+        };
+        Box::pin(async move { rows })
+    }
+}
+
+impl From<Fixture> for FixtureExecutor {
+    fn from(fixture: Fixture) -> Self {
+        Self::new(fixture)
+    }
+}
+
+#[cfg(feature = "mysql")]
+mod mysql {
+    use super::*;
+    use crate::mysql::discovery::SchemaExecutor;
+    use sea_query::MysqlQueryBuilder;
+
+    impl<E> SchemaExecutor for FixtureRecorder<E>
+    where
+        E: SchemaExecutor,
+    {
+        fn fetch_all(
+            &self,
+            select: SelectStatement,
+        ) -> BoxFuture<'_, Result<Vec<SchemaRow>, ExecutorError>> {
+            let sql = select.to_string(MysqlQueryBuilder);
+            Box::pin(self.record(sql, self.executor.fetch_all(select)))
+        }
+    }
+
+    impl SchemaExecutor for FixtureExecutor {
+        fn fetch_all(
+            &self,
+            select: SelectStatement,
+        ) -> BoxFuture<'_, Result<Vec<SchemaRow>, ExecutorError>> {
+            self.replay(&select.to_string(MysqlQueryBuilder))
+        }
+    }
+}
+
+#[cfg(feature = "postgres")]
+mod postgres {
+    use super::*;
+    use crate::postgres::discovery::SchemaExecutor;
+    use sea_query::PostgresQueryBuilder;
+
+    impl<E> SchemaExecutor for FixtureRecorder<E>
+    where
+        E: SchemaExecutor,
+    {
+        fn fetch_all(
+            &self,
+            select: SelectStatement,
+        ) -> BoxFuture<'_, Result<Vec<SchemaRow>, ExecutorError>> {
+            let sql = select.to_string(PostgresQueryBuilder);
+            Box::pin(self.record(sql, self.executor.fetch_all(select)))
+        }
+    }
+
+    impl SchemaExecutor for FixtureExecutor {
+        fn fetch_all(
+            &self,
+            select: SelectStatement,
+        ) -> BoxFuture<'_, Result<Vec<SchemaRow>, ExecutorError>> {
+            self.replay(&select.to_string(PostgresQueryBuilder))
+        }
+    }
+}
+
+#[cfg(feature = "sqlite")]
+mod sqlite {
+    use super::*;
+    use crate::sqlite::discovery::SchemaExecutor;
+    use sea_query::SqliteQueryBuilder;
+
+    impl<E> SchemaExecutor for FixtureRecorder<E>
+    where
+        E: SchemaExecutor,
+    {
+        fn fetch_all(
+            &self,
+            select: SelectStatement,
+        ) -> BoxFuture<'_, Result<Vec<SchemaRow>, ExecutorError>> {
+            let sql = select.to_string(SqliteQueryBuilder);
+            Box::pin(self.record(sql, self.executor.fetch_all(select)))
+        }
+
+        fn fetch_all_raw(
+            &self,
+            sql: String,
+        ) -> BoxFuture<'_, Result<Vec<SchemaRow>, ExecutorError>> {
+            Box::pin(self.record(sql.clone(), self.executor.fetch_all_raw(sql)))
+        }
+    }
+
+    impl SchemaExecutor for FixtureExecutor {
+        fn fetch_all(
+            &self,
+            select: SelectStatement,
+        ) -> BoxFuture<'_, Result<Vec<SchemaRow>, ExecutorError>> {
+            self.replay(&select.to_string(SqliteQueryBuilder))
+        }
+
+        fn fetch_all_raw(
+            &self,
+            sql: String,
+        ) -> BoxFuture<'_, Result<Vec<SchemaRow>, ExecutorError>> {
+            self.replay(&sql)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SchemaValue;
+
+    #[test]
+    fn test_fixture() {
+        let mut fixture = Fixture::new();
+        let row = SchemaRow::new(vec![SchemaValue::String("cake".to_owned())]);
+        fixture.record("SELECT 1".to_owned(), vec![row.clone()]);
+        fixture.record("SELECT 1".to_owned(), Vec::new());
+        fixture.record("SELECT 2".to_owned(), Vec::new());
+
+        assert_eq!(fixture.queries.len(), 2);
+        assert_eq!(fixture.rows("SELECT 1"), Some([row].as_slice()));
+        assert_eq!(fixture.rows("SELECT 2"), Some([].as_slice()));
+        assert_eq!(fixture.rows("SELECT 3"), None);
+    }
+
+    #[cfg(feature = "with-serde")]
+    #[test]
+    fn test_save_load() {
+        let mut fixture = Fixture::new();
+        let row = SchemaRow::new(vec![
+            SchemaValue::String("cake".to_owned()),
+            SchemaValue::Null,
+            SchemaValue::Array(vec![SchemaValue::Int(1)]),
+        ]);
+        fixture.record("SELECT 1".to_owned(), vec![row]);
+        fixture.record("SELECT 2".to_owned(), Vec::new());
+
+        let path = std::env::temp_dir().join("sea-schema-test-fixture.json");
+        fixture.save(&path).unwrap();
+        assert_eq!(Fixture::load(&path).unwrap(), fixture);
+        std::fs::remove_file(path).unwrap();
+    }
+}
@@ -14,12 +14,35 @@ pub mod sqlite;
 
 pub use sea_query;
 
+#[cfg(all(feature = "parser", any(feature = "mysql", feature = "postgres")))]
+pub(crate) mod error;
 pub(crate) mod parser;
 pub(crate) mod sqlx_types;
 pub(crate) mod util;
 
+#[cfg(feature = "discovery")]
+#[cfg_attr(docsrs, doc(cfg(feature = "discovery")))]
+pub mod fixture;
+#[cfg(feature = "discovery")]
+pub use fixture::*;
+
+pub mod filter;
+pub use filter::*;
+
 pub mod name;
 pub use name::*;
 
+pub mod row;
+pub use row::*;
+
+pub mod executor;
+pub use executor::*;
+
+#[cfg(feature = "discovery")]
+#[cfg_attr(docsrs, doc(cfg(feature = "discovery")))]
+pub mod warning;
+#[cfg(feature = "discovery")]
+pub use warning::*;
+
 #[cfg(feature = "probe")]
 pub mod probe;
@@ -10,6 +10,8 @@ pub struct ForeignKeyInfo {
     pub name: String,
     /// The columns composing this foreign key
     pub columns: Vec<String>,
+    /// Referenced schema name, if not the schema of the table
+    pub referenced_schema: Option<String>,
     /// Referenced table name
     pub referenced_table: String,
     /// The columns composing the index of the referenced table
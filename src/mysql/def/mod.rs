@@ -4,6 +4,7 @@ mod char_set;
 mod column;
 mod foreign_key;
 mod index;
+mod privilege;
 mod schema;
 mod storage_engine;
 mod system;
@@ -14,6 +15,7 @@ pub use char_set::*;
 pub use column::*;
 pub use foreign_key::*;
 pub use index::*;
+pub use privilege::*;
 pub use schema::*;
 pub use storage_engine::*;
 pub use system::*;
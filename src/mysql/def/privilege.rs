@@ -0,0 +1,17 @@
+#[cfg(feature = "with-serde")]
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
+pub struct PrivilegeInfo {
+    /// The account the privilege is granted to, in the form `'user'@'host'`
+    pub grantee: String,
+    /// The table the privilege applies to
+    pub table: String,
+    /// The column the privilege applies to, if it is a column privilege
+    pub column: Option<String>,
+    /// The privilege, e.g. `SELECT` or `INSERT`
+    pub privilege: String,
+    /// The grantee may grant the privilege to others
+    pub grantable: bool,
+}
@@ -9,6 +9,33 @@ pub struct Schema {
     pub schema: String,
     pub system: SystemInfo,
     pub tables: Vec<TableDef>,
+    pub privileges: Vec<PrivilegeInfo>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
+/// The schemas, or databases, of a MySQL server
+pub struct Server {
+    pub system: SystemInfo,
+    pub schemas: Vec<SchemaDef>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
+pub struct SchemaDef {
+    pub info: SchemaInfo,
+    pub schema: Schema,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
+pub struct SchemaInfo {
+    /// The name of the schema
+    pub name: String,
+    /// The default character set of the tables created in the schema
+    pub char_set: CharSet,
+    /// The default collation of the tables created in the schema
+    pub collation: Collation,
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -1,47 +1,98 @@
+use futures::{future::BoxFuture, lock::Mutex};
 use sea_query::{MysqlQueryBuilder, SelectStatement};
 use sea_query_binder::SqlxBinder;
-use sqlx::{mysql::MySqlRow, MySqlPool, Row};
+use sqlx::{
+    mysql::MySqlRow, pool::PoolConnection, MySql, MySqlConnection, MySqlPool, Row, Transaction,
+    ValueRef,
+};
 
-use crate::{debug_print, sqlx_types::SqlxError};
+use super::{Executor, IntoExecutor, SchemaExecutor};
+use crate::{debug_print, ExecutorError, SchemaRow, SchemaValue};
 
-pub struct Executor {
-    pool: MySqlPool,
+impl SchemaExecutor for MySqlPool {
+    fn fetch_all(
+        &self,
+        select: SelectStatement,
+    ) -> BoxFuture<'_, Result<Vec<SchemaRow>, ExecutorError>> {
+        Box::pin(fetch_all(self, select))
+    }
 }
 
-pub trait IntoExecutor {
-    fn into_executor(self) -> Executor;
+/// A borrowed connection, locked for each query as the discovery runs them concurrently
+struct ConnectionExecutor<'c> {
+    connection: Mutex<&'c mut MySqlConnection>,
 }
 
-impl IntoExecutor for MySqlPool {
-    fn into_executor(self) -> Executor {
-        Executor { pool: self }
+impl SchemaExecutor for ConnectionExecutor<'_> {
+    fn fetch_all(
+        &self,
+        select: SelectStatement,
+    ) -> BoxFuture<'_, Result<Vec<SchemaRow>, ExecutorError>> {
+        Box::pin(async move {
+            let mut connection = self.connection.lock().await;
+            fetch_all(&mut **connection, select).await
+        })
     }
 }
 
-impl Executor {
-    pub async fn fetch_all(&self, select: SelectStatement) -> Result<Vec<MySqlRow>, SqlxError> {
-        let (sql, values) = select.build_sqlx(MysqlQueryBuilder);
-        debug_print!("{}, {:?}", sql, values);
-
-        sqlx::query_with(&sql, values)
-            .fetch_all(&mut *self.pool.acquire().await?)
-            .await
+impl<'c> IntoExecutor<'c> for &'c mut MySqlConnection {
+    fn into_executor(self) -> Executor<'c> {
+        ConnectionExecutor {
+            connection: Mutex::new(self),
+        }
+        .into_executor()
     }
 }
 
-pub trait GetMySqlValue {
-    fn get_string(&self, idx: usize) -> String;
-
-    fn get_string_opt(&self, idx: usize) -> Option<String>;
+impl<'c> IntoExecutor<'c> for &'c mut Transaction<'_, MySql> {
+    fn into_executor(self) -> Executor<'c> {
+        (&mut **self).into_executor()
+    }
 }
 
-impl GetMySqlValue for MySqlRow {
-    fn get_string(&self, idx: usize) -> String {
-        String::from_utf8(self.get::<Vec<u8>, _>(idx)).unwrap()
+impl<'c> IntoExecutor<'c> for &'c mut PoolConnection<MySql> {
+    fn into_executor(self) -> Executor<'c> {
+        (&mut **self).into_executor()
     }
+}
+
+async fn fetch_all<'e, E>(
+    executor: E,
+    select: SelectStatement,
+) -> Result<Vec<SchemaRow>, ExecutorError>
+where
+    E: sqlx::Executor<'e, Database = MySql>,
+{
+    let (sql, values) = select.build_sqlx(MysqlQueryBuilder);
+    debug_print!("{}, {:?}", sql, values);
+
+    sqlx::query_with(&sql, values)
+        .fetch_all(executor)
+        .await?
+        .iter()
+        .map(schema_row)
+        .collect()
+}
 
-    fn get_string_opt(&self, idx: usize) -> Option<String> {
-        self.get::<Option<Vec<u8>>, _>(idx)
-            .map(|v| String::from_utf8(v).unwrap())
+/// Convert a row, trying the types the INFORMATION_SCHEMA columns can have
+fn schema_row(row: &MySqlRow) -> Result<SchemaRow, ExecutorError> {
+    let mut values = Vec::with_capacity(row.len());
+    for index in 0..row.len() {
+        let value = if row.try_get_raw(index)?.is_null() {
+            SchemaValue::Null
+        } else if let Ok(string) = row.try_get::<String, _>(index) {
+            SchemaValue::String(string)
+        } else if let Ok(int) = row.try_get::<i64, _>(index) {
+            SchemaValue::Int(int)
+        } else if let Ok(int) = row.try_get::<u64, _>(index) {
+            SchemaValue::UInt(int)
+        } else if let Ok(float) = row.try_get::<f64, _>(index) {
+            SchemaValue::Float(float)
+        } else {
+            // Binary strings, and any other type in its text form
+            SchemaValue::Bytes(row.try_get_unchecked::<Vec<u8>, _>(index)?)
+        };
+        values.push(value);
     }
+    Ok(SchemaRow::new(values))
 }
@@ -1,96 +1,219 @@
 //! To query & parse MySQL's INFORMATION_SCHEMA and construct a [`Schema`]
 
-use crate::debug_print;
 use crate::mysql::def::*;
 use crate::mysql::parser::{parse_foreign_key_query_results, parse_index_query_results};
 use crate::mysql::query::{
-    ColumnQueryResult, ForeignKeyQueryResult, IndexQueryResult, SchemaQueryBuilder,
-    TableQueryResult, VersionQueryResult,
+    ColumnQueryResult, ForeignKeyQueryResult, IndexQueryResult, PrivilegeQueryResult,
+    SchemaQueryBuilder, TableQueryResult, TablesFields, VersionQueryResult,
 };
-use crate::sqlx_types::SqlxError;
+use crate::warning::Warnings;
+use crate::{debug_print, DiscoveryWarning, TableFilter};
 use futures::future;
-use sea_query::{Alias, Iden, IntoIden, SeaRc};
+use sea_query::{Alias, Iden, IntoIden, SeaRc, SelectStatement};
+use std::sync::Arc;
 
 mod executor;
+mod server;
+pub use super::error::{DiscoveryResult, MySqlDiscoveryError};
 pub use executor::*;
+pub use server::*;
 
-pub struct SchemaDiscovery {
+pub struct SchemaDiscovery<'c> {
     pub query: SchemaQueryBuilder,
-    pub executor: Executor,
+    pub executor: Executor<'c>,
     pub schema: SeaRc<dyn Iden>,
+    /// The tables to discover
+    pub filter: TableFilter,
+    table_predicates: Vec<TablePredicate<'c>>,
+    warnings: Warnings,
 }
 
-impl SchemaDiscovery {
+type TablePredicate<'c> = Arc<dyn Fn(&TableInfo) -> bool + Send + Sync + 'c>;
+
+impl<'c> SchemaDiscovery<'c> {
     pub fn new<E>(executor: E, schema: &str) -> Self
     where
-        E: IntoExecutor,
+        E: IntoExecutor<'c>,
     {
         Self {
             query: SchemaQueryBuilder::default(),
             executor: executor.into_executor(),
             schema: Alias::new(schema).into_iden(),
+            filter: TableFilter::default(),
+            table_predicates: Vec::new(),
+            warnings: Warnings::default(),
         }
     }
 
-    pub async fn discover(mut self) -> Result<Schema, SqlxError> {
-        self.query = SchemaQueryBuilder::new(self.discover_system().await?);
+    /// Only discover the tables passing the filter
+    pub fn with_filter(mut self, filter: TableFilter) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    /// Only discover the tables for which the predicate holds, e.g. by engine or comment.
+    /// Can be called multiple times, a table has to pass every predicate.
+    /// Unlike the filter, predicates can't be pushed into the query listing the tables
+    pub fn with_table_predicate<F>(mut self, predicate: F) -> Self
+    where
+        F: Fn(&TableInfo) -> bool + Send + Sync + 'c,
+    {
+        self.table_predicates.push(Arc::new(predicate));
+        self
+    }
+
+    pub async fn discover(&self) -> DiscoveryResult<Schema> {
+        let system = self.discover_system().await?;
+        self.with_system(system).discover_schema().await
+    }
+
+    /// A discovery sharing the executor, filter and warnings of this one,
+    /// with the queries built for the version of the server
+    fn with_system(&self, system: SystemInfo) -> SchemaDiscovery<'_> {
+        self.with(SchemaQueryBuilder::new(system), self.warnings.clone())
+    }
+
+    /// A discovery sharing the executor and filter of this one, collecting its own warnings
+    fn lenient(&self) -> SchemaDiscovery<'_> {
+        self.with(self.query.clone(), Warnings::lenient())
+    }
+
+    fn with(&self, query: SchemaQueryBuilder, warnings: Warnings) -> SchemaDiscovery<'_> {
+        SchemaDiscovery {
+            query,
+            executor: self.executor.borrow(),
+            schema: self.schema.clone(),
+            filter: self.filter.clone(),
+            table_predicates: self.table_predicates.clone(),
+            warnings,
+        }
+    }
+
+    async fn discover_schema(self) -> DiscoveryResult<Schema> {
         let tables = self.discover_tables().await?;
+        let table_names: Vec<_> = tables.iter().map(|table| table.name.clone()).collect();
         let tables = future::try_join_all(
             tables
                 .into_iter()
                 .map(|t| (&self, t))
                 .map(Self::discover_table_static),
         )
-        .await?;
+        .await?
+        .into_iter()
+        .flatten()
+        .collect();
+        let mut privileges = self.discover_privileges().await?;
+        if !self.table_predicates.is_empty() {
+            privileges.retain(|privilege| table_names.contains(&privilege.table));
+        }
 
         Ok(Schema {
             schema: self.schema.to_string(),
             system: self.query.system,
             tables,
+            privileges,
         })
     }
 
-    pub async fn discover_system(&mut self) -> Result<SystemInfo, SqlxError> {
+    /// Discover the schema, skipping what can't be discovered instead of failing.
+    /// Every table, column, index, foreign key or privilege skipped, and every column of an unknown
+    /// type, is reported as a warning. An unknown server version is taken as the oldest.
+    /// An error of the executor still fails the discovery
+    pub async fn discover_lenient(&self) -> DiscoveryResult<(Schema, Vec<DiscoveryWarning>)> {
+        let lenient = self.lenient();
+        let schema = lenient.discover().await?;
+        Ok((schema, lenient.warnings.take()))
+    }
+
+    pub async fn discover_system(&self) -> DiscoveryResult<SystemInfo> {
         let rows = self.executor.fetch_all(self.query.query_version()).await?;
+        let row = rows.first().ok_or(SqlxError::RowNotFound)?;
 
-        #[allow(clippy::never_loop)]
-        for row in rows.iter() {
-            let result: VersionQueryResult = row.into();
-            debug_print!("{:?}", result);
-            let version = result.parse();
-            debug_print!("{:?}", version);
-            return Ok(version);
-        }
-        Err(SqlxError::RowNotFound)
+        let version = VersionQueryResult::try_from(row)
+            .map_err(|err| MySqlDiscoveryError::from_row(err, row, None, None))
+            .and_then(|result| {
+                debug_print!("{:?}", result);
+                result.parse()
+            });
+        let version = self.warnings.recover(version)?.unwrap_or_default();
+        debug_print!("{:?}", version);
+        Ok(version)
     }
 
-    pub async fn discover_tables(&mut self) -> Result<Vec<TableInfo>, SqlxError> {
-        let rows = self
-            .executor
-            .fetch_all(self.query.query_tables(self.schema.clone()))
-            .await?;
+    pub async fn discover_tables(&self) -> DiscoveryResult<Vec<TableInfo>> {
+        let rows = self.executor.fetch_all(self.query_tables()).await?;
 
-        let tables: Vec<TableInfo> = rows
-            .iter()
+        rows.iter()
             .map(|row| {
-                let result: TableQueryResult = row.into();
+                let result = TableQueryResult::try_from(row)
+                    .map_err(|err| MySqlDiscoveryError::from_row(err, row, Some(0), None))?;
                 debug_print!("{:?}", result);
-                let table = result.parse();
+                let table = result.parse()?;
                 debug_print!("{:?}", table);
-                table
+                Ok(table)
+            })
+            .filter_map(|result| self.warnings.recover(result).transpose())
+            .filter(|table| match table {
+                Ok(table) => self.is_discovered(table),
+                Err(_) => true,
             })
-            .collect();
+            .collect()
+    }
 
-        Ok(tables)
+    /// The query listing the tables, restricted by the filter
+    fn query_tables(&self) -> SelectStatement {
+        let mut select = self.query.query_tables(self.schema.clone());
+        self.filter.apply(&mut select, TablesFields::TableName);
+        select
     }
 
-    async fn discover_table_static(params: (&Self, TableInfo)) -> Result<TableDef, SqlxError> {
+    /// Whether the table passes the filter and the predicates
+    fn is_discovered(&self, table: &TableInfo) -> bool {
+        self.filter.is_included(&table.name)
+            && self
+                .table_predicates
+                .iter()
+                .all(|predicate| predicate(table))
+    }
+
+    pub async fn discover_privileges(&self) -> DiscoveryResult<Vec<PrivilegeInfo>> {
+        let mut rows = self
+            .executor
+            .fetch_all(self.query.query_table_privileges(self.schema.clone()))
+            .await?;
+        rows.extend(
+            self.executor
+                .fetch_all(self.query.query_column_privileges(self.schema.clone()))
+                .await?,
+        );
+
+        rows.iter()
+            .map(|row| {
+                let result = PrivilegeQueryResult::try_from(row)
+                    .map_err(|err| MySqlDiscoveryError::from_row(err, row, Some(1), Some(2)))?;
+                debug_print!("{:?}", result);
+                let privilege = result.parse();
+                debug_print!("{:?}", privilege);
+                Ok(privilege)
+            })
+            .filter_map(|result| self.warnings.recover(result).transpose())
+            .filter(|privilege| match privilege {
+                Ok(privilege) => self.filter.is_included(&privilege.table),
+                Err(_) => true,
+            })
+            .collect()
+    }
+
+    async fn discover_table_static(
+        params: (&Self, TableInfo),
+    ) -> DiscoveryResult<Option<TableDef>> {
         let this = params.0;
         let info = params.1;
-        Self::discover_table(this, info).await
+        this.warnings
+            .recover(Self::discover_table(this, info).await)
     }
 
-    pub async fn discover_table(&self, info: TableInfo) -> Result<TableDef, SqlxError> {
+    pub async fn discover_table(&self, info: TableInfo) -> DiscoveryResult<TableDef> {
         let table = SeaRc::new(Alias::new(info.name.as_str()));
         let columns = self
             .discover_columns(self.schema.clone(), table.clone(), &self.query.system)
@@ -115,71 +238,102 @@ impl SchemaDiscovery {
         schema: SeaRc<dyn Iden>,
         table: SeaRc<dyn Iden>,
         system: &SystemInfo,
-    ) -> Result<Vec<ColumnInfo>, SqlxError> {
+    ) -> DiscoveryResult<Vec<ColumnInfo>> {
+        let table_name = table.to_string();
         let rows = self
             .executor
             .fetch_all(self.query.query_columns(schema.clone(), table.clone()))
-            .await?;
+            .await
+            .map_err(|err| MySqlDiscoveryError::from(err).with_table(&table_name))?;
 
-        let columns = rows
-            .iter()
+        rows.iter()
             .map(|row| {
-                let result: ColumnQueryResult = row.into();
+                let result = ColumnQueryResult::try_from(row).map_err(|err| {
+                    MySqlDiscoveryError::from_row(err, row, None, Some(0)).with_table(&table_name)
+                })?;
                 debug_print!("{:?}", result);
+                let column_type = result.column_type.clone();
                 let column = result.parse(system);
                 debug_print!("{:?}", column);
-                column
+                if column.col_type.is_unknown() {
+                    self.warnings.warn(
+                        DiscoveryWarning::new("Unknown column type")
+                            .table(&table_name)
+                            .column(&column.name)
+                            .value(column_type),
+                    );
+                }
+                Ok(column)
             })
-            .collect::<Vec<_>>();
-
-        Ok(columns)
+            .filter_map(|result| self.warnings.recover(result).transpose())
+            .collect()
     }
 
     pub async fn discover_indexes(
         &self,
         schema: SeaRc<dyn Iden>,
         table: SeaRc<dyn Iden>,
-    ) -> Result<Vec<IndexInfo>, SqlxError> {
+    ) -> DiscoveryResult<Vec<IndexInfo>> {
+        let table_name = table.to_string();
         let rows = self
             .executor
             .fetch_all(self.query.query_indexes(schema.clone(), table.clone()))
-            .await?;
+            .await
+            .map_err(|err| MySqlDiscoveryError::from(err).with_table(&table_name))?;
 
-        let results = rows.into_iter().map(|row| {
-            let result: IndexQueryResult = (&row).into();
-            debug_print!("{:?}", result);
-            result
-        });
+        let results = rows
+            .iter()
+            .map(|row| {
+                let result = IndexQueryResult::try_from(row).map_err(|err| {
+                    MySqlDiscoveryError::from_row(err, row, None, Some(2)).with_table(&table_name)
+                })?;
+                debug_print!("{:?}", result);
+                Ok(result)
+            })
+            .filter_map(|result| self.warnings.recover(result).transpose())
+            .collect::<DiscoveryResult<Vec<_>>>()?;
 
-        Ok(parse_index_query_results(Box::new(results))
+        parse_index_query_results(Box::new(results.into_iter()))
             .map(|index| {
+                let index = index.map_err(|err| err.with_table(&table_name))?;
                 debug_print!("{:?}", index);
-                index
+                Ok(index)
             })
-            .collect())
+            .filter_map(|result| self.warnings.recover(result).transpose())
+            .collect()
     }
 
     pub async fn discover_foreign_keys(
         &self,
         schema: SeaRc<dyn Iden>,
         table: SeaRc<dyn Iden>,
-    ) -> Result<Vec<ForeignKeyInfo>, SqlxError> {
+    ) -> DiscoveryResult<Vec<ForeignKeyInfo>> {
+        let table_name = table.to_string();
         let rows = self
             .executor
             .fetch_all(self.query.query_foreign_key(schema.clone(), table.clone()))
-            .await?;
+            .await
+            .map_err(|err| MySqlDiscoveryError::from(err).with_table(&table_name))?;
 
-        let results = rows.into_iter().map(|row| {
-            let result: ForeignKeyQueryResult = (&row).into();
-            debug_print!("{:?}", result);
-            result
-        });
+        let results = rows
+            .iter()
+            .map(|row| {
+                let result = ForeignKeyQueryResult::try_from(row).map_err(|err| {
+                    MySqlDiscoveryError::from_row(err, row, None, Some(1)).with_table(&table_name)
+                })?;
+                debug_print!("{:?}", result);
+                Ok(result)
+            })
+            .filter_map(|result| self.warnings.recover(result).transpose())
+            .collect::<DiscoveryResult<Vec<_>>>()?;
 
-        Ok(parse_foreign_key_query_results(Box::new(results))
-            .map(|index| {
-                debug_print!("{:?}", index);
-                index
+        parse_foreign_key_query_results(Box::new(results.into_iter()))
+            .map(|foreign_key| {
+                let foreign_key = foreign_key.map_err(|err| err.with_table(&table_name))?;
+                debug_print!("{:?}", foreign_key);
+                Ok(foreign_key)
             })
-            .collect())
+            .filter_map(|result| self.warnings.recover(result).transpose())
+            .collect()
     }
 }
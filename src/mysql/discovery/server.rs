@@ -0,0 +1,120 @@
+use futures::future;
+
+use super::{DiscoveryResult, Executor, IntoExecutor, MySqlDiscoveryError, SchemaDiscovery};
+use crate::mysql::def::{SchemaDef, SchemaInfo, Server};
+use crate::mysql::query::{SchemaQueryBuilder, SchemataFields, SchemataQueryResult};
+use crate::warning::Warnings;
+use crate::{debug_print, DiscoveryWarning, SchemaFilter, TableFilter};
+
+/// Discovers every schema of a MySQL server, each with a [SchemaDiscovery]
+pub struct ServerDiscovery<'c> {
+    pub query: SchemaQueryBuilder,
+    pub executor: Executor<'c>,
+    /// The schemas to discover. By default every schema but the system ones:
+    /// `information_schema`, `mysql`, `performance_schema` and `sys`
+    pub filter: SchemaFilter,
+    /// The tables to discover in every schema
+    pub table_filter: TableFilter,
+    warnings: Warnings,
+}
+
+impl<'c> ServerDiscovery<'c> {
+    pub fn new<E>(executor: E) -> Self
+    where
+        E: IntoExecutor<'c>,
+    {
+        Self {
+            query: SchemaQueryBuilder::default(),
+            executor: executor.into_executor(),
+            filter: SchemaFilter::new().exclude([
+                "information_schema",
+                "mysql",
+                "performance_schema",
+                "sys",
+            ]),
+            table_filter: TableFilter::default(),
+            warnings: Warnings::default(),
+        }
+    }
+
+    /// Only discover the schemas passing the filter, which replaces the default one
+    pub fn with_filter(mut self, filter: SchemaFilter) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    /// Only discover the tables passing the filter, in every schema
+    pub fn with_table_filter(mut self, filter: TableFilter) -> Self {
+        self.table_filter = filter;
+        self
+    }
+
+    /// Discover the schemas concurrently, in the order of their names. The version of the server
+    /// is discovered once for all the schemas
+    pub async fn discover(&self) -> DiscoveryResult<Server> {
+        let system = self.schema_discovery("").discover_system().await?;
+        let infos = self.discover_schemata().await?;
+        let schemas = future::try_join_all(infos.into_iter().map(|info| {
+            let system = system.clone();
+            async move {
+                let schema = self
+                    .schema_discovery(&info.name)
+                    .with_system(system)
+                    .discover_schema()
+                    .await?;
+                DiscoveryResult::Ok(SchemaDef { info, schema })
+            }
+        }))
+        .await?;
+
+        Ok(Server { system, schemas })
+    }
+
+    /// Discover the schemas, skipping what can't be discovered instead of failing, as
+    /// [SchemaDiscovery::discover_lenient] does. An error of the executor still fails the
+    /// discovery
+    pub async fn discover_lenient(&self) -> DiscoveryResult<(Server, Vec<DiscoveryWarning>)> {
+        let lenient = ServerDiscovery {
+            query: self.query.clone(),
+            executor: self.executor.borrow(),
+            filter: self.filter.clone(),
+            table_filter: self.table_filter.clone(),
+            warnings: Warnings::lenient(),
+        };
+        let server = lenient.discover().await?;
+        Ok((server, lenient.warnings.take()))
+    }
+
+    /// The discovery of a schema, sharing the executor, table filter and warnings of this one
+    fn schema_discovery(&self, schema: &str) -> SchemaDiscovery<'_> {
+        SchemaDiscovery {
+            query: self.query.clone(),
+            filter: self.table_filter.clone(),
+            warnings: self.warnings.clone(),
+            ..SchemaDiscovery::new(self.executor.borrow(), schema)
+        }
+    }
+
+    /// List the schemas passing the filter, with their default character set and collation
+    pub async fn discover_schemata(&self) -> DiscoveryResult<Vec<SchemaInfo>> {
+        let mut select = self.query.query_schemata();
+        self.filter.apply(&mut select, SchemataFields::SchemaName);
+        let rows = self.executor.fetch_all(select).await?;
+
+        rows.iter()
+            .map(|row| {
+                let result = SchemataQueryResult::try_from(row)
+                    .map_err(|err| MySqlDiscoveryError::from_row(err, row, None, None))?;
+                debug_print!("{:?}", result);
+                let info = result.parse()?;
+                debug_print!("{:?}", info);
+                Ok(info)
+            })
+            .filter_map(|result| self.warnings.recover(result).transpose())
+            .filter(|info| match info {
+                Ok(info) => self.filter.is_included(&info.name),
+                Err(_) => true,
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,9 @@
+crate::error::discovery_error! {
+    /// All the errors that can be encountered when discovering a MySQL schema.
+    pub enum MySqlDiscoveryError {
+        /// A row returned by an INFORMATION_SCHEMA query does not have the expected shape
+        UnexpectedRow,
+        /// A value that sea-schema does not know, e.g. an index type
+        UnknownValue,
+    }
+}
@@ -8,6 +8,9 @@ pub mod def;
 #[cfg_attr(docsrs, doc(cfg(feature = "discovery")))]
 pub mod discovery;
 
+#[cfg(feature = "parser")]
+mod error;
+
 #[cfg(feature = "parser")]
 #[cfg_attr(docsrs, doc(cfg(feature = "parser")))]
 pub mod parser;
@@ -194,13 +194,18 @@ fn parse_enum_definition(parser: &mut Parser, mut ctype: ColumnType) -> ColumnTy
     if parser.next_if_punctuation("(") {
         while parser.curr().is_some() {
             if let Some(word) = parser.next_if_quoted_any() {
-                ctype
-                    .get_enum_def_mut()
-                    .values
-                    .push(MysqlQueryBuilder.unescape_string(word.unquote().unwrap().as_str()));
+                if let Some(value) = word.unquote() {
+                    ctype
+                        .get_enum_def_mut()
+                        .values
+                        .push(MysqlQueryBuilder.unescape_string(value.as_str()));
+                }
                 parser.next_if_punctuation(",");
-            } else if parser.curr_is_unquoted() {
-                todo!("there can actually be numeric enum values but is very confusing");
+            } else if parser.next_if_punctuation(")") {
+                break;
+            } else {
+                // MySQL always quotes the values, skip anything else
+                parser.next();
             }
             if parser.next_if_punctuation(")") {
                 break;
@@ -217,13 +222,18 @@ fn parse_set_definition(parser: &mut Parser, mut ctype: ColumnType) -> ColumnTyp
     if parser.next_if_punctuation("(") {
         while parser.curr().is_some() {
             if let Some(word) = parser.next_if_quoted_any() {
-                ctype
-                    .get_set_def_mut()
-                    .members
-                    .push(MysqlQueryBuilder.unescape_string(word.unquote().unwrap().as_str()));
+                if let Some(member) = word.unquote() {
+                    ctype
+                        .get_set_def_mut()
+                        .members
+                        .push(MysqlQueryBuilder.unescape_string(member.as_str()));
+                }
                 parser.next_if_punctuation(",");
-            } else if parser.curr_is_unquoted() {
-                todo!("there can actually be numeric set values but is very confusing");
+            } else if parser.next_if_punctuation(")") {
+                break;
+            } else {
+                // MySQL always quotes the members, skip anything else
+                parser.next();
             }
             if parser.next_if_punctuation(")") {
                 break;
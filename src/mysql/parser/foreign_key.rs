@@ -1,4 +1,5 @@
 use crate::mysql::def::*;
+use crate::mysql::error::{DiscoveryResult, MySqlDiscoveryError};
 use crate::mysql::query::ForeignKeyQueryResult;
 use crate::Name;
 
@@ -10,7 +11,7 @@ pub struct ForeignKeyQueryResultParser {
 /// ForeignKeyQueryResult must be sorted by (TableName, ConstraintName, OrdinalPosition)
 pub fn parse_foreign_key_query_results(
     results: Box<dyn Iterator<Item = ForeignKeyQueryResult>>,
-) -> impl Iterator<Item = ForeignKeyInfo> {
+) -> impl Iterator<Item = DiscoveryResult<ForeignKeyInfo>> {
     ForeignKeyQueryResultParser {
         curr: None,
         results,
@@ -18,43 +19,55 @@ pub fn parse_foreign_key_query_results(
 }
 
 impl Iterator for ForeignKeyQueryResultParser {
-    type Item = ForeignKeyInfo;
+    type Item = DiscoveryResult<ForeignKeyInfo>;
 
     fn next(&mut self) -> Option<Self::Item> {
         for result in self.results.by_ref() {
-            let mut foreign_key = parse_foreign_key_query_result(result);
+            let mut foreign_key = match parse_foreign_key_query_result(result) {
+                Ok(foreign_key) => foreign_key,
+                Err(err) => return Some(Err(err)),
+            };
             if let Some(curr) = &mut self.curr {
                 // group by `foreign_key.name`
                 if curr.name == foreign_key.name {
-                    curr.columns.push(foreign_key.columns.pop().unwrap());
+                    curr.columns.append(&mut foreign_key.columns);
                     curr.referenced_columns
-                        .push(foreign_key.referenced_columns.pop().unwrap());
+                        .append(&mut foreign_key.referenced_columns);
                 } else {
                     let prev = self.curr.take();
                     self.curr = Some(foreign_key);
-                    return prev;
+                    return prev.map(Ok);
                 }
             } else {
                 self.curr = Some(foreign_key);
             }
         }
-        self.curr.take()
+        self.curr.take().map(Ok)
     }
 }
 
-pub fn parse_foreign_key_query_result(result: ForeignKeyQueryResult) -> ForeignKeyInfo {
-    ForeignKeyInfo {
+pub fn parse_foreign_key_query_result(
+    result: ForeignKeyQueryResult,
+) -> DiscoveryResult<ForeignKeyInfo> {
+    let on_update = parse_foreign_key_action(result.update_rule.as_str())
+        .map_err(|err| err.with_column(&result.column_name))?;
+    let on_delete = parse_foreign_key_action(result.delete_rule.as_str())
+        .map_err(|err| err.with_column(&result.column_name))?;
+    Ok(ForeignKeyInfo {
         name: result.constraint_name,
         columns: vec![result.column_name],
+        referenced_schema: Some(result.referenced_table_schema)
+            .filter(|schema| *schema != result.table_schema),
         referenced_table: result.referenced_table_name,
         referenced_columns: vec![result.referenced_column_name],
-        on_update: parse_foreign_key_action(result.update_rule.as_str()),
-        on_delete: parse_foreign_key_action(result.delete_rule.as_str()),
-    }
+        on_update,
+        on_delete,
+    })
 }
 
-pub fn parse_foreign_key_action(string: &str) -> ForeignKeyAction {
-    ForeignKeyAction::from_str(string).unwrap()
+pub fn parse_foreign_key_action(string: &str) -> DiscoveryResult<ForeignKeyAction> {
+    ForeignKeyAction::from_str(string)
+        .ok_or_else(|| MySqlDiscoveryError::unknown_value("foreign key action", string))
 }
 
 #[cfg(test)]
@@ -73,6 +86,8 @@ mod tests {
                         referenced_column_name: "c1".to_owned(),
                         update_rule: "CASCADE".to_owned(),
                         delete_rule: "NO ACTION".to_owned(),
+                        table_schema: "zoo".to_owned(),
+                        referenced_table_schema: "zoo".to_owned(),
                     },
                     ForeignKeyQueryResult {
                         constraint_name: "fk-cat-dog".to_owned(),
@@ -81,14 +96,18 @@ mod tests {
                         referenced_column_name: "c2".to_owned(),
                         update_rule: "CASCADE".to_owned(),
                         delete_rule: "NO ACTION".to_owned(),
+                        table_schema: "zoo".to_owned(),
+                        referenced_table_schema: "zoo".to_owned(),
                     },
                 ]
                 .into_iter()
             ))
-            .collect::<Vec<ForeignKeyInfo>>(),
+            .collect::<DiscoveryResult<Vec<ForeignKeyInfo>>>()
+            .unwrap(),
             vec![ForeignKeyInfo {
                 name: "fk-cat-dog".to_owned(),
                 columns: vec!["d1".to_owned(), "d2".to_owned()],
+                referenced_schema: None,
                 referenced_table: "cat".to_owned(),
                 referenced_columns: vec!["c1".to_owned(), "c2".to_owned()],
                 on_update: ForeignKeyAction::Cascade,
@@ -96,4 +115,30 @@ mod tests {
             }]
         );
     }
+
+    #[test]
+    fn test_2() {
+        assert_eq!(
+            parse_foreign_key_query_result(ForeignKeyQueryResult {
+                constraint_name: "fk-dog-owner".to_owned(),
+                column_name: "owner_id".to_owned(),
+                referenced_table_name: "person".to_owned(),
+                referenced_column_name: "id".to_owned(),
+                update_rule: "RESTRICT".to_owned(),
+                delete_rule: "SET NULL".to_owned(),
+                table_schema: "zoo".to_owned(),
+                referenced_table_schema: "town".to_owned(),
+            })
+            .unwrap(),
+            ForeignKeyInfo {
+                name: "fk-dog-owner".to_owned(),
+                columns: vec!["owner_id".to_owned()],
+                referenced_schema: Some("town".to_owned()),
+                referenced_table: "person".to_owned(),
+                referenced_columns: vec!["id".to_owned()],
+                on_update: ForeignKeyAction::Restrict,
+                on_delete: ForeignKeyAction::SetNull,
+            }
+        );
+    }
 }
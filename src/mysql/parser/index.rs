@@ -1,4 +1,5 @@
 use crate::mysql::def::*;
+use crate::mysql::error::{DiscoveryResult, MySqlDiscoveryError};
 use crate::mysql::query::IndexQueryResult;
 use crate::Name;
 
@@ -10,7 +11,7 @@ pub struct IndexQueryResultParser {
 /// IndexQueryResult must be sorted by (TableName, IndexName, SeqInIndex)
 pub fn parse_index_query_results(
     results: Box<dyn Iterator<Item = IndexQueryResult>>,
-) -> impl Iterator<Item = IndexInfo> {
+) -> impl Iterator<Item = DiscoveryResult<IndexInfo>> {
     IndexQueryResultParser {
         curr: None,
         results,
@@ -18,60 +19,72 @@ pub fn parse_index_query_results(
 }
 
 impl Iterator for IndexQueryResultParser {
-    type Item = IndexInfo;
+    type Item = DiscoveryResult<IndexInfo>;
 
     fn next(&mut self) -> Option<Self::Item> {
         for result in self.results.by_ref() {
-            let mut index = parse_index_query_result(result);
+            let mut index = match parse_index_query_result(result) {
+                Ok(index) => index,
+                Err(err) => return Some(Err(err)),
+            };
             if let Some(curr) = &mut self.curr {
                 // group by `index.name`, consolidate to `index.parts`
                 if curr.name == index.name {
-                    curr.parts.push(index.parts.pop().unwrap());
+                    curr.parts.append(&mut index.parts);
                     curr.functional |= index.functional;
                 } else {
                     let prev = self.curr.take();
                     self.curr = Some(index);
-                    return prev;
+                    return prev.map(Ok);
                 }
             } else {
                 self.curr = Some(index);
             }
         }
-        self.curr.take()
+        self.curr.take().map(Ok)
     }
 }
 
-pub fn parse_index_query_result(mut result: IndexQueryResult) -> IndexInfo {
-    IndexInfo {
-        unique: match result.non_unique {
-            0 => true,
-            1 => false,
-            _ => unimplemented!(),
-        },
+pub fn parse_index_query_result(result: IndexQueryResult) -> DiscoveryResult<IndexInfo> {
+    let functional = result.expression.is_some();
+    let column = match (result.column_name, result.expression) {
+        (Some(column), _) => column,
+        (None, Some(expression)) => expression,
+        (None, None) => {
+            return Err(MySqlDiscoveryError::unknown_value(
+                "index part",
+                &result.index_name,
+            ))
+        }
+    };
+    let unknown =
+        |kind, value: &str| MySqlDiscoveryError::unknown_value(kind, value).with_column(&column);
+    let unique = match result.non_unique {
+        0 => true,
+        1 => false,
+        non_unique => return Err(unknown("NON_UNIQUE", &non_unique.to_string())),
+    };
+    let order = match result.collation.as_deref() {
+        Some("A") => IndexOrder::Ascending,
+        Some("D") => IndexOrder::Descending,
+        Some(collation) => return Err(unknown("index order", collation)),
+        None => IndexOrder::Unordered,
+    };
+    let idx_type = IndexType::from_str(result.index_type.as_str())
+        .ok_or_else(|| unknown("index type", &result.index_type))?;
+    Ok(IndexInfo {
+        unique,
         name: result.index_name,
         parts: vec![IndexPart {
-            column: if result.column_name.is_some() {
-                result.column_name.take().unwrap()
-            } else if result.expression.is_some() {
-                result.expression.take().unwrap()
-            } else {
-                panic!("index column error")
-            },
-            order: match result.collation {
-                Some(collation) => match collation.as_str() {
-                    "A" => IndexOrder::Ascending,
-                    "D" => IndexOrder::Descending,
-                    _ => unimplemented!(),
-                },
-                None => IndexOrder::Unordered,
-            },
+            column,
+            order,
             sub_part: result.sub_part.map(|v| v as u32),
         }],
         nullable: matches!(result.nullable.as_str(), "YES"),
-        idx_type: IndexType::from_str(result.index_type.as_str()).unwrap(),
+        idx_type,
         comment: result.index_comment,
-        functional: result.expression.is_some(),
-    }
+        functional,
+    })
 }
 
 #[cfg(test)]
@@ -95,7 +108,8 @@ mod tests {
                 }]
                 .into_iter()
             ))
-            .collect::<Vec<IndexInfo>>(),
+            .collect::<DiscoveryResult<Vec<IndexInfo>>>()
+            .unwrap(),
             vec![IndexInfo {
                 unique: true,
                 name: "PRIMARY".to_owned(),
@@ -129,7 +143,8 @@ mod tests {
                 }]
                 .into_iter()
             ))
-            .collect::<Vec<IndexInfo>>(),
+            .collect::<DiscoveryResult<Vec<IndexInfo>>>()
+            .unwrap(),
             vec![IndexInfo {
                 unique: false,
                 name: "idx_title".to_owned(),
@@ -187,7 +202,8 @@ mod tests {
                 ]
                 .into_iter()
             ))
-            .collect::<Vec<IndexInfo>>(),
+            .collect::<DiscoveryResult<Vec<IndexInfo>>>()
+            .unwrap(),
             vec![IndexInfo {
                 unique: true,
                 name: "rental_date".to_owned(),
@@ -233,7 +249,8 @@ mod tests {
                 }]
                 .into_iter()
             ))
-            .collect::<Vec<IndexInfo>>(),
+            .collect::<DiscoveryResult<Vec<IndexInfo>>>()
+            .unwrap(),
             vec![IndexInfo {
                 unique: false,
                 name: "idx_location".to_owned(),
@@ -249,4 +266,32 @@ mod tests {
             }]
         );
     }
+
+    #[test]
+    fn test_5() {
+        let result = parse_index_query_results(Box::new(
+            vec![IndexQueryResult {
+                non_unique: 1,
+                index_name: "idx_embedding".to_owned(),
+                column_name: Some("embedding".to_owned()),
+                collation: None,
+                sub_part: None,
+                nullable: "YES".to_owned(),
+                index_type: "VECTOR".to_owned(),
+                index_comment: "".to_owned(),
+                expression: None,
+            }]
+            .into_iter(),
+        ))
+        .collect::<DiscoveryResult<Vec<IndexInfo>>>();
+
+        assert!(matches!(
+            result,
+            Err(MySqlDiscoveryError::UnknownValue {
+                kind: "index type",
+                value,
+                ..
+            }) if value == "VECTOR"
+        ));
+    }
 }
@@ -3,11 +3,16 @@
 mod column;
 mod foreign_key;
 mod index;
+mod privilege;
+mod schemata;
 mod system;
 mod table;
 
+pub use super::error::{DiscoveryResult, MySqlDiscoveryError};
 pub use column::*;
 pub use foreign_key::*;
 pub use index::*;
+pub use privilege::*;
+pub use schemata::*;
 pub use system::*;
 pub use table::*;
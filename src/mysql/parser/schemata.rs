@@ -0,0 +1,43 @@
+use crate::mysql::def::*;
+use crate::mysql::error::{DiscoveryResult, MySqlDiscoveryError};
+use crate::mysql::query::SchemataQueryResult;
+use crate::Name;
+
+impl SchemataQueryResult {
+    pub fn parse(self) -> DiscoveryResult<SchemaInfo> {
+        parse_schemata_query_result(self)
+    }
+}
+
+pub fn parse_schemata_query_result(result: SchemataQueryResult) -> DiscoveryResult<SchemaInfo> {
+    let unknown = |kind, value: &str| MySqlDiscoveryError::unknown_value(kind, value);
+    Ok(SchemaInfo {
+        char_set: CharSet::from_str(result.default_character_set_name.as_str())
+            .ok_or_else(|| unknown("character set", &result.default_character_set_name))?,
+        collation: Collation::from_str(result.default_collation_name.as_str())
+            .ok_or_else(|| unknown("collation", &result.default_collation_name))?,
+        name: result.schema_name,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_1() {
+        assert_eq!(
+            parse_schemata_query_result(SchemataQueryResult {
+                schema_name: "bakery".to_owned(),
+                default_character_set_name: "utf8mb4".to_owned(),
+                default_collation_name: "utf8mb4_0900_ai_ci".to_owned(),
+            })
+            .unwrap(),
+            SchemaInfo {
+                name: "bakery".to_owned(),
+                char_set: CharSet::Utf8Mb4,
+                collation: Collation::Utf8Mb40900AiCi,
+            }
+        );
+    }
+}
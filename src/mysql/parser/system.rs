@@ -1,44 +1,41 @@
 use crate::mysql::def::*;
+use crate::mysql::error::{DiscoveryResult, MySqlDiscoveryError};
 use crate::mysql::query::VersionQueryResult;
 
 impl VersionQueryResult {
-    pub fn parse(self) -> SystemInfo {
+    pub fn parse(self) -> DiscoveryResult<SystemInfo> {
         parse_version_query_result(self)
     }
 }
 
-pub fn parse_version_query_result(result: VersionQueryResult) -> SystemInfo {
+pub fn parse_version_query_result(result: VersionQueryResult) -> DiscoveryResult<SystemInfo> {
     parse_version_string(result.version.as_str())
 }
 
-pub fn parse_version_string(string: &str) -> SystemInfo {
+pub fn parse_version_string(string: &str) -> DiscoveryResult<SystemInfo> {
     let mut system = SystemInfo::default();
     for (i, part) in string.split('-').enumerate() {
         if i == 0 {
-            system.version = parse_version_number(part);
+            system.version = parse_version_number(part)
+                .map_err(|_| MySqlDiscoveryError::unknown_value("version", string))?;
         } else if i == 1 {
             system.system = part.to_string();
         } else {
             system.suffix.push(part.to_owned());
         }
     }
-    system
+    Ok(system)
 }
 
-pub fn parse_version_number(string: &str) -> u32 {
+pub fn parse_version_number(string: &str) -> DiscoveryResult<u32> {
     let mut number: u32 = 0;
-    let numbers: Vec<&str> = string.split('.').collect();
-    #[allow(clippy::len_zero)]
-    if numbers.len() > 0 {
-        number += numbers[0].parse::<u32>().unwrap() * 10000
+    for (part, scale) in string.split('.').zip([10000, 100, 1]) {
+        let part: u32 = part
+            .parse()
+            .map_err(|_| MySqlDiscoveryError::unknown_value("version", string))?;
+        number += part * scale;
     }
-    if numbers.len() > 1 {
-        number += numbers[1].parse::<u32>().unwrap() * 100
-    }
-    if numbers.len() > 2 {
-        number += numbers[2].parse::<u32>().unwrap()
-    }
-    number
+    Ok(number)
 }
 
 #[cfg(test)]
@@ -47,18 +44,29 @@ mod tests {
 
     #[test]
     fn test_0() {
-        assert_eq!(parse_version_number("5.1.10"), 50110);
+        assert_eq!(parse_version_number("5.1.10").unwrap(), 50110);
     }
 
     #[test]
     fn test_1() {
-        assert_eq!(parse_version_number("8.0.23"), 80023);
+        assert_eq!(parse_version_number("8.0.23").unwrap(), 80023);
+    }
+
+    #[test]
+    fn test_5() {
+        assert!(matches!(
+            parse_version_string("8.x"),
+            Err(MySqlDiscoveryError::UnknownValue {
+                kind: "version",
+                ..
+            })
+        ));
     }
 
     #[test]
     fn test_2() {
         assert_eq!(
-            parse_version_string("8.0.23-0ubuntu0.20.04.1"),
+            parse_version_string("8.0.23-0ubuntu0.20.04.1").unwrap(),
             SystemInfo {
                 version: 80023,
                 system: "0ubuntu0.20.04.1".to_owned(),
@@ -70,7 +78,7 @@ mod tests {
     #[test]
     fn test_3() {
         assert_eq!(
-            parse_version_string("10.2.31-MariaDB"),
+            parse_version_string("10.2.31-MariaDB").unwrap(),
             SystemInfo {
                 version: 100231,
                 system: "MariaDB".to_owned(),
@@ -82,7 +90,7 @@ mod tests {
     #[test]
     fn test_4() {
         assert_eq!(
-            parse_version_string("10.2.31-MariaDB-debug"),
+            parse_version_string("10.2.31-MariaDB-debug").unwrap(),
             SystemInfo {
                 version: 100231,
                 system: "MariaDB".to_owned(),
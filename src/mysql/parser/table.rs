@@ -1,20 +1,27 @@
 use crate::mysql::def::*;
+use crate::mysql::error::{DiscoveryResult, MySqlDiscoveryError};
 use crate::mysql::query::TableQueryResult;
 use crate::Name;
 
 impl TableQueryResult {
-    pub fn parse(self) -> TableInfo {
+    pub fn parse(self) -> DiscoveryResult<TableInfo> {
         parse_table_query_result(self)
     }
 }
 
-pub fn parse_table_query_result(result: TableQueryResult) -> TableInfo {
-    TableInfo {
-        name: result.table_name,
-        engine: StorageEngine::from_str(result.engine.as_str()).unwrap(),
+pub fn parse_table_query_result(result: TableQueryResult) -> DiscoveryResult<TableInfo> {
+    let table = result.table_name;
+    let parse =
+        |kind, value: &str| MySqlDiscoveryError::unknown_value(kind, value).with_table(&table);
+    Ok(TableInfo {
+        engine: StorageEngine::from_str(result.engine.as_str())
+            .ok_or_else(|| parse("storage engine", &result.engine))?,
         auto_increment: result.auto_increment,
-        char_set: CharSet::from_str(result.table_char_set.as_str()).unwrap(),
-        collation: Collation::from_str(result.table_collation.as_str()).unwrap(),
+        char_set: CharSet::from_str(result.table_char_set.as_str())
+            .ok_or_else(|| parse("character set", &result.table_char_set))?,
+        collation: Collation::from_str(result.table_collation.as_str())
+            .ok_or_else(|| parse("collation", &result.table_collation))?,
         comment: result.table_comment,
-    }
+        name: table,
+    })
 }
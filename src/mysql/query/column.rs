@@ -1,5 +1,5 @@
 use super::{InformationSchema, SchemaQueryBuilder};
-use crate::sqlx_types::mysql::MySqlRow;
+use crate::{SchemaRow, SchemaRowError};
 use sea_query::{Expr, Iden, Order, Query, SeaRc, SelectStatement, Value};
 
 #[derive(Debug, sea_query::Iden)]
@@ -74,26 +74,19 @@ impl SchemaQueryBuilder {
     }
 }
 
-#[cfg(feature = "sqlx-mysql")]
-impl From<&MySqlRow> for ColumnQueryResult {
-    fn from(row: &MySqlRow) -> Self {
-        use crate::mysql::discovery::GetMySqlValue;
-        Self {
-            column_name: row.get_string(0),
-            column_type: row.get_string(1),
-            is_nullable: row.get_string(2),
-            column_key: row.get_string(3),
-            column_default: row.get_string_opt(4),
-            extra: row.get_string(5),
-            generation_expression: row.get_string_opt(6),
-            column_comment: row.get_string(7),
-        }
-    }
-}
+impl TryFrom<&SchemaRow> for ColumnQueryResult {
+    type Error = SchemaRowError;
 
-#[cfg(not(feature = "sqlx-mysql"))]
-impl From<&MySqlRow> for ColumnQueryResult {
-    fn from(_: &MySqlRow) -> Self {
-        Self::default()
+    fn try_from(row: &SchemaRow) -> Result<Self, Self::Error> {
+        Ok(Self {
+            column_name: row.try_get(0)?,
+            column_type: row.try_get(1)?,
+            is_nullable: row.try_get(2)?,
+            column_key: row.try_get(3)?,
+            column_default: row.try_get(4)?,
+            extra: row.try_get(5)?,
+            generation_expression: row.try_get(6)?,
+            column_comment: row.try_get(7)?,
+        })
     }
 }
@@ -1,5 +1,5 @@
 use super::{InformationSchema, SchemaQueryBuilder};
-use crate::sqlx_types::mysql::MySqlRow;
+use crate::{SchemaRow, SchemaRowError};
 use sea_query::{Expr, Iden, Order, Query, SeaRc, SelectStatement};
 
 #[derive(Debug, sea_query::Iden)]
@@ -38,6 +38,8 @@ pub struct ForeignKeyQueryResult {
     pub referenced_column_name: String,
     pub update_rule: String,
     pub delete_rule: String,
+    pub table_schema: String,
+    pub referenced_table_schema: String,
 }
 
 impl SchemaQueryBuilder {
@@ -60,6 +62,10 @@ impl SchemaQueryBuilder {
                 (Schema::ReferentialConstraints, Ref::UpdateRule),
                 (Schema::ReferentialConstraints, Ref::DeleteRule),
             ])
+            .columns(vec![
+                (Schema::KeyColumnUsage, Key::TableSchema),
+                (Schema::KeyColumnUsage, Key::ReferencedTableSchema),
+            ])
             .from((Schema::Schema, Schema::KeyColumnUsage))
             .inner_join(
                 (Schema::Schema, Schema::ReferentialConstraints),
@@ -82,24 +88,19 @@ impl SchemaQueryBuilder {
     }
 }
 
-#[cfg(feature = "sqlx-mysql")]
-impl From<&MySqlRow> for ForeignKeyQueryResult {
-    fn from(row: &MySqlRow) -> Self {
-        use crate::mysql::discovery::GetMySqlValue;
-        Self {
-            constraint_name: row.get_string(0),
-            column_name: row.get_string(1),
-            referenced_table_name: row.get_string(2),
-            referenced_column_name: row.get_string(3),
-            update_rule: row.get_string(4),
-            delete_rule: row.get_string(5),
-        }
-    }
-}
+impl TryFrom<&SchemaRow> for ForeignKeyQueryResult {
+    type Error = SchemaRowError;
 
-#[cfg(not(feature = "sqlx-mysql"))]
-impl From<&MySqlRow> for ForeignKeyQueryResult {
-    fn from(_: &MySqlRow) -> Self {
-        Self::default()
+    fn try_from(row: &SchemaRow) -> Result<Self, Self::Error> {
+        Ok(Self {
+            constraint_name: row.try_get(0)?,
+            column_name: row.try_get(1)?,
+            referenced_table_name: row.try_get(2)?,
+            referenced_column_name: row.try_get(3)?,
+            update_rule: row.try_get(4)?,
+            delete_rule: row.try_get(5)?,
+            table_schema: row.try_get(6)?,
+            referenced_table_schema: row.try_get(7)?,
+        })
     }
 }
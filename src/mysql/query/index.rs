@@ -1,5 +1,5 @@
 use super::{InformationSchema, SchemaQueryBuilder};
-use crate::sqlx_types::mysql::MySqlRow;
+use crate::{SchemaRow, SchemaRowError};
 use sea_query::{Expr, Iden, Order, Query, SeaRc, SelectStatement, Value};
 
 #[derive(Debug, sea_query::Iden)]
@@ -73,28 +73,20 @@ impl SchemaQueryBuilder {
     }
 }
 
-#[cfg(feature = "sqlx-mysql")]
-impl From<&MySqlRow> for IndexQueryResult {
-    fn from(row: &MySqlRow) -> Self {
-        use crate::mysql::discovery::GetMySqlValue;
-        use crate::sqlx_types::Row;
-        Self {
-            non_unique: row.get(0),
-            index_name: row.get_string(1),
-            column_name: row.get_string_opt(2),
-            collation: row.get_string_opt(3),
-            sub_part: row.get(4),
-            nullable: row.get_string(5),
-            index_type: row.get_string(6),
-            index_comment: row.get_string(7),
-            expression: row.get_string_opt(8),
-        }
-    }
-}
+impl TryFrom<&SchemaRow> for IndexQueryResult {
+    type Error = SchemaRowError;
 
-#[cfg(not(feature = "sqlx-mysql"))]
-impl From<&MySqlRow> for IndexQueryResult {
-    fn from(_: &MySqlRow) -> Self {
-        Self::default()
+    fn try_from(row: &SchemaRow) -> Result<Self, Self::Error> {
+        Ok(Self {
+            non_unique: row.try_get(0)?,
+            index_name: row.try_get(1)?,
+            column_name: row.try_get(2)?,
+            collation: row.try_get(3)?,
+            sub_part: row.try_get(4)?,
+            nullable: row.try_get(5)?,
+            index_type: row.try_get(6)?,
+            index_comment: row.try_get(7)?,
+            expression: row.try_get(8)?,
+        })
     }
 }
@@ -4,7 +4,9 @@ mod char_set;
 mod column;
 mod foreign_key;
 mod index;
+mod privilege;
 mod schema;
+mod schemata;
 mod table;
 mod version;
 
@@ -12,6 +14,8 @@ pub use char_set::*;
 pub use column::*;
 pub use foreign_key::*;
 pub use index::*;
+pub use privilege::*;
 pub use schema::*;
+pub use schemata::*;
 pub use table::*;
 pub use version::*;
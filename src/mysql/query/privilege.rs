@@ -0,0 +1,94 @@
+use super::{InformationSchema, SchemaQueryBuilder};
+use crate::{SchemaRow, SchemaRowError};
+use sea_query::{Expr, Iden, Order, Query, SeaRc, SelectStatement, Value};
+
+#[derive(Debug, sea_query::Iden)]
+/// Ref: https://dev.mysql.com/doc/refman/8.0/en/information-schema-table-privileges-table.html
+pub enum TablePrivilegesFields {
+    Grantee,
+    TableCatalog,
+    TableSchema,
+    TableName,
+    PrivilegeType,
+    IsGrantable,
+}
+
+#[derive(Debug, sea_query::Iden)]
+/// Ref: https://dev.mysql.com/doc/refman/8.0/en/information-schema-column-privileges-table.html
+pub enum ColumnPrivilegesFields {
+    Grantee,
+    TableCatalog,
+    TableSchema,
+    TableName,
+    ColumnName,
+    PrivilegeType,
+    IsGrantable,
+}
+
+#[derive(Debug, Default)]
+pub struct PrivilegeQueryResult {
+    pub grantee: String,
+    pub table_name: String,
+    pub column_name: Option<String>,
+    pub privilege_type: String,
+    pub is_grantable: String,
+}
+
+impl SchemaQueryBuilder {
+    pub fn query_table_privileges(&self, schema: SeaRc<dyn Iden>) -> SelectStatement {
+        Query::select()
+            .columns(vec![
+                TablePrivilegesFields::Grantee,
+                TablePrivilegesFields::TableName,
+            ])
+            .expr(Expr::val(Value::String(None)))
+            .columns(vec![
+                TablePrivilegesFields::PrivilegeType,
+                TablePrivilegesFields::IsGrantable,
+            ])
+            .from((
+                InformationSchema::Schema,
+                InformationSchema::TablePrivileges,
+            ))
+            .and_where(Expr::col(TablePrivilegesFields::TableSchema).eq(schema.to_string()))
+            .order_by(TablePrivilegesFields::TableName, Order::Asc)
+            .order_by(TablePrivilegesFields::Grantee, Order::Asc)
+            .order_by(TablePrivilegesFields::PrivilegeType, Order::Asc)
+            .take()
+    }
+
+    pub fn query_column_privileges(&self, schema: SeaRc<dyn Iden>) -> SelectStatement {
+        Query::select()
+            .columns(vec![
+                ColumnPrivilegesFields::Grantee,
+                ColumnPrivilegesFields::TableName,
+                ColumnPrivilegesFields::ColumnName,
+                ColumnPrivilegesFields::PrivilegeType,
+                ColumnPrivilegesFields::IsGrantable,
+            ])
+            .from((
+                InformationSchema::Schema,
+                InformationSchema::ColumnPrivileges,
+            ))
+            .and_where(Expr::col(ColumnPrivilegesFields::TableSchema).eq(schema.to_string()))
+            .order_by(ColumnPrivilegesFields::TableName, Order::Asc)
+            .order_by(ColumnPrivilegesFields::ColumnName, Order::Asc)
+            .order_by(ColumnPrivilegesFields::Grantee, Order::Asc)
+            .order_by(ColumnPrivilegesFields::PrivilegeType, Order::Asc)
+            .take()
+    }
+}
+
+impl TryFrom<&SchemaRow> for PrivilegeQueryResult {
+    type Error = SchemaRowError;
+
+    fn try_from(row: &SchemaRow) -> Result<Self, Self::Error> {
+        Ok(Self {
+            grantee: row.try_get(0)?,
+            table_name: row.try_get(1)?,
+            column_name: row.try_get(2)?,
+            privilege_type: row.try_get(3)?,
+            is_grantable: row.try_get(4)?,
+        })
+    }
+}
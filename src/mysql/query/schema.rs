@@ -1,6 +1,6 @@
 use crate::mysql::def::SystemInfo;
 
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
 pub struct SchemaQueryBuilder {
     pub system: SystemInfo,
 }
@@ -16,11 +16,14 @@ impl SchemaQueryBuilder {
 pub enum InformationSchema {
     #[iden = "information_schema"]
     Schema,
+    Schemata,
     Tables,
     Columns,
     Statistics,
     KeyColumnUsage,
     ReferentialConstraints,
+    TablePrivileges,
+    ColumnPrivileges,
     #[iden = "collation_character_set_applicability"]
     CollationCharacterSet,
 }
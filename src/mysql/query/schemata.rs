@@ -0,0 +1,46 @@
+use super::{InformationSchema, SchemaQueryBuilder};
+use crate::{SchemaRow, SchemaRowError};
+use sea_query::{Order, Query, SelectStatement};
+
+#[derive(Debug, Clone, Copy, sea_query::Iden)]
+/// Ref: https://dev.mysql.com/doc/refman/8.0/en/information-schema-schemata-table.html
+pub enum SchemataFields {
+    CatalogName,
+    SchemaName,
+    DefaultCharacterSetName,
+    DefaultCollationName,
+}
+
+#[derive(Debug, Default)]
+pub struct SchemataQueryResult {
+    pub schema_name: String,
+    pub default_character_set_name: String,
+    pub default_collation_name: String,
+}
+
+impl SchemaQueryBuilder {
+    /// List the schemas of the server, system schemas included
+    pub fn query_schemata(&self) -> SelectStatement {
+        Query::select()
+            .columns([
+                SchemataFields::SchemaName,
+                SchemataFields::DefaultCharacterSetName,
+                SchemataFields::DefaultCollationName,
+            ])
+            .from((InformationSchema::Schema, InformationSchema::Schemata))
+            .order_by(SchemataFields::SchemaName, Order::Asc)
+            .take()
+    }
+}
+
+impl TryFrom<&SchemaRow> for SchemataQueryResult {
+    type Error = SchemaRowError;
+
+    fn try_from(row: &SchemaRow) -> Result<Self, Self::Error> {
+        Ok(Self {
+            schema_name: row.try_get(0)?,
+            default_character_set_name: row.try_get(1)?,
+            default_collation_name: row.try_get(2)?,
+        })
+    }
+}
@@ -1,8 +1,8 @@
 use super::{CharacterSetFields, InformationSchema, SchemaQueryBuilder};
-use crate::sqlx_types::mysql::MySqlRow;
+use crate::{SchemaRow, SchemaRowError};
 use sea_query::{Expr, Iden, Order, Query, SeaRc, SelectStatement};
 
-#[derive(Debug, sea_query::Iden)]
+#[derive(Debug, Clone, Copy, sea_query::Iden)]
 /// Ref: https://dev.mysql.com/doc/refman/8.0/en/information-schema-tables-table.html
 pub enum TablesFields {
     TableCatalog,
@@ -86,26 +86,18 @@ impl SchemaQueryBuilder {
     }
 }
 
-#[cfg(feature = "sqlx-mysql")]
-impl From<&MySqlRow> for TableQueryResult {
-    fn from(row: &MySqlRow) -> Self {
-        use crate::mysql::discovery::GetMySqlValue;
-        use crate::sqlx_types::Row;
-        Self {
-            table_name: row.get_string(0),
-            engine: row.get_string(1),
-            auto_increment: row.get(2),
-            table_collation: row.get_string(3),
-            table_comment: row.get_string(4),
-            create_options: row.get_string(5),
-            table_char_set: row.get_string(6),
-        }
-    }
-}
+impl TryFrom<&SchemaRow> for TableQueryResult {
+    type Error = SchemaRowError;
 
-#[cfg(not(feature = "sqlx-mysql"))]
-impl From<&MySqlRow> for TableQueryResult {
-    fn from(_: &MySqlRow) -> Self {
-        Self::default()
+    fn try_from(row: &SchemaRow) -> Result<Self, Self::Error> {
+        Ok(Self {
+            table_name: row.try_get(0)?,
+            engine: row.try_get(1)?,
+            auto_increment: row.try_get(2)?,
+            table_collation: row.try_get(3)?,
+            table_comment: row.try_get(4)?,
+            create_options: row.try_get(5)?,
+            table_char_set: row.try_get(6)?,
+        })
     }
 }
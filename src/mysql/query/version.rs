@@ -1,5 +1,5 @@
 use super::SchemaQueryBuilder;
-use crate::sqlx_types::mysql::MySqlRow;
+use crate::{SchemaRow, SchemaRowError};
 use sea_query::{Func, Query, SelectStatement};
 
 #[derive(sea_query::Iden)]
@@ -18,19 +18,12 @@ impl SchemaQueryBuilder {
     }
 }
 
-#[cfg(feature = "sqlx-mysql")]
-impl From<&MySqlRow> for VersionQueryResult {
-    fn from(row: &MySqlRow) -> Self {
-        use crate::mysql::discovery::GetMySqlValue;
-        Self {
-            version: row.get_string(0),
-        }
-    }
-}
+impl TryFrom<&SchemaRow> for VersionQueryResult {
+    type Error = SchemaRowError;
 
-#[cfg(not(feature = "sqlx-mysql"))]
-impl From<&MySqlRow> for VersionQueryResult {
-    fn from(_: &MySqlRow) -> Self {
-        Self::default()
+    fn try_from(row: &SchemaRow) -> Result<Self, Self::Error> {
+        Ok(Self {
+            version: row.try_get(0)?,
+        })
     }
 }
@@ -4,8 +4,11 @@ use sea_query::{Alias, ForeignKey, ForeignKeyCreateStatement};
 impl ForeignKeyInfo {
     pub fn write(&self) -> ForeignKeyCreateStatement {
         let mut key = ForeignKey::create();
-        key.name(&self.name)
-            .to_tbl(Alias::new(&self.referenced_table));
+        key.name(&self.name);
+        match &self.referenced_schema {
+            Some(schema) => key.to_tbl((Alias::new(schema), Alias::new(&self.referenced_table))),
+            None => key.to_tbl(Alias::new(&self.referenced_table)),
+        };
         for column in self.columns.iter() {
             key.from_col(Alias::new(column.as_str()));
         }
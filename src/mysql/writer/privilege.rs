@@ -0,0 +1,70 @@
+use crate::mysql::def::{PrivilegeInfo, Schema};
+use crate::util::quote_iden;
+use sea_query::{MysqlQueryBuilder, QuotedBuilder};
+
+impl PrivilegeInfo {
+    /// Converts the [PrivilegeInfo] into a `GRANT` statement
+    pub fn write(&self) -> String {
+        let mut sql = format!("GRANT {}", self.privilege);
+        if let Some(column) = &self.column {
+            sql.push_str(&format!(
+                " ({})",
+                quote_iden(column, MysqlQueryBuilder.quote())
+            ));
+        }
+        sql.push_str(&format!(
+            " ON {} TO {}",
+            quote_iden(&self.table, MysqlQueryBuilder.quote()),
+            self.grantee
+        ));
+        if self.grantable {
+            sql.push_str(" WITH GRANT OPTION");
+        }
+        sql
+    }
+}
+
+impl Schema {
+    /// Write the `GRANT` statements of all table and column privileges
+    pub fn write_privileges(&self) -> Vec<String> {
+        self.privileges
+            .iter()
+            .map(|privilege| privilege.write())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::mysql::def::*;
+
+    #[test]
+    fn test_1() {
+        assert_eq!(
+            PrivilegeInfo {
+                grantee: "'reporting'@'%'".to_owned(),
+                table: "actor".to_owned(),
+                column: None,
+                privilege: "SELECT".to_owned(),
+                grantable: false,
+            }
+            .write(),
+            "GRANT SELECT ON `actor` TO 'reporting'@'%'"
+        );
+    }
+
+    #[test]
+    fn test_2() {
+        assert_eq!(
+            PrivilegeInfo {
+                grantee: "'clerk'@'localhost'".to_owned(),
+                table: "customer".to_owned(),
+                column: Some("email".to_owned()),
+                privilege: "UPDATE".to_owned(),
+                grantable: true,
+            }
+            .write(),
+            "GRANT UPDATE (`email`) ON `customer` TO 'clerk'@'localhost' WITH GRANT OPTION"
+        );
+    }
+}
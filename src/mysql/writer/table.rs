@@ -272,6 +272,7 @@ mod tests {
                         columns: vec![
                             "actor_id".to_owned(),
                         ],
+                        referenced_schema: None,
                         referenced_table: "actor".to_owned(),
                         referenced_columns: vec![
                             "actor_id".to_owned(),
@@ -284,6 +285,7 @@ mod tests {
                         columns: vec![
                             "film_id".to_owned(),
                         ],
+                        referenced_schema: None,
                         referenced_table: "film".to_owned(),
                         referenced_columns: vec![
                             "film_id".to_owned(),
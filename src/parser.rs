@@ -6,6 +6,8 @@ pub struct Parser {
     pub tokens: Tokenizer,
     pub curr: Option<Token>,
     pub last: Option<Token>,
+    /// Whether whitespace preceded the current token
+    pub space: bool,
 }
 
 impl Parser {
@@ -14,6 +16,7 @@ impl Parser {
             tokens: Tokenizer::new(string),
             curr: None,
             last: None,
+            space: false,
         }
     }
 
@@ -35,8 +38,10 @@ impl Parser {
             self.last = std::mem::take(&mut self.curr);
         }
 
+        self.space = false;
         if let Some(tok) = self.tokens.next() {
             if tok.is_space() {
+                self.space = true;
                 if let Some(tok) = self.tokens.next() {
                     self.curr = Some(tok);
                 }
@@ -94,4 +99,34 @@ impl Parser {
     pub fn curr_as_str(&mut self) -> &str {
         self.curr().unwrap().as_str()
     }
+
+    /// Consume a parenthesized group, returning the text between the outermost parentheses
+    /// with every run of whitespace collapsed into a single space
+    pub fn next_balanced(&mut self) -> Option<String> {
+        if !self.next_if_punctuation("(") {
+            return None;
+        }
+        let mut string = String::new();
+        let mut depth = 1;
+        loop {
+            let space = self.space;
+            let (is_punctuation, tok) = match self.curr() {
+                Some(tok) => (tok.is_punctuation(), tok.to_string()),
+                None => return None,
+            };
+            self.next();
+            if is_punctuation && tok == "(" {
+                depth += 1;
+            } else if is_punctuation && tok == ")" {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(string);
+                }
+            }
+            if space && !string.is_empty() {
+                string.push(' ');
+            }
+            string.push_str(&tok);
+        }
+    }
 }
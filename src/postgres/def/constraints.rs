@@ -64,6 +64,8 @@ pub struct PrimaryKey {
 pub struct References {
     pub name: String,
     pub columns: Vec<String>,
+    /// The schema of the referenced table, if not the schema of the table
+    pub schema: Option<String>,
     pub table: String,
     pub foreign_columns: Vec<String>,
     pub on_update: Option<ForeignKeyAction>,
@@ -0,0 +1,14 @@
+#[cfg(feature = "with-serde")]
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
+/// An extension installed in the database, as listed in `pg_extension`
+pub struct ExtensionDef {
+    /// The name of the extension, e.g. `vector` or `postgis`
+    pub name: String,
+    /// The schema containing the objects of the extension
+    pub schema: String,
+    /// The installed version of the extension
+    pub version: String,
+}
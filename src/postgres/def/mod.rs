@@ -1,11 +1,17 @@
 mod column;
 mod constraints;
+mod extension;
+mod policy;
+mod privilege;
 mod schema;
 mod table;
 mod types;
 
 pub use column::*;
 pub use constraints::*;
+pub use extension::*;
+pub use policy::*;
+pub use privilege::*;
 pub use schema::*;
 pub use table::*;
 pub use types::*;
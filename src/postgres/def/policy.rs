@@ -0,0 +1,55 @@
+#[cfg(feature = "with-serde")]
+use serde::{Deserialize, Serialize};
+
+use crate as sea_schema;
+
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
+/// The row-level security flags of a table, `relrowsecurity` and `relforcerowsecurity` of `pg_class`
+pub struct RowLevelSecurity {
+    /// Row-level security is enabled on the table
+    pub enabled: bool,
+    /// Row-level security also applies to the table owner
+    pub forced: bool,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
+/// A row-level security policy of a table
+pub struct Policy {
+    pub name: String,
+    /// The command the policy applies to
+    pub command: PolicyCommand,
+    /// Whether the policy is combined with others using OR (permissive) or AND (restrictive)
+    pub mode: PolicyMode,
+    /// The roles the policy applies to; `public` means all roles
+    pub roles: Vec<String>,
+    /// The expression rows must satisfy to be visible, the USING clause
+    pub using: Option<String>,
+    /// The expression new rows must satisfy, the WITH CHECK clause
+    pub with_check: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, sea_query::Iden, sea_schema_derive::Name)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
+pub enum PolicyCommand {
+    #[iden = "ALL"]
+    All,
+    #[iden = "SELECT"]
+    Select,
+    #[iden = "INSERT"]
+    Insert,
+    #[iden = "UPDATE"]
+    Update,
+    #[iden = "DELETE"]
+    Delete,
+}
+
+#[derive(Clone, Debug, PartialEq, sea_query::Iden, sea_schema_derive::Name)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
+pub enum PolicyMode {
+    #[iden = "PERMISSIVE"]
+    Permissive,
+    #[iden = "RESTRICTIVE"]
+    Restrictive,
+}
@@ -5,9 +5,22 @@ use super::*;
 
 #[derive(Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
+/// The schemas of a database. The extensions of the database are kept here,
+/// and left out of the schemas
+pub struct Database {
+    pub extensions: Vec<ExtensionDef>,
+    pub schemas: Vec<Schema>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
+/// A discovered schema. To recreate it, run the statements of [Schema::write_extensions] first,
+/// as the tables may use the types the extensions define, then those of [Schema::write]
 pub struct Schema {
     pub schema: String,
+    pub extensions: Vec<ExtensionDef>,
     pub tables: Vec<TableDef>,
+    pub privileges: Vec<PrivilegeInfo>,
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -22,6 +35,9 @@ pub struct TableDef {
     pub primary_key_constraints: Vec<PrimaryKey>,
     pub reference_constraints: Vec<References>,
     pub exclusion_constraints: Vec<Exclusion>,
+
+    pub row_level_security: RowLevelSecurity,
+    pub policies: Vec<Policy>,
     // FIXME: Duplication? TableInfo also have of_type
     // pub of_type: Option<Type>,
     // TODO:
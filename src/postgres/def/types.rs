@@ -120,6 +120,29 @@ pub enum Type {
     /// The postgres vector type introduced by the vector extension.
     Vector(VectorDef),
 
+    #[cfg(feature = "postgres-postgis")]
+    /// Planar spatial data introduced by the PostGIS extension
+    Geometry(GeometryDef),
+    #[cfg(feature = "postgres-postgis")]
+    /// Geodetic spatial data on a spheroid introduced by the PostGIS extension
+    Geography(GeometryDef),
+
+    #[cfg(feature = "postgres-citext")]
+    /// Case-insensitive character string introduced by the citext extension
+    CiText,
+    #[cfg(feature = "postgres-hstore")]
+    /// Set of key/value pairs introduced by the hstore extension
+    HStore,
+    #[cfg(feature = "postgres-ltree")]
+    /// Label path in a tree-like structure introduced by the ltree extension
+    LTree,
+    #[cfg(feature = "postgres-ltree")]
+    /// Regular-expression-like pattern for matching ltree values introduced by the ltree extension
+    LQuery,
+    #[cfg(feature = "postgres-cube")]
+    /// Multi-dimensional cube introduced by the cube extension
+    Cube,
+
     // TODO:
     // /// The structure of a row or record; a list of field names and types
     // Composite,
@@ -137,6 +160,24 @@ pub enum Type {
     TsTzRange,
     /// Range of a date
     DateRange,
+    /// A user-defined range type
+    Range(RangeDef),
+
+    // Multirange types
+    /// Multirange of an integer
+    Int4MultiRange,
+    /// Multirange of a bigint
+    Int8MultiRange,
+    /// Multirange of a numeric
+    NumMultiRange,
+    /// Multirange of a timestamp without time zone
+    TsMultiRange,
+    /// Multirange of a timestamp with time zone
+    TsTzMultiRange,
+    /// Multirange of a date
+    DateMultiRange,
+    /// The multirange of a user-defined range type
+    MultiRange(MultiRangeDef),
 
     // TODO:
     // /// A user-defined data type that is based on another underlying type with optional constraints
@@ -169,14 +210,16 @@ impl Type {
             "bigserial" | "serial8" => Type::BigSerial,
             "money" => Type::Money,
             "character varying" | "varchar" => Type::Varchar(StringAttr::default()),
-            "character" | "char" => Type::Char(StringAttr::default()),
+            "character" | "char" | "bpchar" => Type::Char(StringAttr::default()),
             "text" => Type::Text,
             "bytea" => Type::Bytea,
             "timestamp" | "timestamp without time zone" => Type::Timestamp(TimeAttr::default()),
-            "timestamp with time zone" => Type::TimestampWithTimeZone(TimeAttr::default()),
+            "timestamp with time zone" | "timestamptz" => {
+                Type::TimestampWithTimeZone(TimeAttr::default())
+            }
             "date" => Type::Date,
             "time" | "time without time zone" => Type::Time(TimeAttr::default()),
-            "time with time zone" => Type::TimeWithTimeZone(TimeAttr::default()),
+            "time with time zone" | "timetz" => Type::TimeWithTimeZone(TimeAttr::default()),
             "interval" => Type::Interval(IntervalAttr::default()),
             "boolean" | "bool" => Type::Boolean,
             "point" => Type::Point,
@@ -205,14 +248,41 @@ impl Type {
             "tsrange" => Type::TsRange,
             "tstzrange" => Type::TsTzRange,
             "daterange" => Type::DateRange,
+            "int4multirange" => Type::Int4MultiRange,
+            "int8multirange" => Type::Int8MultiRange,
+            "nummultirange" => Type::NumMultiRange,
+            "tsmultirange" => Type::TsMultiRange,
+            "tstzmultirange" => Type::TsTzMultiRange,
+            "datemultirange" => Type::DateMultiRange,
             // "" => Type::Domain,
             "pg_lsn" => Type::PgLsn,
             "user-defined" if is_enum => Type::Enum(EnumDef::default()),
             "user-defined" if !is_enum && udt_name.is_some() => {
-                Type::Unknown(udt_name.unwrap().to_owned())
+                Type::from_udt_name(udt_name.unwrap())
             }
             "array" => Type::Array(ArrayDef::default()),
-            _ => Type::Unknown(column_type.to_owned()),
+            _ => Type::from_udt_name(column_type),
+        }
+    }
+
+    /// Types provided by extensions, which are reported as `USER-DEFINED` data types
+    fn from_udt_name(udt_name: &str) -> Type {
+        match udt_name {
+            #[cfg(feature = "postgres-postgis")]
+            "geometry" => Type::Geometry(GeometryDef::default()),
+            #[cfg(feature = "postgres-postgis")]
+            "geography" => Type::Geography(GeometryDef::default()),
+            #[cfg(feature = "postgres-citext")]
+            "citext" => Type::CiText,
+            #[cfg(feature = "postgres-hstore")]
+            "hstore" => Type::HStore,
+            #[cfg(feature = "postgres-ltree")]
+            "ltree" => Type::LTree,
+            #[cfg(feature = "postgres-ltree")]
+            "lquery" => Type::LQuery,
+            #[cfg(feature = "postgres-cube")]
+            "cube" => Type::Cube,
+            _ => Type::Unknown(udt_name.to_owned()),
         }
     }
 }
@@ -270,6 +340,30 @@ pub struct EnumDef {
 pub struct ArrayDef {
     /// Array type
     pub col_type: Option<RcOrArc<Type>>,
+    /// The declared number of dimensions, e.g. 2 for `integer[][]`
+    pub dimensions: u32,
+}
+
+/// Defines a user-defined range type, as listed in `pg_range`
+#[derive(Clone, Debug, PartialEq, Default)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
+pub struct RangeDef {
+    /// The name of the range type, qualified by its schema when not on the search path
+    pub typename: String,
+    /// The type of the range bounds
+    pub subtype: Option<RcOrArc<Type>>,
+    /// The name of the multirange type of the range, since PostgreSQL 14
+    pub multirange: Option<String>,
+}
+
+/// Defines the multirange type of a user-defined range type
+#[derive(Clone, Debug, PartialEq, Default)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
+pub struct MultiRangeDef {
+    /// The name of the multirange type, qualified by its schema when not on the search path
+    pub typename: String,
+    /// The range type of the multirange
+    pub range: RangeDef,
 }
 
 #[cfg(feature = "postgres-vector")]
@@ -280,6 +374,33 @@ pub struct VectorDef {
     pub length: Option<u32>,
 }
 
+#[cfg(feature = "postgres-postgis")]
+/// Defines a PostGIS geometry or geography type, e.g. `geometry(PointZ,4326)`
+#[derive(Clone, Debug, PartialEq, Default)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
+pub struct GeometryDef {
+    /// The geometry subtype, e.g. `Point` or `MultiPolygon`; `None` accepts any geometry
+    pub subtype: Option<String>,
+    /// The spatial reference system identifier; `None` if unconstrained
+    pub srid: Option<u32>,
+    /// The coordinate dimensions of the geometry
+    pub dims: GeometryDims,
+}
+
+#[cfg(feature = "postgres-postgis")]
+#[derive(Clone, Debug, PartialEq, Default)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
+pub enum GeometryDims {
+    #[default]
+    Xy,
+    /// With a Z coordinate, e.g. `PointZ`
+    Xyz,
+    /// With an M (measure) coordinate, e.g. `PointM`
+    Xym,
+    /// With both Z and M coordinates, e.g. `PointZM`
+    Xyzm,
+}
+
 impl Type {
     pub fn has_numeric_attr(&self) -> bool {
         matches!(self, Type::Numeric(_) | Type::Decimal(_))
@@ -315,8 +436,17 @@ impl Type {
         matches!(self, Type::Array(_))
     }
 
+    pub fn has_range_attr(&self) -> bool {
+        matches!(self, Type::Range(_) | Type::MultiRange(_))
+    }
+
     #[cfg(feature = "postgres-vector")]
     pub fn has_vector_attr(&self) -> bool {
         matches!(self, Type::Vector(_))
     }
+
+    #[cfg(feature = "postgres-postgis")]
+    pub fn has_geometry_attr(&self) -> bool {
+        matches!(self, Type::Geometry(_) | Type::Geography(_))
+    }
 }
@@ -0,0 +1,122 @@
+use futures::future;
+
+use super::{
+    enum_variant_map, range_def_map, DiscoveryResult, Executor, IntoExecutor,
+    PostgresDiscoveryError, SchemaDiscovery,
+};
+use crate::postgres::def::Database;
+use crate::postgres::query::{NamespaceQueryResult, PgNamespace, SchemaQueryBuilder};
+use crate::warning::Warnings;
+use crate::{debug_print, DiscoveryWarning, SchemaFilter, TableFilter};
+
+/// Discovers every schema of a database, each with a [SchemaDiscovery]
+pub struct DatabaseDiscovery<'c> {
+    pub query: SchemaQueryBuilder,
+    pub executor: Executor<'c>,
+    /// The schemas to discover. By default every schema but the system ones:
+    /// `pg_catalog`, `information_schema`, `pg_toast` and the temporary schemas
+    pub filter: SchemaFilter,
+    /// The tables to discover in every schema
+    pub table_filter: TableFilter,
+    warnings: Warnings,
+}
+
+impl<'c> DatabaseDiscovery<'c> {
+    pub fn new<E>(executor: E) -> Self
+    where
+        E: IntoExecutor<'c>,
+    {
+        Self {
+            query: SchemaQueryBuilder::default(),
+            executor: executor.into_executor(),
+            filter: SchemaFilter::new()
+                .exclude(["pg_catalog", "information_schema"])
+                .exclude_glob(["pg_toast*", "pg_temp_*"]),
+            table_filter: TableFilter::default(),
+            warnings: Warnings::default(),
+        }
+    }
+
+    /// Only discover the schemas passing the filter, which replaces the default one
+    pub fn with_filter(mut self, filter: SchemaFilter) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    /// Only discover the tables passing the filter, in every schema
+    pub fn with_table_filter(mut self, filter: TableFilter) -> Self {
+        self.table_filter = filter;
+        self
+    }
+
+    /// Discover the schemas concurrently, in the order of their names. The extensions, enums and
+    /// ranges belong to the database, so they are discovered once for all the schemas
+    pub async fn discover(&self) -> DiscoveryResult<Database> {
+        // These queries are not restricted to the schema of the discovery
+        let database = self.schema_discovery("");
+        let extensions = database.discover_extensions().await?;
+        let enums = enum_variant_map(database.discover_enum_rows().await?);
+        let ranges = range_def_map(database.discover_range_rows().await?);
+
+        let names = self.discover_schema_names().await?;
+        let schemas = future::try_join_all(names.iter().map(|name| {
+            let (enums, ranges) = (&enums, &ranges);
+            async move {
+                self.schema_discovery(name)
+                    .discover_with_types(enums, ranges)
+                    .await
+            }
+        }))
+        .await?;
+
+        Ok(Database {
+            extensions,
+            schemas,
+        })
+    }
+
+    /// Discover the schemas, skipping what can't be discovered instead of failing, as
+    /// [SchemaDiscovery::discover_lenient] does. An error of the executor still fails the
+    /// discovery
+    pub async fn discover_lenient(&self) -> DiscoveryResult<(Database, Vec<DiscoveryWarning>)> {
+        let lenient = DatabaseDiscovery {
+            query: self.query.clone(),
+            executor: self.executor.borrow(),
+            filter: self.filter.clone(),
+            table_filter: self.table_filter.clone(),
+            warnings: Warnings::lenient(),
+        };
+        let database = lenient.discover().await?;
+        Ok((database, lenient.warnings.take()))
+    }
+
+    /// The discovery of a schema, sharing the executor, table filter and warnings of this one
+    fn schema_discovery(&self, schema: &str) -> SchemaDiscovery<'_> {
+        SchemaDiscovery {
+            query: self.query.clone(),
+            filter: self.table_filter.clone(),
+            warnings: self.warnings.clone(),
+            ..SchemaDiscovery::new(self.executor.borrow(), schema)
+        }
+    }
+
+    /// List the schemas passing the filter
+    pub async fn discover_schema_names(&self) -> DiscoveryResult<Vec<String>> {
+        let mut select = self.query.query_schemas();
+        self.filter.apply(&mut select, PgNamespace::NspName);
+        let rows = self.executor.fetch_all(select).await?;
+
+        rows.iter()
+            .map(|row| {
+                let result = NamespaceQueryResult::try_from(row)
+                    .map_err(|err| PostgresDiscoveryError::from_row(err, row, None, None))?;
+                debug_print!("{:?}", result);
+                Ok(result.name)
+            })
+            .filter(|name| match name {
+                Ok(name) => self.filter.is_included(name),
+                Err(_) => true,
+            })
+            .collect()
+    }
+}
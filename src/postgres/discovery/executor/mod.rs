@@ -1,9 +1,74 @@
+use futures::future::BoxFuture;
+use sea_query::SelectStatement;
+
+use crate::SchemaRow;
+pub use crate::{sqlx_types::SqlxError, ExecutorError};
+
 #[cfg(feature = "sqlx-postgres")]
 mod real;
-#[cfg(feature = "sqlx-postgres")]
-pub use real::*;
 
-#[cfg(not(feature = "sqlx-postgres"))]
-mod mock;
-#[cfg(not(feature = "sqlx-postgres"))]
-pub use mock::*;
+/// Runs the queries of the schema discovery on a Postgres database.
+/// Implemented for `PgPool` with the `sqlx-postgres` feature, which also provides [IntoExecutor]
+/// for a borrowed connection or transaction, so that the discovery runs in that session.
+/// Can be implemented to discover through any other driver or connection
+pub trait SchemaExecutor: Send + Sync {
+    /// Run a query, to be built with `PostgresQueryBuilder`, and fetch all the rows
+    fn fetch_all(
+        &self,
+        select: SelectStatement,
+    ) -> BoxFuture<'_, Result<Vec<SchemaRow>, ExecutorError>>;
+}
+
+impl<E> SchemaExecutor for &E
+where
+    E: SchemaExecutor + ?Sized,
+{
+    fn fetch_all(
+        &self,
+        select: SelectStatement,
+    ) -> BoxFuture<'_, Result<Vec<SchemaRow>, ExecutorError>> {
+        (**self).fetch_all(select)
+    }
+}
+
+/// The executor the discovery runs on, which may borrow a connection for `'c`
+pub struct Executor<'c> {
+    executor: Box<dyn SchemaExecutor + 'c>,
+}
+
+pub trait IntoExecutor<'c> {
+    fn into_executor(self) -> Executor<'c>;
+}
+
+impl<'c, E> IntoExecutor<'c> for E
+where
+    E: SchemaExecutor + 'c,
+{
+    fn into_executor(self) -> Executor<'c> {
+        Executor {
+            executor: Box::new(self),
+        }
+    }
+}
+
+impl<'c> IntoExecutor<'c> for Executor<'c> {
+    fn into_executor(self) -> Executor<'c> {
+        self
+    }
+}
+
+impl Executor<'_> {
+    /// An executor running the queries on this one, to share it between discoveries
+    pub(crate) fn borrow(&self) -> Executor<'_> {
+        Executor {
+            executor: Box::new(&*self.executor),
+        }
+    }
+
+    pub async fn fetch_all(
+        &self,
+        select: SelectStatement,
+    ) -> Result<Vec<SchemaRow>, ExecutorError> {
+        self.executor.fetch_all(select).await
+    }
+}
@@ -1,30 +1,116 @@
+use futures::{future::BoxFuture, lock::Mutex};
 use sea_query::{PostgresQueryBuilder, SelectStatement};
 use sea_query_binder::SqlxBinder;
-use sqlx::{postgres::PgRow, PgPool};
+use sqlx::{
+    pool::PoolConnection,
+    postgres::{types::Oid, PgRow},
+    PgConnection, PgPool, Postgres, Row, Transaction, ValueRef,
+};
 
-use crate::{debug_print, sqlx_types::SqlxError};
+use super::{Executor, IntoExecutor, SchemaExecutor};
+use crate::{debug_print, ExecutorError, SchemaRow, SchemaValue};
 
-pub struct Executor {
-    pool: PgPool,
+impl SchemaExecutor for PgPool {
+    fn fetch_all(
+        &self,
+        select: SelectStatement,
+    ) -> BoxFuture<'_, Result<Vec<SchemaRow>, ExecutorError>> {
+        Box::pin(fetch_all(self, select))
+    }
+}
+
+/// A borrowed connection, locked for each query as the discovery runs them concurrently
+struct ConnectionExecutor<'c> {
+    connection: Mutex<&'c mut PgConnection>,
+}
+
+impl SchemaExecutor for ConnectionExecutor<'_> {
+    fn fetch_all(
+        &self,
+        select: SelectStatement,
+    ) -> BoxFuture<'_, Result<Vec<SchemaRow>, ExecutorError>> {
+        Box::pin(async move {
+            let mut connection = self.connection.lock().await;
+            fetch_all(&mut **connection, select).await
+        })
+    }
 }
 
-pub trait IntoExecutor {
-    fn into_executor(self) -> Executor;
+impl<'c> IntoExecutor<'c> for &'c mut PgConnection {
+    fn into_executor(self) -> Executor<'c> {
+        ConnectionExecutor {
+            connection: Mutex::new(self),
+        }
+        .into_executor()
+    }
+}
+
+impl<'c> IntoExecutor<'c> for &'c mut Transaction<'_, Postgres> {
+    fn into_executor(self) -> Executor<'c> {
+        (&mut **self).into_executor()
+    }
 }
 
-impl IntoExecutor for PgPool {
-    fn into_executor(self) -> Executor {
-        Executor { pool: self }
+impl<'c> IntoExecutor<'c> for &'c mut PoolConnection<Postgres> {
+    fn into_executor(self) -> Executor<'c> {
+        (&mut **self).into_executor()
     }
 }
 
-impl Executor {
-    pub async fn fetch_all(&self, select: SelectStatement) -> Result<Vec<PgRow>, SqlxError> {
-        let (sql, values) = select.build_sqlx(PostgresQueryBuilder);
-        debug_print!("{}, {:?}", sql, values);
+async fn fetch_all<'e, E>(
+    executor: E,
+    select: SelectStatement,
+) -> Result<Vec<SchemaRow>, ExecutorError>
+where
+    E: sqlx::Executor<'e, Database = Postgres>,
+{
+    let (sql, values) = select.build_sqlx(PostgresQueryBuilder);
+    debug_print!("{}, {:?}", sql, values);
+
+    sqlx::query_with(&sql, values)
+        .fetch_all(executor)
+        .await?
+        .iter()
+        .map(schema_row)
+        .collect()
+}
 
-        sqlx::query_with(&sql, values)
-            .fetch_all(&mut *self.pool.acquire().await?)
-            .await
+/// Convert a row, trying the types the catalog columns can have
+fn schema_row(row: &PgRow) -> Result<SchemaRow, ExecutorError> {
+    let mut values = Vec::with_capacity(row.len());
+    for index in 0..row.len() {
+        let value = if row.try_get_raw(index)?.is_null() {
+            SchemaValue::Null
+        } else if let Ok(string) = row.try_get::<String, _>(index) {
+            SchemaValue::String(string)
+        } else if let Ok(bool) = row.try_get::<bool, _>(index) {
+            SchemaValue::Bool(bool)
+        } else if let Ok(int) = row.try_get::<i64, _>(index) {
+            SchemaValue::Int(int)
+        } else if let Ok(int) = row.try_get::<i32, _>(index) {
+            SchemaValue::Int(int.into())
+        } else if let Ok(int) = row.try_get::<i16, _>(index) {
+            SchemaValue::Int(int.into())
+        } else if let Ok(int) = row.try_get::<i8, _>(index) {
+            // The "char" type
+            SchemaValue::Int(int.into())
+        } else if let Ok(oid) = row.try_get::<Oid, _>(index) {
+            SchemaValue::UInt(oid.0.into())
+        } else if let Ok(float) = row.try_get::<f64, _>(index) {
+            SchemaValue::Float(float)
+        } else if let Ok(float) = row.try_get::<f32, _>(index) {
+            SchemaValue::Float(float.into())
+        } else if let Ok(strings) = row.try_get::<Vec<Option<String>>, _>(index) {
+            SchemaValue::Array(
+                strings
+                    .into_iter()
+                    .map(|string| string.map_or(SchemaValue::Null, SchemaValue::String))
+                    .collect(),
+            )
+        } else {
+            SchemaValue::Bytes(row.try_get::<Vec<u8>, _>(index)?)
+        };
+        values.push(value);
     }
+    Ok(SchemaRow::new(values))
 }
@@ -1,101 +1,274 @@
 //! To query & parse MySQL's INFORMATION_SCHEMA and construct a [`Schema`]
 
-use crate::debug_print;
 use crate::postgres::def::*;
 use crate::postgres::parser::{
     parse_table_constraint_query_results, parse_unique_index_query_results,
 };
 use crate::postgres::query::{
-    ColumnQueryResult, EnumQueryResult, SchemaQueryBuilder, TableConstraintsQueryResult,
-    TableQueryResult, UniqueIndexQueryResult,
+    ColumnQueryResult, EnumQueryResult, ExtensionQueryResult, PolicyQueryResult,
+    PrivilegeQueryResult, RangeQueryResult, RowLevelSecurityQueryResult, SchemaQueryBuilder,
+    TableConstraintsQueryResult, TableQueryResult, TablesFields, UniqueIndexQueryResult,
 };
-use crate::sqlx_types::SqlxError;
+use crate::warning::Warnings;
+use crate::{debug_print, DiscoveryWarning, TableFilter};
 use futures::future;
-use sea_query::{Alias, Iden, IntoIden, SeaRc};
+use sea_query::{Alias, Iden, IntoIden, SeaRc, SelectStatement};
 use std::collections::HashMap;
+use std::sync::Arc;
 
+mod database;
 mod executor;
+pub use super::error::{DiscoveryResult, PostgresDiscoveryError};
+pub use database::*;
 pub use executor::*;
 
-pub(crate) type EnumVariantMap = HashMap<String, Vec<String>>;
+/// A user-defined type, by the name of its schema and its own unqualified name
+pub(crate) type TypeKey = (String, String);
 
-pub struct SchemaDiscovery {
+pub(crate) type EnumVariantMap = HashMap<TypeKey, Vec<String>>;
+
+/// Map every enum type to its values
+pub(crate) fn enum_variant_map(enums: Vec<EnumQueryResult>) -> EnumVariantMap {
+    enums.into_iter().fold(HashMap::new(), |mut map, result| {
+        map.entry((result.schema, result.typename))
+            .or_insert_with(Vec::new)
+            .push(result.enumlabel);
+        map
+    })
+}
+
+pub(crate) type RangeDefMap = HashMap<TypeKey, RangeDef>;
+
+/// Map every range and multirange type to the definition of its range
+pub(crate) fn range_def_map(ranges: Vec<RangeQueryResult>) -> RangeDefMap {
+    ranges
+        .into_iter()
+        .flat_map(|result| {
+            let range_key = (result.schema.clone(), result.name.clone());
+            let multirange_key = result
+                .multirange_schema
+                .clone()
+                .zip(result.multirange_name.clone());
+            let range_def = result.parse();
+            let multirange = multirange_key.map(|key| (key, range_def.clone()));
+            std::iter::once((range_key, range_def)).chain(multirange)
+        })
+        .collect()
+}
+
+pub struct SchemaDiscovery<'c> {
     pub query: SchemaQueryBuilder,
-    pub executor: Executor,
+    pub executor: Executor<'c>,
     pub schema: SeaRc<dyn Iden>,
+    /// The tables to discover
+    pub filter: TableFilter,
+    table_predicates: Vec<TablePredicate<'c>>,
+    warnings: Warnings,
 }
 
-impl SchemaDiscovery {
+type TablePredicate<'c> = Arc<dyn Fn(&TableInfo) -> bool + Send + Sync + 'c>;
+
+impl<'c> SchemaDiscovery<'c> {
     pub fn new<E>(executor: E, schema: &str) -> Self
     where
-        E: IntoExecutor,
+        E: IntoExecutor<'c>,
     {
         Self {
             query: SchemaQueryBuilder::default(),
             executor: executor.into_executor(),
             schema: Alias::new(schema).into_iden(),
+            filter: TableFilter::default(),
+            table_predicates: Vec::new(),
+            warnings: Warnings::default(),
         }
     }
 
-    pub async fn discover(&self) -> Result<Schema, SqlxError> {
-        let enums: EnumVariantMap = self
-            .discover_enums()
-            .await?
-            .into_iter()
-            .map(|enum_def| (enum_def.typename, enum_def.values))
-            .collect();
+    /// Only discover the tables passing the filter
+    pub fn with_filter(mut self, filter: TableFilter) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    /// Only discover the tables for which the predicate holds, e.g. by the type they are of.
+    /// Can be called multiple times, a table has to pass every predicate.
+    /// Unlike the filter, predicates can't be pushed into the query listing the tables
+    pub fn with_table_predicate<F>(mut self, predicate: F) -> Self
+    where
+        F: Fn(&TableInfo) -> bool + Send + Sync + 'c,
+    {
+        self.table_predicates.push(Arc::new(predicate));
+        self
+    }
+
+    pub async fn discover(&self) -> DiscoveryResult<Schema> {
+        let extensions = self.discover_extensions().await?;
+        let enums = enum_variant_map(self.discover_enum_rows().await?);
+        let ranges = range_def_map(self.discover_range_rows().await?);
+        let schema = self.discover_with_types(&enums, &ranges).await?;
+
+        Ok(Schema {
+            extensions,
+            ..schema
+        })
+    }
+
+    /// Discover the schema, given the enum and range types of the database.
+    /// The extensions, which belong to the database, are left out
+    pub(crate) async fn discover_with_types(
+        &self,
+        enums: &EnumVariantMap,
+        ranges: &RangeDefMap,
+    ) -> DiscoveryResult<Schema> {
+        let tables = self.discover_tables().await?;
+        let table_names: Vec<_> = tables.iter().map(|table| table.name.clone()).collect();
         let tables = future::try_join_all(
-            self.discover_tables()
-                .await?
+            tables
                 .into_iter()
-                .map(|t| (self, t, &enums))
+                .map(|t| (self, t, enums, ranges))
                 .map(Self::discover_table_static),
         )
-        .await?;
+        .await?
+        .into_iter()
+        .flatten()
+        .collect();
+        let mut privileges = self.discover_privileges().await?;
+        if !self.table_predicates.is_empty() {
+            privileges.retain(|privilege| table_names.contains(&privilege.table));
+        }
 
         Ok(Schema {
             schema: self.schema.to_string(),
+            extensions: Vec::new(),
             tables,
+            privileges,
         })
     }
 
-    pub async fn discover_tables(&self) -> Result<Vec<TableInfo>, SqlxError> {
+    /// Discover the schema, skipping what can't be discovered instead of failing.
+    /// Every table, column, constraint, index, policy, privilege or type skipped, and every column
+    /// of an unknown type, is reported as a warning. An error of the executor still fails the
+    /// discovery
+    pub async fn discover_lenient(&self) -> DiscoveryResult<(Schema, Vec<DiscoveryWarning>)> {
+        let lenient = self.lenient();
+        let schema = lenient.discover().await?;
+        Ok((schema, lenient.warnings.take()))
+    }
+
+    /// A discovery sharing the executor and filter of this one, collecting its own warnings
+    fn lenient(&self) -> SchemaDiscovery<'_> {
+        SchemaDiscovery {
+            query: self.query.clone(),
+            executor: self.executor.borrow(),
+            schema: self.schema.clone(),
+            filter: self.filter.clone(),
+            table_predicates: self.table_predicates.clone(),
+            warnings: Warnings::lenient(),
+        }
+    }
+
+    pub async fn discover_extensions(&self) -> DiscoveryResult<Vec<ExtensionDef>> {
         let rows = self
             .executor
-            .fetch_all(self.query.query_tables(self.schema.clone()))
+            .fetch_all(self.query.query_extensions())
             .await?;
 
-        let tables: Vec<TableInfo> = rows
-            .iter()
+        rows.iter()
             .map(|row| {
-                let result: TableQueryResult = row.into();
+                let result = ExtensionQueryResult::try_from(row)
+                    .map_err(|err| PostgresDiscoveryError::from_row(err, row, None, None))?;
+                debug_print!("{:?}", result);
+                let extension = result.parse();
+                debug_print!("{:?}", extension);
+                Ok(extension)
+            })
+            .filter_map(|result| self.warnings.recover(result).transpose())
+            .collect()
+    }
+
+    pub async fn discover_tables(&self) -> DiscoveryResult<Vec<TableInfo>> {
+        let rows = self.executor.fetch_all(self.query_tables()).await?;
+
+        rows.iter()
+            .map(|row| {
+                let result = TableQueryResult::try_from(row)
+                    .map_err(|err| PostgresDiscoveryError::from_row(err, row, Some(0), None))?;
                 debug_print!("{:?}", result);
                 let table = result.parse();
                 debug_print!("{:?}", table);
-                table
+                Ok(table)
+            })
+            .filter_map(|result| self.warnings.recover(result).transpose())
+            .filter(|table| match table {
+                Ok(table) => self.is_discovered(table),
+                Err(_) => true,
             })
-            .collect();
+            .collect()
+    }
 
-        Ok(tables)
+    /// The query listing the tables, restricted by the filter
+    fn query_tables(&self) -> SelectStatement {
+        let mut select = self.query.query_tables(self.schema.clone());
+        self.filter.apply(&mut select, TablesFields::TableName);
+        select
+    }
+
+    /// Whether the table passes the filter and the predicates
+    fn is_discovered(&self, table: &TableInfo) -> bool {
+        self.filter.is_included(&table.name)
+            && self
+                .table_predicates
+                .iter()
+                .all(|predicate| predicate(table))
+    }
+
+    pub async fn discover_privileges(&self) -> DiscoveryResult<Vec<PrivilegeInfo>> {
+        let mut rows = self
+            .executor
+            .fetch_all(self.query.query_table_privileges(self.schema.clone()))
+            .await?;
+        rows.extend(
+            self.executor
+                .fetch_all(self.query.query_column_privileges(self.schema.clone()))
+                .await?,
+        );
+
+        rows.iter()
+            .map(|row| {
+                let result = PrivilegeQueryResult::try_from(row)
+                    .map_err(|err| PostgresDiscoveryError::from_row(err, row, Some(1), Some(2)))?;
+                debug_print!("{:?}", result);
+                let privilege = result.parse();
+                debug_print!("{:?}", privilege);
+                Ok(privilege)
+            })
+            .filter_map(|result| self.warnings.recover(result).transpose())
+            .filter(|privilege| match privilege {
+                Ok(privilege) => self.filter.is_included(&privilege.table),
+                Err(_) => true,
+            })
+            .collect()
     }
 
     async fn discover_table_static(
-        params: (&Self, TableInfo, &EnumVariantMap),
-    ) -> Result<TableDef, SqlxError> {
+        params: (&Self, TableInfo, &EnumVariantMap, &RangeDefMap),
+    ) -> DiscoveryResult<Option<TableDef>> {
         let this = params.0;
         let info = params.1;
         let enums = params.2;
-        Self::discover_table(this, info, enums).await
+        let ranges = params.3;
+        this.warnings
+            .recover(Self::discover_table(this, info, enums, ranges).await)
     }
 
     pub async fn discover_table(
         &self,
         info: TableInfo,
         enums: &EnumVariantMap,
-    ) -> Result<TableDef, SqlxError> {
+        ranges: &RangeDefMap,
+    ) -> DiscoveryResult<TableDef> {
         let table = SeaRc::new(Alias::new(info.name.as_str()));
         let columns = self
-            .discover_columns(self.schema.clone(), table.clone(), enums)
+            .discover_columns(self.schema.clone(), table.clone(), enums, ranges)
             .await?;
         let constraints = self
             .discover_constraints(self.schema.clone(), table.clone())
@@ -125,6 +298,14 @@ impl SchemaDiscovery {
             .discover_unique_indexes(self.schema.clone(), table.clone())
             .await?;
 
+        let row_level_security = self
+            .discover_row_level_security(self.schema.clone(), table.clone())
+            .await?;
+
+        let policies = self
+            .discover_policies(self.schema.clone(), table.clone())
+            .await?;
+
         Ok(TableDef {
             info,
             columns,
@@ -134,6 +315,8 @@ impl SchemaDiscovery {
             primary_key_constraints,
             reference_constraints,
             exclusion_constraints,
+            row_level_security,
+            policies,
         })
     }
 
@@ -142,106 +325,219 @@ impl SchemaDiscovery {
         schema: SeaRc<dyn Iden>,
         table: SeaRc<dyn Iden>,
         enums: &EnumVariantMap,
-    ) -> Result<Vec<ColumnInfo>, SqlxError> {
+        ranges: &RangeDefMap,
+    ) -> DiscoveryResult<Vec<ColumnInfo>> {
+        let table_name = table.to_string();
         let rows = self
             .executor
             .fetch_all(self.query.query_columns(schema.clone(), table.clone()))
-            .await?;
+            .await
+            .map_err(|err| PostgresDiscoveryError::from(err).with_table(&table_name))?;
 
-        Ok(rows
-            .into_iter()
+        rows.iter()
             .map(|row| {
-                let result: ColumnQueryResult = (&row).into();
+                let result = ColumnQueryResult::try_from(row).map_err(|err| {
+                    PostgresDiscoveryError::from_row(err, row, None, Some(0))
+                        .with_table(&table_name)
+                })?;
                 debug_print!("{:?}", result);
-                let column = result.parse(enums);
+                let column = result.parse(enums, ranges);
                 debug_print!("{:?}", column);
-                column
+                if let Type::Unknown(name) = &column.col_type {
+                    self.warnings.warn(
+                        DiscoveryWarning::new("Unknown column type")
+                            .table(&table_name)
+                            .column(&column.name)
+                            .value(name),
+                    );
+                }
+                Ok(column)
             })
-            .collect())
+            .filter_map(|result| self.warnings.recover(result).transpose())
+            .collect()
     }
 
     pub async fn discover_constraints(
         &self,
         schema: SeaRc<dyn Iden>,
         table: SeaRc<dyn Iden>,
-    ) -> Result<Vec<Constraint>, SqlxError> {
+    ) -> DiscoveryResult<Vec<Constraint>> {
+        let table_name = table.to_string();
         let rows = self
             .executor
             .fetch_all(
                 self.query
                     .query_table_constraints(schema.clone(), table.clone()),
             )
-            .await?;
+            .await
+            .map_err(|err| PostgresDiscoveryError::from(err).with_table(&table_name))?;
 
-        let results = rows.into_iter().map(|row| {
-            let result: TableConstraintsQueryResult = (&row).into();
-            debug_print!("{:?}", result);
-            result
-        });
+        let results = rows
+            .iter()
+            .map(|row| {
+                let result = TableConstraintsQueryResult::try_from(row).map_err(|err| {
+                    PostgresDiscoveryError::from_row(err, row, Some(3), Some(8))
+                        .with_table(&table_name)
+                })?;
+                debug_print!("{:?}", result);
+                Ok(result)
+            })
+            .filter_map(|result| self.warnings.recover(result).transpose())
+            .collect::<DiscoveryResult<Vec<_>>>()?;
 
-        Ok(parse_table_constraint_query_results(Box::new(results))
-            .map(|index| {
-                debug_print!("{:?}", index);
-                index
+        parse_table_constraint_query_results(Box::new(results.into_iter()))
+            .map(|constraint| {
+                let constraint = constraint.map_err(|err| err.with_table(&table_name))?;
+                debug_print!("{:?}", constraint);
+                Ok(constraint)
             })
-            .collect())
+            .filter_map(|result| self.warnings.recover(result).transpose())
+            .collect()
     }
 
     pub async fn discover_unique_indexes(
         &self,
         schema: SeaRc<dyn Iden>,
         table: SeaRc<dyn Iden>,
-    ) -> Result<Vec<Unique>, SqlxError> {
+    ) -> DiscoveryResult<Vec<Unique>> {
+        let table_name = table.to_string();
         let rows = self
             .executor
             .fetch_all(
                 self.query
                     .query_table_unique_indexes(schema.clone(), table.clone()),
             )
-            .await?;
+            .await
+            .map_err(|err| PostgresDiscoveryError::from(err).with_table(&table_name))?;
 
-        let results = rows.into_iter().map(|row| {
-            let result: UniqueIndexQueryResult = (&row).into();
-            debug_print!("{:?}", result);
-            result
-        });
+        let results = rows
+            .iter()
+            .map(|row| {
+                let result = UniqueIndexQueryResult::try_from(row).map_err(|err| {
+                    PostgresDiscoveryError::from_row(err, row, Some(2), Some(3))
+                        .with_table(&table_name)
+                })?;
+                debug_print!("{:?}", result);
+                Ok(result)
+            })
+            .filter_map(|result| self.warnings.recover(result).transpose())
+            .collect::<DiscoveryResult<Vec<_>>>()?;
+
+        let indexes: Vec<_> =
+            parse_unique_index_query_results(Box::new(results.into_iter())).collect();
+        debug_print!("{:?}", indexes);
+        Ok(indexes)
+    }
+
+    pub async fn discover_row_level_security(
+        &self,
+        schema: SeaRc<dyn Iden>,
+        table: SeaRc<dyn Iden>,
+    ) -> DiscoveryResult<RowLevelSecurity> {
+        let table_name = table.to_string();
+        let rows = self
+            .executor
+            .fetch_all(
+                self.query
+                    .query_table_row_level_security(schema.clone(), table.clone()),
+            )
+            .await
+            .map_err(|err| PostgresDiscoveryError::from(err).with_table(&table_name))?;
+
+        match rows.first() {
+            Some(row) => {
+                let result = RowLevelSecurityQueryResult::try_from(row).map_err(|err| {
+                    PostgresDiscoveryError::from_row(err, row, None, None).with_table(&table_name)
+                })?;
+                debug_print!("{:?}", result);
+                let row_level_security = result.parse();
+                debug_print!("{:?}", row_level_security);
+                Ok(row_level_security)
+            }
+            None => Ok(RowLevelSecurity::default()),
+        }
+    }
+
+    pub async fn discover_policies(
+        &self,
+        schema: SeaRc<dyn Iden>,
+        table: SeaRc<dyn Iden>,
+    ) -> DiscoveryResult<Vec<Policy>> {
+        let table_name = table.to_string();
+        let rows = self
+            .executor
+            .fetch_all(
+                self.query
+                    .query_table_policies(schema.clone(), table.clone()),
+            )
+            .await
+            .map_err(|err| PostgresDiscoveryError::from(err).with_table(&table_name))?;
 
-        Ok(parse_unique_index_query_results(Box::new(results))
-            .map(|index| {
-                debug_print!("{:?}", index);
-                index
+        rows.iter()
+            .map(|row| {
+                let result = PolicyQueryResult::try_from(row).map_err(|err| {
+                    PostgresDiscoveryError::from_row(err, row, None, None).with_table(&table_name)
+                })?;
+                debug_print!("{:?}", result);
+                let policy = result.parse().map_err(|err| err.with_table(&table_name))?;
+                debug_print!("{:?}", policy);
+                Ok(policy)
             })
+            .filter_map(|result| self.warnings.recover(result).transpose())
+            .collect()
+    }
+
+    /// Discover the enum types of the database. Enums of the same name in different schemas are
+    /// discovered separately
+    pub async fn discover_enums(&self) -> DiscoveryResult<Vec<EnumDef>> {
+        let map = enum_variant_map(self.discover_enum_rows().await?);
+
+        Ok(map
+            .into_iter()
+            .map(|((_, typename), values)| EnumDef { values, typename })
             .collect())
     }
 
-    pub async fn discover_enums(&self) -> Result<Vec<EnumDef>, SqlxError> {
+    /// Discover the values of the enum types of the database, one row per value
+    pub(crate) async fn discover_enum_rows(&self) -> DiscoveryResult<Vec<EnumQueryResult>> {
         let rows = self.executor.fetch_all(self.query.query_enums()).await?;
 
-        let enum_rows = rows.into_iter().map(|row| {
-            let result: EnumQueryResult = (&row).into();
-            debug_print!("{:?}", result);
-            result
-        });
-
-        let map = enum_rows.fold(
-            HashMap::new(),
-            |mut map: HashMap<String, Vec<String>>,
-             EnumQueryResult {
-                 typename,
-                 enumlabel,
-             }| {
-                if let Some(entry_exists) = map.get_mut(&typename) {
-                    entry_exists.push(enumlabel);
-                } else {
-                    map.insert(typename, vec![enumlabel]);
-                }
-                map
-            },
-        );
+        rows.iter()
+            .map(|row| {
+                let result = EnumQueryResult::try_from(row)
+                    .map_err(|err| PostgresDiscoveryError::from_row(err, row, None, None))?;
+                debug_print!("{:?}", result);
+                Ok(result)
+            })
+            .filter_map(|result| self.warnings.recover(result).transpose())
+            .collect()
+    }
 
-        Ok(map
+    pub async fn discover_ranges(&self) -> DiscoveryResult<Vec<RangeDef>> {
+        let rows = self.discover_range_rows().await?;
+
+        Ok(rows
             .into_iter()
-            .map(|(typename, values)| EnumDef { values, typename })
+            .map(|result| {
+                let range_def = result.parse();
+                debug_print!("{:?}", range_def);
+                range_def
+            })
             .collect())
     }
+
+    /// Discover the user-defined range types of the database
+    pub(crate) async fn discover_range_rows(&self) -> DiscoveryResult<Vec<RangeQueryResult>> {
+        let rows = self.executor.fetch_all(self.query.query_ranges()).await?;
+
+        rows.iter()
+            .map(|row| {
+                let result = RangeQueryResult::try_from(row)
+                    .map_err(|err| PostgresDiscoveryError::from_row(err, row, None, None))?;
+                debug_print!("{:?}", result);
+                Ok(result)
+            })
+            .filter_map(|result| self.warnings.recover(result).transpose())
+            .collect()
+    }
 }
@@ -0,0 +1,26 @@
+use crate::SchemaValue;
+
+crate::error::discovery_error! {
+    /// All the errors that can be encountered when discovering a Postgres schema.
+    pub enum PostgresDiscoveryError {
+        /// A row returned by a query of the catalog does not have the expected shape
+        UnexpectedRow,
+        /// A value that sea-schema does not know
+        UnknownValue,
+    }
+}
+
+impl PostgresDiscoveryError {
+    /// A NULL in the column `index` of a row, where the discovery needs a string
+    pub(crate) fn null_value(index: usize) -> Self {
+        Self::UnexpectedRow {
+            table: None,
+            column: None,
+            error: SchemaRowError::UnexpectedValue {
+                index,
+                expected: std::any::type_name::<String>(),
+                value: SchemaValue::Null,
+            },
+        }
+    }
+}
@@ -1,18 +1,25 @@
 use crate::postgres::{
-    def::*, discovery::EnumVariantMap, parser::yes_or_no_to_bool, query::ColumnQueryResult,
+    def::*,
+    discovery::{EnumVariantMap, RangeDefMap, TypeKey},
+    parser::yes_or_no_to_bool,
+    query::ColumnQueryResult,
 };
 use sea_query::RcOrArc;
 
 impl ColumnQueryResult {
-    pub fn parse(self, enums: &EnumVariantMap) -> ColumnInfo {
-        parse_column_query_result(self, enums)
+    pub fn parse(self, enums: &EnumVariantMap, ranges: &RangeDefMap) -> ColumnInfo {
+        parse_column_query_result(self, enums, ranges)
     }
 }
 
-pub fn parse_column_query_result(result: ColumnQueryResult, enums: &EnumVariantMap) -> ColumnInfo {
+pub fn parse_column_query_result(
+    result: ColumnQueryResult,
+    enums: &EnumVariantMap,
+    ranges: &RangeDefMap,
+) -> ColumnInfo {
     ColumnInfo {
         name: result.column_name.clone(),
-        col_type: parse_column_type(&result, enums),
+        col_type: parse_column_type(&result, enums, ranges),
         default: ColumnExpression::from_option_string(result.column_default),
         generated: ColumnExpression::from_option_string(result.column_generated),
         not_null: NotNull::from_bool(!yes_or_no_to_bool(&result.is_nullable)),
@@ -20,16 +27,32 @@ pub fn parse_column_query_result(result: ColumnQueryResult, enums: &EnumVariantM
     }
 }
 
-pub fn parse_column_type(result: &ColumnQueryResult, enums: &EnumVariantMap) -> ColumnType {
-    let is_enum = result
-        .udt_name
+pub fn parse_column_type(
+    result: &ColumnQueryResult,
+    enums: &EnumVariantMap,
+    ranges: &RangeDefMap,
+) -> ColumnType {
+    // The user-defined types are looked up by their schema, as types of different schemas can
+    // share a name
+    let udt_key = type_key(result.udt_schema.as_deref(), result.udt_name.as_deref());
+    let is_enum = udt_key
         .as_ref()
-        .map_or(false, |udt_name| enums.contains_key(udt_name));
-    let mut ctype = Type::from_str(
-        result.column_type.as_str(),
-        result.udt_name.as_deref(),
-        is_enum,
-    );
+        .map_or(false, |key| enums.contains_key(key));
+    // The ranges are named as `format_type` writes them, maybe qualified by their schema
+    let typename = result
+        .formatted_type
+        .as_deref()
+        .or(result.udt_name.as_deref());
+    let range_def = typename.zip(udt_key.as_ref().and_then(|key| ranges.get(key)));
+    let mut ctype = if let Some((typename, range_def)) = range_def {
+        range_type(typename, range_def)
+    } else {
+        Type::from_str(
+            result.column_type.as_str(),
+            result.udt_name.as_deref(),
+            is_enum,
+        )
+    };
 
     if ctype.has_numeric_attr() {
         ctype = parse_numeric_attributes(
@@ -52,19 +75,46 @@ pub fn parse_column_type(result: &ColumnQueryResult, enums: &EnumVariantMap) ->
         ctype = parse_bit_attributes(result.character_maximum_length, ctype);
     }
     if ctype.has_enum_attr() {
-        ctype = parse_enum_attributes(result.udt_name.as_deref(), ctype, enums);
+        ctype = parse_enum_attributes(udt_key.as_ref(), ctype, enums);
+    }
+    if ctype.has_range_attr() {
+        ctype = parse_range_attributes(typename, udt_key.as_ref(), ctype, ranges);
     }
     if ctype.has_array_attr() {
-        ctype = parse_array_attributes(result.udt_name_regtype.as_deref(), ctype, enums);
+        ctype = parse_array_attributes(
+            result
+                .array_element_type
+                .as_deref()
+                .or(result.udt_name_regtype.as_deref()),
+            // An array type is in the schema of its element type
+            type_key(
+                result.udt_schema.as_deref(),
+                result.array_element_name.as_deref(),
+            )
+            .as_ref(),
+            result.array_dimensions,
+            ctype,
+            enums,
+            ranges,
+        );
     }
     #[cfg(feature = "postgres-vector")]
     if ctype.has_vector_attr() {
         ctype = parse_vector_attributes(result.character_maximum_length, ctype);
     }
+    #[cfg(feature = "postgres-postgis")]
+    if ctype.has_geometry_attr() {
+        ctype = parse_geometry_attributes(result.formatted_type.as_deref(), ctype);
+    }
 
     ctype
 }
 
+/// The key of a user-defined type in the enum and range maps
+fn type_key(schema: Option<&str>, name: Option<&str>) -> Option<TypeKey> {
+    Some((schema?.to_owned(), name?.to_owned()))
+}
+
 pub fn parse_numeric_attributes(
     num_precision: Option<i32>,
     num_precision_radix: Option<i32>,
@@ -195,18 +245,18 @@ pub fn parse_bit_attributes(
     ctype
 }
 
+/// Resolve an enum type, given its schema and name
 pub fn parse_enum_attributes(
-    udt_name: Option<&str>,
+    key: Option<&TypeKey>,
     mut ctype: ColumnType,
     enums: &EnumVariantMap,
 ) -> ColumnType {
     match ctype {
         Type::Enum(ref mut def) => {
-            def.typename = match udt_name {
-                None => panic!("parse_enum_attributes(_) received an empty udt_name"),
-                Some(typename) => typename.to_string(),
-            };
-            if let Some(variants) = enums.get(&def.typename) {
+            if let Some((_, typename)) = key {
+                def.typename = typename.to_string();
+            }
+            if let Some(variants) = key.and_then(|key| enums.get(key)) {
                 def.values.clone_from(variants);
             }
         }
@@ -216,22 +266,82 @@ pub fn parse_enum_attributes(
     ctype
 }
 
+/// The range type named `typename`, or the multirange type if `typename` names the multirange
+fn range_type(typename: &str, range_def: &RangeDef) -> ColumnType {
+    if range_def.typename == typename {
+        Type::Range(range_def.clone())
+    } else {
+        Type::MultiRange(MultiRangeDef {
+            typename: typename.to_owned(),
+            range: range_def.clone(),
+        })
+    }
+}
+
+/// Resolve a user-defined range or multirange type, given the name of either as `format_type`
+/// writes it, and its schema and unqualified name
+pub fn parse_range_attributes(
+    typename: Option<&str>,
+    key: Option<&TypeKey>,
+    mut ctype: ColumnType,
+    ranges: &RangeDefMap,
+) -> ColumnType {
+    let range_def = key.and_then(|key| ranges.get(key));
+    match ctype {
+        Type::Range(ref mut def) => {
+            if let Some(typename) = typename {
+                def.typename = typename.to_string();
+            }
+            if let Some(range_def) = range_def {
+                def.clone_from(range_def);
+            }
+        }
+        Type::MultiRange(ref mut def) => {
+            if let Some(typename) = typename {
+                def.typename = typename.to_string();
+            }
+            if let Some(range_def) = range_def {
+                def.range.clone_from(range_def);
+            }
+        }
+        _ => panic!("parse_range_attributes(_) received a type that does not have RangeDef"),
+    };
+
+    ctype
+}
+
+/// Resolve the element type of an array, given either the name of the element type or the
+/// `regtype` of the array, e.g. `integer[]`, and the schema and unqualified name of the element
+/// type
 pub fn parse_array_attributes(
-    udt_name_regtype: Option<&str>,
+    element_type: Option<&str>,
+    element_key: Option<&TypeKey>,
+    dimensions: Option<i32>,
     mut ctype: ColumnType,
     enums: &EnumVariantMap,
+    ranges: &RangeDefMap,
 ) -> ColumnType {
     match ctype {
         Type::Array(ref mut def) => {
-            def.col_type = match udt_name_regtype {
-                None => panic!("parse_array_attributes(_) received an empty udt_name_regtype"),
+            def.dimensions = match dimensions {
+                Some(num) if num > 1 => u32::try_from(num).unwrap_or(1),
+                _ => 1,
+            };
+            // The element type is unknown if the catalog does not report it
+            def.col_type = match element_type {
+                None => None,
                 Some(typename) => {
-                    let typename = &typename.replacen('"', "", 2).replacen("[]", "", 1);
-                    let arr_col_type = if let Some(variants) = enums.get(typename) {
+                    let typename = typename.strip_suffix("[]").unwrap_or(typename);
+                    // The enums are named without their schema, any other type is kept as written
+                    let variants = element_key.and_then(|key| Some((&key.1, enums.get(key)?)));
+                    let range_def = element_key.and_then(|key| ranges.get(key));
+                    let arr_col_type = if let Some((name, variants)) = variants {
                         Type::Enum(EnumDef {
-                            typename: typename.to_string(),
+                            typename: name.clone(),
                             values: variants.clone(),
                         })
+                    } else if let Some(range_def) = range_def {
+                        range_type(typename, range_def)
                     } else {
                         Type::from_str(typename, Some(typename), false)
                     };
@@ -265,3 +375,320 @@ pub fn parse_vector_attributes(
 
     ctype
 }
+
+#[cfg(feature = "postgres-postgis")]
+/// Parse the type modifiers of a PostGIS type, e.g. `geometry(PointZ,4326)`
+pub fn parse_geometry_attributes(
+    formatted_type: Option<&str>,
+    mut ctype: ColumnType,
+) -> ColumnType {
+    match ctype {
+        Type::Geometry(ref mut def) | Type::Geography(ref mut def) => {
+            let modifiers = formatted_type
+                .and_then(|string| string.split_once('('))
+                .and_then(|(_, modifiers)| modifiers.strip_suffix(')'));
+            if let Some(modifiers) = modifiers {
+                let mut parts = modifiers.split(',').map(str::trim);
+                if let Some(subtype) = parts.next() {
+                    let (subtype, dims) = if let Some(subtype) = subtype.strip_suffix("ZM") {
+                        (subtype, GeometryDims::Xyzm)
+                    } else if let Some(subtype) = subtype.strip_suffix('Z') {
+                        (subtype, GeometryDims::Xyz)
+                    } else if let Some(subtype) = subtype.strip_suffix('M') {
+                        (subtype, GeometryDims::Xym)
+                    } else {
+                        (subtype, GeometryDims::Xy)
+                    };
+                    def.subtype = Some(subtype.to_owned());
+                    def.dims = dims;
+                }
+                def.srid = parts.next().and_then(|srid| srid.parse().ok());
+            }
+        }
+        _ => panic!("parse_geometry_attributes(_) received a type that does not have GeometryDef"),
+    };
+
+    ctype
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "postgres-postgis")]
+    fn test_geometry_attributes() {
+        assert_eq!(
+            parse_geometry_attributes(
+                Some("geometry(PointZ,4326)"),
+                Type::Geometry(GeometryDef::default())
+            ),
+            Type::Geometry(GeometryDef {
+                subtype: Some("Point".to_owned()),
+                srid: Some(4326),
+                dims: GeometryDims::Xyz,
+            })
+        );
+        assert_eq!(
+            parse_geometry_attributes(
+                Some("geography(MultiPolygonM)"),
+                Type::Geography(GeometryDef::default())
+            ),
+            Type::Geography(GeometryDef {
+                subtype: Some("MultiPolygon".to_owned()),
+                srid: None,
+                dims: GeometryDims::Xym,
+            })
+        );
+        assert_eq!(
+            parse_geometry_attributes(Some("geometry"), Type::Geometry(GeometryDef::default())),
+            Type::Geometry(GeometryDef::default())
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "postgres-citext", feature = "postgres-ltree"))]
+    fn test_extension_types() {
+        let enums = EnumVariantMap::new();
+        let ranges = RangeDefMap::new();
+        assert_eq!(
+            Type::from_str("USER-DEFINED", Some("citext"), false),
+            Type::CiText
+        );
+        assert_eq!(
+            parse_array_attributes(
+                Some("ltree[]"),
+                Some(&key("public", "ltree")),
+                None,
+                Type::Array(ArrayDef::default()),
+                &enums,
+                &ranges
+            ),
+            Type::Array(ArrayDef {
+                col_type: Some(RcOrArc::new(Type::LTree)),
+                dimensions: 1,
+            })
+        );
+    }
+
+    fn key(schema: &str, name: &str) -> TypeKey {
+        (schema.to_owned(), name.to_owned())
+    }
+
+    #[test]
+    fn test_range_attributes() {
+        let enums = EnumVariantMap::new();
+        let float_range = RangeDef {
+            typename: "floatrange".to_owned(),
+            subtype: Some(RcOrArc::new(Type::DoublePrecision)),
+            multirange: Some("floatmultirange".to_owned()),
+        };
+        let ranges: RangeDefMap = [
+            (key("public", "floatrange"), float_range.clone()),
+            (key("public", "floatmultirange"), float_range.clone()),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(
+            parse_range_attributes(
+                Some("floatrange"),
+                Some(&key("public", "floatrange")),
+                Type::Range(RangeDef::default()),
+                &ranges
+            ),
+            Type::Range(float_range.clone())
+        );
+        let float_multirange = MultiRangeDef {
+            typename: "floatmultirange".to_owned(),
+            range: float_range.clone(),
+        };
+        assert_eq!(
+            parse_range_attributes(
+                Some("floatmultirange"),
+                Some(&key("public", "floatmultirange")),
+                Type::MultiRange(MultiRangeDef::default()),
+                &ranges
+            ),
+            Type::MultiRange(float_multirange.clone())
+        );
+        // A range of the same name in another schema is not resolved
+        assert_eq!(
+            parse_range_attributes(
+                Some("audit.floatrange"),
+                Some(&key("audit", "floatrange")),
+                Type::Range(RangeDef::default()),
+                &ranges
+            ),
+            Type::Range(RangeDef {
+                typename: "audit.floatrange".to_owned(),
+                ..Default::default()
+            })
+        );
+        assert_eq!(
+            parse_array_attributes(
+                Some("floatrange[]"),
+                Some(&key("public", "floatrange")),
+                None,
+                Type::Array(ArrayDef::default()),
+                &enums,
+                &ranges
+            ),
+            Type::Array(ArrayDef {
+                col_type: Some(RcOrArc::new(Type::Range(float_range.clone()))),
+                dimensions: 1,
+            })
+        );
+        assert_eq!(
+            parse_array_attributes(
+                Some("floatmultirange"),
+                Some(&key("public", "floatmultirange")),
+                None,
+                Type::Array(ArrayDef::default()),
+                &enums,
+                &ranges
+            ),
+            Type::Array(ArrayDef {
+                col_type: Some(RcOrArc::new(Type::MultiRange(float_multirange))),
+                dimensions: 1,
+            })
+        );
+        assert_eq!(
+            parse_array_attributes(
+                Some("tstzmultirange[]"),
+                Some(&key("pg_catalog", "tstzmultirange")),
+                None,
+                Type::Array(ArrayDef::default()),
+                &enums,
+                &ranges
+            ),
+            Type::Array(ArrayDef {
+                col_type: Some(RcOrArc::new(Type::TsTzMultiRange)),
+                dimensions: 1,
+            })
+        );
+    }
+
+    #[test]
+    fn test_enum_attributes() {
+        let enums: EnumVariantMap = [
+            (
+                key("public", "mood"),
+                vec!["happy".to_owned(), "sad".to_owned()],
+            ),
+            (
+                key("audit", "mood"),
+                vec!["closed".to_owned(), "open".to_owned()],
+            ),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(
+            parse_enum_attributes(
+                Some(&key("audit", "mood")),
+                Type::Enum(EnumDef::default()),
+                &enums
+            ),
+            Type::Enum(EnumDef {
+                values: vec!["closed".to_owned(), "open".to_owned()],
+                typename: "mood".to_owned(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_array_attributes() {
+        let enums: EnumVariantMap = [
+            (
+                key("public", "mood"),
+                vec!["happy".to_owned(), "sad".to_owned()],
+            ),
+            (
+                key("audit", "Status"),
+                vec!["closed".to_owned(), "open".to_owned()],
+            ),
+        ]
+        .into_iter()
+        .collect();
+        let ranges = RangeDefMap::new();
+        assert_eq!(
+            parse_array_attributes(
+                Some("timestamptz"),
+                Some(&key("pg_catalog", "timestamptz")),
+                Some(2),
+                Type::Array(ArrayDef::default()),
+                &enums,
+                &ranges
+            ),
+            Type::Array(ArrayDef {
+                col_type: Some(RcOrArc::new(Type::TimestampWithTimeZone(
+                    TimeAttr::default()
+                ))),
+                dimensions: 2,
+            })
+        );
+        assert_eq!(
+            parse_array_attributes(
+                Some("mood"),
+                Some(&key("public", "mood")),
+                Some(0),
+                Type::Array(ArrayDef::default()),
+                &enums,
+                &ranges
+            ),
+            Type::Array(ArrayDef {
+                col_type: Some(RcOrArc::new(Type::Enum(EnumDef {
+                    values: vec!["happy".to_owned(), "sad".to_owned()],
+                    typename: "mood".to_owned(),
+                }))),
+                dimensions: 1,
+            })
+        );
+        // An enum of another schema, as `format_type` writes it
+        assert_eq!(
+            parse_array_attributes(
+                Some(r#"audit."Status""#),
+                Some(&key("audit", "Status")),
+                Some(1),
+                Type::Array(ArrayDef::default()),
+                &enums,
+                &ranges
+            ),
+            Type::Array(ArrayDef {
+                col_type: Some(RcOrArc::new(Type::Enum(EnumDef {
+                    values: vec!["closed".to_owned(), "open".to_owned()],
+                    typename: "Status".to_owned(),
+                }))),
+                dimensions: 1,
+            })
+        );
+        // A domain, and a type of another schema that is not an enum
+        assert_eq!(
+            parse_array_attributes(
+                Some("positive_int"),
+                Some(&key("public", "positive_int")),
+                Some(1),
+                Type::Array(ArrayDef::default()),
+                &enums,
+                &ranges
+            ),
+            Type::Array(ArrayDef {
+                col_type: Some(RcOrArc::new(Type::Unknown("positive_int".to_owned()))),
+                dimensions: 1,
+            })
+        );
+        assert_eq!(
+            parse_array_attributes(
+                Some(r#"audit."Mood""#),
+                Some(&key("audit", "Mood")),
+                Some(1),
+                Type::Array(ArrayDef::default()),
+                &enums,
+                &ranges
+            ),
+            Type::Array(ArrayDef {
+                col_type: Some(RcOrArc::new(Type::Unknown(r#"audit."Mood""#.to_owned()))),
+                dimensions: 1,
+            })
+        );
+    }
+}
@@ -0,0 +1,15 @@
+use crate::postgres::{def::*, query::ExtensionQueryResult};
+
+impl ExtensionQueryResult {
+    pub fn parse(self) -> ExtensionDef {
+        parse_extension_query_result(self)
+    }
+}
+
+pub fn parse_extension_query_result(result: ExtensionQueryResult) -> ExtensionDef {
+    ExtensionDef {
+        name: result.name,
+        schema: result.schema,
+        version: result.version,
+    }
+}
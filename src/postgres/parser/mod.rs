@@ -1,10 +1,19 @@
 mod column;
+mod extension;
 mod pg_indexes;
+mod policy;
+mod privilege;
+mod range;
 mod table;
 mod table_constraints;
 
+pub use super::error::{DiscoveryResult, PostgresDiscoveryError};
 pub use column::*;
+pub use extension::*;
 pub use pg_indexes::*;
+pub use policy::*;
+pub use privilege::*;
+pub use range::*;
 pub use table::*;
 pub use table_constraints::*;
 
@@ -0,0 +1,67 @@
+use crate::postgres::error::{DiscoveryResult, PostgresDiscoveryError};
+use crate::postgres::{
+    def::*,
+    query::{PolicyQueryResult, RowLevelSecurityQueryResult},
+};
+use crate::Name;
+
+impl RowLevelSecurityQueryResult {
+    pub fn parse(self) -> RowLevelSecurity {
+        parse_row_level_security_query_result(self)
+    }
+}
+
+pub fn parse_row_level_security_query_result(
+    result: RowLevelSecurityQueryResult,
+) -> RowLevelSecurity {
+    RowLevelSecurity {
+        enabled: result.row_security,
+        forced: result.force_row_security,
+    }
+}
+
+impl PolicyQueryResult {
+    pub fn parse(self) -> DiscoveryResult<Policy> {
+        parse_policy_query_result(self)
+    }
+}
+
+/// Parse a policy. An unknown command or mode is an error rather than taken as the broadest one,
+/// which would grant more when written back
+pub fn parse_policy_query_result(result: PolicyQueryResult) -> DiscoveryResult<Policy> {
+    let command = PolicyCommand::from_str(&result.cmd)
+        .ok_or_else(|| PostgresDiscoveryError::unknown_value("policy command", &result.cmd))?;
+    let mode = PolicyMode::from_str(&result.permissive)
+        .ok_or_else(|| PostgresDiscoveryError::unknown_value("policy mode", &result.permissive))?;
+    Ok(Policy {
+        name: result.policy_name,
+        command,
+        mode,
+        roles: result.roles,
+        using: result.qual,
+        with_check: result.with_check,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unknown_command() {
+        let result = |cmd: &str| PolicyQueryResult {
+            policy_name: "tenant_isolation".to_owned(),
+            permissive: "PERMISSIVE".to_owned(),
+            cmd: cmd.to_owned(),
+            ..Default::default()
+        };
+        assert_eq!(
+            result("SELECT").parse().unwrap().command,
+            PolicyCommand::Select
+        );
+        assert_eq!(
+            result("MERGE").parse().unwrap_err().to_string(),
+            r#"Unknown policy command: "MERGE""#
+        );
+    }
+}
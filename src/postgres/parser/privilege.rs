@@ -0,0 +1,17 @@
+use crate::postgres::{def::*, query::PrivilegeQueryResult};
+
+impl PrivilegeQueryResult {
+    pub fn parse(self) -> PrivilegeInfo {
+        parse_privilege_query_result(self)
+    }
+}
+
+pub fn parse_privilege_query_result(result: PrivilegeQueryResult) -> PrivilegeInfo {
+    PrivilegeInfo {
+        grantee: result.grantee,
+        table: result.table_name,
+        column: result.column_name,
+        privilege: result.privilege_type,
+        grantable: result.is_grantable,
+    }
+}
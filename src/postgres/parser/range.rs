@@ -0,0 +1,20 @@
+use crate::postgres::{def::*, query::RangeQueryResult};
+use sea_query::RcOrArc;
+
+impl RangeQueryResult {
+    pub fn parse(self) -> RangeDef {
+        parse_range_query_result(self)
+    }
+}
+
+pub fn parse_range_query_result(result: RangeQueryResult) -> RangeDef {
+    RangeDef {
+        subtype: Some(RcOrArc::new(Type::from_str(
+            &result.subtype,
+            Some(&result.subtype),
+            false,
+        ))),
+        typename: result.typename,
+        multirange: result.multirange_typename,
+    }
+}
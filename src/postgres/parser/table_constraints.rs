@@ -1,3 +1,4 @@
+use crate::postgres::error::{DiscoveryResult, PostgresDiscoveryError};
 use crate::postgres::{def::*, query::TableConstraintsQueryResult};
 use crate::Name;
 
@@ -10,7 +11,7 @@ pub struct TableConstraintsQueryResultParser {
 /// constraint name of the foreign key, then the ordinal position of the foreign key
 pub fn parse_table_constraint_query_results(
     results: Box<dyn Iterator<Item = TableConstraintsQueryResult>>,
-) -> impl Iterator<Item = Constraint> {
+) -> impl Iterator<Item = DiscoveryResult<Constraint>> {
     TableConstraintsQueryResultParser {
         curr: None,
         results,
@@ -18,120 +19,119 @@ pub fn parse_table_constraint_query_results(
 }
 
 impl Iterator for TableConstraintsQueryResultParser {
-    type Item = Constraint;
+    type Item = DiscoveryResult<Constraint>;
 
-    // FIXME/TODO: How to handle invalid input
     fn next(&mut self) -> Option<Self::Item> {
-        let result = if let Some(result) = self.curr.take() {
-            result
-        } else {
-            self.results.next()?
-        };
-
-        let constraint_name = result.constraint_name;
-        match result.constraint_type.as_str() {
-            "CHECK" => {
-                match result.check_clause {
-                    Some(check_clause) => {
-                        Some(Constraint::Check(Check {
+        self.parse_next().transpose()
+    }
+}
+
+impl TableConstraintsQueryResultParser {
+    fn parse_next(&mut self) -> DiscoveryResult<Option<Constraint>> {
+        loop {
+            let result = match self.curr.take().or_else(|| self.results.next()) {
+                Some(result) => result,
+                None => return Ok(None),
+            };
+
+            let constraint_name = result.constraint_name;
+            match result.constraint_type.as_str() {
+                "CHECK" => {
+                    if let Some(check_clause) = result.check_clause {
+                        return Ok(Some(Constraint::Check(Check {
                             name: constraint_name,
                             expr: check_clause,
-                            // TODO: How to find?
                             no_inherit: false,
-                        }))
+                        })));
                     }
-                    None => self.next(),
                 }
-            }
-
-            "FOREIGN KEY" => {
-                let mut columns = Vec::new();
-                let mut foreign_columns = Vec::new();
-
-                columns.push(result.column_name.unwrap());
-                let table = result.referential_key_table_name.unwrap();
-                foreign_columns.push(result.referential_key_column_name.unwrap());
-                let on_update =
-                    ForeignKeyAction::from_str(&result.update_rule.clone().unwrap_or_default());
-                let on_delete =
-                    ForeignKeyAction::from_str(&result.delete_rule.clone().unwrap_or_default());
-
-                for result in self.results.by_ref() {
-                    if result.constraint_name != constraint_name {
-                        self.curr = Some(result);
-                        return Some(Constraint::References(References {
-                            name: constraint_name,
-                            columns,
-                            table,
-                            foreign_columns,
-                            on_update,
-                            on_delete,
-                        }));
-                    }
 
-                    if result.column_name.is_some() && result.referential_key_column_name.is_some()
-                    {
-                        columns.push(result.column_name.unwrap());
-                        foreign_columns.push(result.referential_key_column_name.unwrap());
+                "FOREIGN KEY" => {
+                    // Consume the whole constraint first, not to mistake its rest for another
+                    let mut rest = Vec::new();
+                    while let Some(result) = self.next_of(&constraint_name) {
+                        rest.push(result);
                     }
-                }
-
-                Some(Constraint::References(References {
-                    name: constraint_name,
-                    columns,
-                    table,
-                    foreign_columns,
-                    on_update,
-                    on_delete,
-                }))
-            }
 
-            "PRIMARY KEY" => {
-                let mut columns = vec![result.column_name.unwrap()];
-
-                for result in self.results.by_ref() {
-                    if result.constraint_name != constraint_name {
-                        self.curr = Some(result);
-                        return Some(Constraint::PrimaryKey(PrimaryKey {
-                            name: constraint_name,
-                            columns,
-                        }));
+                    let column = required(result.column_name, 8)?;
+                    let schema = result
+                        .referential_key_table_schema
+                        .filter(|schema| *schema != result.table_schema);
+                    let table = required(result.referential_key_table_name, 16)?;
+                    let foreign_column = required(result.referential_key_column_name, 17)?;
+                    let on_update = parse_foreign_key_action(result.update_rule)
+                        .map_err(|err| err.with_column(&column))?;
+                    let on_delete = parse_foreign_key_action(result.delete_rule)
+                        .map_err(|err| err.with_column(&column))?;
+
+                    let mut columns = vec![column];
+                    let mut foreign_columns = vec![foreign_column];
+                    for result in rest {
+                        if let (Some(column), Some(foreign_column)) =
+                            (result.column_name, result.referential_key_column_name)
+                        {
+                            columns.push(column);
+                            foreign_columns.push(foreign_column);
+                        }
                     }
 
-                    columns.push(result.column_name.unwrap());
+                    return Ok(Some(Constraint::References(References {
+                        name: constraint_name,
+                        columns,
+                        schema,
+                        table,
+                        foreign_columns,
+                        on_update,
+                        on_delete,
+                    })));
                 }
 
-                Some(Constraint::PrimaryKey(PrimaryKey {
-                    name: constraint_name,
-                    columns,
-                }))
-            }
-
-            "UNIQUE" => {
-                let mut columns = vec![result.column_name.unwrap()];
-
-                for result in self.results.by_ref() {
-                    if result.constraint_name != constraint_name {
-                        self.curr = Some(result);
-                        return Some(Constraint::Unique(Unique {
-                            name: constraint_name,
-                            columns,
-                        }));
+                constraint_type @ ("PRIMARY KEY" | "UNIQUE") => {
+                    let mut columns = vec![result.column_name];
+                    while let Some(result) = self.next_of(&constraint_name) {
+                        columns.push(result.column_name);
                     }
-
-                    columns.push(result.column_name.unwrap());
+                    let columns = columns
+                        .into_iter()
+                        .map(|column| required(column, 8))
+                        .collect::<DiscoveryResult<_>>()?;
+
+                    let name = constraint_name;
+                    return Ok(Some(if constraint_type == "UNIQUE" {
+                        Constraint::Unique(Unique { name, columns })
+                    } else {
+                        Constraint::PrimaryKey(PrimaryKey { name, columns })
+                    }));
                 }
 
-                Some(Constraint::Unique(Unique {
-                    name: constraint_name,
-                    columns,
-                }))
+                // Constraints of other types are not discovered from this query
+                _ => (),
             }
+        }
+    }
 
-            _ => {
-                // FIXME: Invalid input error handling
-                None
-            }
+    /// The next result if it belongs to the constraint `name`, otherwise it is kept for later
+    fn next_of(&mut self, name: &str) -> Option<TableConstraintsQueryResult> {
+        let result = self.results.next()?;
+        if result.constraint_name == name {
+            Some(result)
+        } else {
+            self.curr = Some(result);
+            None
         }
     }
 }
+
+/// A value of the row which is NULL only if the query is wrong, `index` being its column
+fn required(value: Option<String>, index: usize) -> DiscoveryResult<String> {
+    value.ok_or_else(|| PostgresDiscoveryError::null_value(index))
+}
+
+fn parse_foreign_key_action(rule: Option<String>) -> DiscoveryResult<Option<ForeignKeyAction>> {
+    match rule {
+        Some(rule) => ForeignKeyAction::from_str(&rule)
+            .map(Some)
+            .ok_or_else(|| PostgresDiscoveryError::unknown_value("foreign key action", &rule)),
+        None => Ok(None),
+    }
+}
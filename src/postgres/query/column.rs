@@ -1,6 +1,6 @@
-use super::{InformationSchema, SchemaQueryBuilder};
-use crate::sqlx_types::postgres::PgRow;
-use sea_query::{BinOper, Expr, Iden, Query, SeaRc, SelectStatement};
+use super::{InformationSchema, PgAttribute, PgType, SchemaQueryBuilder};
+use crate::{SchemaRow, SchemaRowError};
+use sea_query::{Alias, BinOper, Expr, Iden, JoinType, Order, Query, SeaRc, SelectStatement};
 
 #[derive(Debug, sea_query::Iden)]
 /// Ref: https://www.postgresql.org/docs/13/infoschema-columns.html
@@ -67,6 +67,19 @@ pub struct ColumnQueryResult {
 
     pub udt_name: Option<String>,
     pub udt_name_regtype: Option<String>,
+
+    /// The type as written in DDL including its modifiers, e.g. `geometry(Point,4326)`
+    pub formatted_type: Option<String>,
+
+    /// The declared number of dimensions of an array column
+    pub array_dimensions: Option<i32>,
+    /// The element type of an array column as `format_type` writes it, e.g. `audit."Mood"`
+    pub array_element_type: Option<String>,
+
+    /// The schema of the type of the column, and of the element type of an array column
+    pub udt_schema: Option<String>,
+    /// The unqualified name of the element type of an array column, e.g. `Mood`
+    pub array_element_name: Option<String>,
 }
 
 impl SchemaQueryBuilder {
@@ -75,6 +88,8 @@ impl SchemaQueryBuilder {
         schema: SeaRc<dyn Iden>,
         table: SeaRc<dyn Iden>,
     ) -> SelectStatement {
+        let col_type = Alias::new("col_type");
+
         Query::select()
             .columns([
                 ColumnsField::ColumnName,
@@ -96,45 +111,76 @@ impl SchemaQueryBuilder {
             .expr(
                 // The double quotes are required to correctly handle user types containing
                 // upper case letters.
-                Expr::expr(Expr::cust("CONCAT('\"', udt_name, '\"')::regtype").cast_as(Text))
+                Expr::expr(Expr::cust("to_regtype(CONCAT('\"', udt_name, '\"'))").cast_as(Text))
                     .binary(BinOper::As, Expr::col(UdtNameRegtype)),
             )
+            .expr(Expr::cust(
+                "format_type(pg_attribute.atttypid, pg_attribute.atttypmod)",
+            ))
+            .column((PgAttribute::Table, PgAttribute::AttNDims))
+            // Qualified by its schema when not on the search path, and domains are kept as written
+            .expr(Expr::cust_with_expr(
+                "format_type(NULLIF($1, 0), NULL)",
+                Expr::col((col_type.clone(), PgType::TypeElem)),
+            ))
+            .column(ColumnsField::UdtSchema)
+            .expr(Expr::cust_with_expr(
+                "(SELECT typname FROM pg_type WHERE oid = $1)",
+                Expr::col((col_type.clone(), PgType::TypeElem)),
+            ))
             .from((InformationSchema::Schema, InformationSchema::Columns))
+            .left_join(
+                PgAttribute::Table,
+                Expr::col((PgAttribute::Table, PgAttribute::AttRelId))
+                    .eq(Expr::cust(
+                        "format('%I.%I', table_schema, table_name)::regclass",
+                    ))
+                    .and(
+                        Expr::col((PgAttribute::Table, PgAttribute::AttName))
+                            .equals(ColumnsField::ColumnName),
+                    ),
+            )
+            .join_as(
+                JoinType::LeftJoin,
+                PgType::Table,
+                col_type.clone(),
+                Expr::col((col_type.clone(), PgType::Oid))
+                    .equals((PgAttribute::Table, PgAttribute::AttTypId)),
+            )
             .and_where(Expr::col(ColumnsField::TableSchema).eq(schema.to_string()))
             .and_where(Expr::col(ColumnsField::TableName).eq(table.to_string()))
+            .order_by(ColumnsField::OrdinalPosition, Order::Asc)
             .take()
     }
 }
 
-#[cfg(feature = "sqlx-postgres")]
-impl From<&PgRow> for ColumnQueryResult {
-    fn from(row: &PgRow) -> Self {
-        use crate::sqlx_types::Row;
-        Self {
-            column_name: row.get(0),
-            column_type: row.get(1),
-            column_default: row.get(2),
-            column_generated: row.get(3),
-            is_nullable: row.get(4),
-            is_identity: row.get(5),
-            numeric_precision: row.get(6),
-            numeric_precision_radix: row.get(7),
-            numeric_scale: row.get(8),
-            character_maximum_length: row.get(9),
-            character_octet_length: row.get(10),
-            datetime_precision: row.get(11),
-            interval_type: row.get(12),
-            interval_precision: row.get(13),
-            udt_name: row.get(14),
-            udt_name_regtype: row.get(15),
-        }
-    }
-}
+impl TryFrom<&SchemaRow> for ColumnQueryResult {
+    type Error = SchemaRowError;
 
-#[cfg(not(feature = "sqlx-postgres"))]
-impl From<&PgRow> for ColumnQueryResult {
-    fn from(_: &PgRow) -> Self {
-        Self::default()
+    fn try_from(row: &SchemaRow) -> Result<Self, Self::Error> {
+        Ok(Self {
+            column_name: row.try_get(0)?,
+            column_type: row.try_get(1)?,
+            column_default: row.try_get(2)?,
+            column_generated: row.try_get(3)?,
+            is_nullable: row.try_get(4)?,
+            is_identity: row.try_get(5)?,
+            numeric_precision: row.try_get(6)?,
+            numeric_precision_radix: row.try_get(7)?,
+            numeric_scale: row.try_get(8)?,
+            character_maximum_length: row.try_get(9)?,
+            character_octet_length: row.try_get(10)?,
+            datetime_precision: row.try_get(11)?,
+            interval_type: row.try_get(12)?,
+            interval_precision: row.try_get(13)?,
+            udt_name: row.try_get(14)?,
+            udt_name_regtype: row.try_get(15)?,
+            formatted_type: row.try_get(16)?,
+            array_dimensions: row.try_get(17)?,
+            array_element_type: row.try_get(18)?,
+            udt_schema: row.try_get(19)?,
+            array_element_name: row.try_get(20)?,
+        })
     }
 }
 
@@ -9,7 +9,7 @@ pub use referential_constraints::*;
 pub use table_constraints::*;
 
 use super::{InformationSchema, SchemaQueryBuilder};
-use crate::{postgres::query::select_base_table_and_view, sqlx_types::postgres::PgRow};
+use crate::{postgres::query::select_base_table_and_view, SchemaRow, SchemaRowError};
 use sea_query::{Alias, Condition, Expr, Iden, JoinType, Order, Query, SeaRc, SelectStatement};
 
 #[derive(Debug, Default)]
@@ -41,6 +41,7 @@ pub struct TableConstraintsQueryResult {
     // From key_column_usage as part of subquery involving referential_constraints
     pub referential_key_table_name: Option<String>,
     pub referential_key_column_name: Option<String>,
+    pub referential_key_table_schema: Option<String>,
 }
 
 impl SchemaQueryBuilder {
@@ -83,6 +84,7 @@ impl SchemaQueryBuilder {
             .columns(vec![
                 (rcsq.clone(), Kcuf::TableName),
                 (rcsq.clone(), Kcuf::ColumnName),
+                (rcsq.clone(), Kcuf::TableSchema),
             ])
             .from((Schema::Schema, InformationSchema::TableConstraints))
             .join(
@@ -136,6 +138,7 @@ impl SchemaQueryBuilder {
                 Query::select()
                     .distinct()
                     .columns(vec![
+                        (Schema::ReferentialConstraints, RefC::ConstraintSchema),
                         (Schema::ReferentialConstraints, RefC::ConstraintName),
                         (Schema::ReferentialConstraints, RefC::UniqueConstraintSchema),
                         (Schema::ReferentialConstraints, RefC::UniqueConstraintName),
@@ -146,6 +149,7 @@ impl SchemaQueryBuilder {
                     .columns(vec![
                         (Schema::ConstraintColumnUsage, Kcuf::TableName),
                         (Schema::ConstraintColumnUsage, Kcuf::ColumnName),
+                        (Schema::ConstraintColumnUsage, Kcuf::TableSchema),
                     ])
                     .columns(vec![
                         // Extract the ordinal position of the referenced primary keys
@@ -154,8 +158,18 @@ impl SchemaQueryBuilder {
                     .from((Schema::Schema, Schema::ReferentialConstraints))
                     .left_join(
                         (Schema::Schema, Schema::ConstraintColumnUsage),
-                        Expr::col((Schema::ReferentialConstraints, RefC::ConstraintName))
-                            .equals((Schema::ConstraintColumnUsage, Kcuf::ConstraintName)),
+                        Condition::all()
+                            .add(
+                                Expr::col((Schema::ReferentialConstraints, RefC::ConstraintName))
+                                    .equals((Schema::ConstraintColumnUsage, Kcuf::ConstraintName)),
+                            )
+                            .add(
+                                Expr::col((Schema::ReferentialConstraints, RefC::ConstraintSchema))
+                                    .equals((
+                                        Schema::ConstraintColumnUsage,
+                                        Kcuf::ConstraintSchema,
+                                    )),
+                            ),
                     )
                     .left_join(
                         // Join the key_column_usage rows for the referenced primary keys
@@ -187,6 +201,10 @@ impl SchemaQueryBuilder {
                         Expr::col((Schema::TableConstraints, Tcf::ConstraintName))
                             .equals((rcsq.clone(), RefC::ConstraintName)),
                     )
+                    .add(
+                        Expr::col((Schema::TableConstraints, Tcf::ConstraintSchema))
+                            .equals((rcsq.clone(), RefC::ConstraintSchema)),
+                    )
                     .add(
                         Condition::any()
                             .add(
@@ -223,40 +241,34 @@ impl SchemaQueryBuilder {
     }
 }
 
-#[cfg(feature = "sqlx-postgres")]
-impl From<&PgRow> for TableConstraintsQueryResult {
-    fn from(row: &PgRow) -> Self {
-        use crate::sqlx_types::Row;
-        Self {
-            constraint_schema: row.get(0),
-            constraint_name: row.get(1),
-            table_schema: row.get(2),
-            table_name: row.get(3),
-            constraint_type: row.get(4),
-            is_deferrable: row.get(5),
-            initially_deferred: row.get(6),
+impl TryFrom<&SchemaRow> for TableConstraintsQueryResult {
+    type Error = SchemaRowError;
 
-            check_clause: row.get(7),
+    fn try_from(row: &SchemaRow) -> Result<Self, Self::Error> {
+        Ok(Self {
+            constraint_schema: row.try_get(0)?,
+            constraint_name: row.try_get(1)?,
+            table_schema: row.try_get(2)?,
+            table_name: row.try_get(3)?,
+            constraint_type: row.try_get(4)?,
+            is_deferrable: row.try_get(5)?,
+            initially_deferred: row.try_get(6)?,
 
-            column_name: row.get(8),
-            ordinal_position: row.get(9),
-            position_in_unique_constraint: row.get(10),
+            check_clause: row.try_get(7)?,
 
-            unique_constraint_schema: row.get(11),
-            unique_constraint_name: row.get(12),
-            match_option: row.get(13),
-            update_rule: row.get(14),
-            delete_rule: row.get(15),
+            column_name: row.try_get(8)?,
+            ordinal_position: row.try_get(9)?,
+            position_in_unique_constraint: row.try_get(10)?,
 
-            referential_key_table_name: row.get(16),
-            referential_key_column_name: row.get(17),
-        }
-    }
-}
+            unique_constraint_schema: row.try_get(11)?,
+            unique_constraint_name: row.try_get(12)?,
+            match_option: row.try_get(13)?,
+            update_rule: row.try_get(14)?,
+            delete_rule: row.try_get(15)?,
 
-#[cfg(not(feature = "sqlx-postgres"))]
-impl From<&PgRow> for TableConstraintsQueryResult {
-    fn from(_: &PgRow) -> Self {
-        Self::default()
+            referential_key_table_name: row.try_get(16)?,
+            referential_key_column_name: row.try_get(17)?,
+            referential_key_table_schema: row.try_get(18)?,
+        })
     }
 }
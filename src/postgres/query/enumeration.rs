@@ -1,5 +1,5 @@
-use super::SchemaQueryBuilder;
-use crate::sqlx_types::postgres::PgRow;
+use super::{PgNamespace, SchemaQueryBuilder};
+use crate::{SchemaRow, SchemaRowError};
 use sea_query::{Expr, Order, Query, SelectStatement};
 
 #[derive(Debug, sea_query::Iden)]
@@ -10,6 +10,10 @@ pub enum PgType {
     TypeName,
     #[iden = "oid"]
     Oid,
+    #[iden = "typnamespace"]
+    TypeNamespace,
+    #[iden = "typelem"]
+    TypeElem,
 }
 
 #[derive(Debug, sea_query::Iden)]
@@ -26,6 +30,8 @@ pub enum PgEnum {
 pub struct EnumQueryResult {
     pub typename: String,
     pub enumlabel: String,
+    /// The schema of the enum type
+    pub schema: String,
 }
 
 impl SchemaQueryBuilder {
@@ -33,31 +39,32 @@ impl SchemaQueryBuilder {
         Query::select()
             .column((PgType::Table, PgType::TypeName))
             .column((PgEnum::Table, PgEnum::EnumLabel))
+            .column((PgNamespace::Table, PgNamespace::NspName))
             .from(PgType::Table)
             .inner_join(
                 PgEnum::Table,
                 Expr::col((PgEnum::Table, PgEnum::EnumTypeId)).equals((PgType::Table, PgType::Oid)),
             )
+            .inner_join(
+                PgNamespace::Table,
+                Expr::col((PgNamespace::Table, PgNamespace::Oid))
+                    .equals((PgType::Table, PgType::TypeNamespace)),
+            )
+            .order_by((PgNamespace::Table, PgNamespace::NspName), Order::Asc)
             .order_by((PgType::Table, PgType::TypeName), Order::Asc)
             .order_by((PgEnum::Table, PgEnum::EnumLabel), Order::Asc)
             .take()
     }
 }
 
-#[cfg(feature = "sqlx-postgres")]
-impl From<&PgRow> for EnumQueryResult {
-    fn from(row: &PgRow) -> Self {
-        use crate::sqlx_types::Row;
-        Self {
-            typename: row.get(0),
-            enumlabel: row.get(1),
-        }
-    }
-}
+impl TryFrom<&SchemaRow> for EnumQueryResult {
+    type Error = SchemaRowError;
 
-#[cfg(not(feature = "sqlx-postgres"))]
-impl From<&PgRow> for EnumQueryResult {
-    fn from(_: &PgRow) -> Self {
-        Self::default()
+    fn try_from(row: &SchemaRow) -> Result<Self, Self::Error> {
+        Ok(Self {
+            typename: row.try_get(0)?,
+            enumlabel: row.try_get(1)?,
+            schema: row.try_get(2)?,
+        })
     }
 }
@@ -0,0 +1,51 @@
+use super::{PgNamespace, SchemaQueryBuilder};
+use crate::{SchemaRow, SchemaRowError};
+use sea_query::{Expr, Iden, Order, Query, SelectStatement};
+
+#[derive(Debug, Iden)]
+/// Ref: https://www.postgresql.org/docs/13/catalog-pg-extension.html
+pub enum PgExtension {
+    Table,
+    #[iden = "extname"]
+    ExtName,
+    #[iden = "extnamespace"]
+    ExtNamespace,
+    #[iden = "extversion"]
+    ExtVersion,
+}
+
+#[derive(Debug, Default)]
+pub struct ExtensionQueryResult {
+    pub name: String,
+    pub schema: String,
+    pub version: String,
+}
+
+impl SchemaQueryBuilder {
+    pub fn query_extensions(&self) -> SelectStatement {
+        Query::select()
+            .column((PgExtension::Table, PgExtension::ExtName))
+            .column((PgNamespace::Table, PgNamespace::NspName))
+            .column((PgExtension::Table, PgExtension::ExtVersion))
+            .from(PgExtension::Table)
+            .inner_join(
+                PgNamespace::Table,
+                Expr::col((PgNamespace::Table, PgNamespace::Oid))
+                    .equals((PgExtension::Table, PgExtension::ExtNamespace)),
+            )
+            .order_by((PgExtension::Table, PgExtension::ExtName), Order::Asc)
+            .take()
+    }
+}
+
+impl TryFrom<&SchemaRow> for ExtensionQueryResult {
+    type Error = SchemaRowError;
+
+    fn try_from(row: &SchemaRow) -> Result<Self, Self::Error> {
+        Ok(Self {
+            name: row.try_get(0)?,
+            schema: row.try_get(1)?,
+            version: row.try_get(2)?,
+        })
+    }
+}
@@ -2,7 +2,11 @@ pub mod char_set;
 pub mod column;
 pub mod constraints;
 pub mod enumeration;
+pub mod extension;
 pub mod pg_indexes;
+pub mod policy;
+pub mod privilege;
+pub mod range;
 pub mod schema;
 pub mod table;
 
@@ -10,6 +14,10 @@ pub use char_set::*;
 pub use column::*;
 pub use constraints::*;
 pub use enumeration::*;
+pub use extension::*;
 pub use pg_indexes::*;
+pub use policy::*;
+pub use privilege::*;
+pub use range::*;
 pub use schema::*;
 pub use table::*;
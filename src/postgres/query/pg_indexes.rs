@@ -1,5 +1,5 @@
 use super::SchemaQueryBuilder;
-use crate::sqlx_types::postgres::PgRow;
+use crate::{SchemaRow, SchemaRowError};
 use sea_query::{Alias, Condition, Expr, Iden, JoinType, Order, Query, SeaRc, SelectStatement};
 
 #[derive(Debug, Iden)]
@@ -34,9 +34,19 @@ pub enum PgClass {
     RelNamespace,
     #[iden = "relname"]
     RelName,
+    #[iden = "relrowsecurity"]
+    RelRowSecurity,
+    #[iden = "relforcerowsecurity"]
+    RelForceRowSecurity,
+    #[iden = "relkind"]
+    RelKind,
+    #[iden = "relowner"]
+    RelOwner,
+    #[iden = "relacl"]
+    RelAcl,
 }
 
-#[derive(Debug, Iden)]
+#[derive(Debug, Clone, Copy, Iden)]
 pub enum PgNamespace {
     Table,
     Oid,
@@ -52,6 +62,14 @@ pub enum PgAttribute {
     AttRelId,
     #[iden = "attname"]
     AttName,
+    #[iden = "attacl"]
+    AttAcl,
+    #[iden = "attisdropped"]
+    AttIsDropped,
+    #[iden = "atttypid"]
+    AttTypId,
+    #[iden = "attndims"]
+    AttNDims,
 }
 
 #[derive(Debug, Default)]
@@ -126,22 +144,15 @@ impl SchemaQueryBuilder {
     }
 }
 
-#[cfg(feature = "sqlx-postgres")]
-impl From<&PgRow> for UniqueIndexQueryResult {
-    fn from(row: &PgRow) -> Self {
-        use crate::sqlx_types::Row;
-        Self {
-            index_name: row.get(0),
-            table_schema: row.get(1),
-            table_name: row.get(2),
-            column_name: row.get(3),
-        }
-    }
-}
+impl TryFrom<&SchemaRow> for UniqueIndexQueryResult {
+    type Error = SchemaRowError;
 
-#[cfg(not(feature = "sqlx-postgres"))]
-impl From<&PgRow> for UniqueIndexQueryResult {
-    fn from(_: &PgRow) -> Self {
-        Self::default()
+    fn try_from(row: &SchemaRow) -> Result<Self, Self::Error> {
+        Ok(Self {
+            index_name: row.try_get(0)?,
+            table_schema: row.try_get(1)?,
+            table_name: row.try_get(2)?,
+            column_name: row.try_get(3)?,
+        })
     }
 }
@@ -0,0 +1,110 @@
+use super::{PgClass, PgNamespace, SchemaQueryBuilder};
+use crate::{SchemaRow, SchemaRowError};
+use sea_query::{Condition, Expr, Iden, Order, Query, SeaRc, SelectStatement};
+
+#[derive(Debug, Iden)]
+/// Ref: https://www.postgresql.org/docs/13/view-pg-policies.html
+pub enum PgPolicies {
+    Table,
+    #[iden = "schemaname"]
+    SchemaName,
+    #[iden = "tablename"]
+    TableName,
+    #[iden = "policyname"]
+    PolicyName,
+    Permissive,
+    Roles,
+    Cmd,
+    Qual,
+    WithCheck,
+}
+
+#[derive(Debug, Default)]
+pub struct RowLevelSecurityQueryResult {
+    pub row_security: bool,
+    pub force_row_security: bool,
+}
+
+#[derive(Debug, Default)]
+pub struct PolicyQueryResult {
+    pub policy_name: String,
+    pub permissive: String,
+    pub roles: Vec<String>,
+    pub cmd: String,
+    pub qual: Option<String>,
+    pub with_check: Option<String>,
+}
+
+impl SchemaQueryBuilder {
+    pub fn query_table_row_level_security(
+        &self,
+        schema: SeaRc<dyn Iden>,
+        table: SeaRc<dyn Iden>,
+    ) -> SelectStatement {
+        Query::select()
+            .column((PgClass::Table, PgClass::RelRowSecurity))
+            .column((PgClass::Table, PgClass::RelForceRowSecurity))
+            .from(PgClass::Table)
+            .inner_join(
+                PgNamespace::Table,
+                Expr::col((PgNamespace::Table, PgNamespace::Oid))
+                    .equals((PgClass::Table, PgClass::RelNamespace)),
+            )
+            .cond_where(
+                Condition::all()
+                    .add(Expr::col((PgClass::Table, PgClass::RelName)).eq(table.to_string()))
+                    .add(
+                        Expr::col((PgNamespace::Table, PgNamespace::NspName))
+                            .eq(schema.to_string()),
+                    ),
+            )
+            .take()
+    }
+
+    pub fn query_table_policies(
+        &self,
+        schema: SeaRc<dyn Iden>,
+        table: SeaRc<dyn Iden>,
+    ) -> SelectStatement {
+        Query::select()
+            .columns([
+                PgPolicies::PolicyName,
+                PgPolicies::Permissive,
+                PgPolicies::Roles,
+                PgPolicies::Cmd,
+                PgPolicies::Qual,
+                PgPolicies::WithCheck,
+            ])
+            .from(PgPolicies::Table)
+            .and_where(Expr::col(PgPolicies::SchemaName).eq(schema.to_string()))
+            .and_where(Expr::col(PgPolicies::TableName).eq(table.to_string()))
+            .order_by(PgPolicies::PolicyName, Order::Asc)
+            .take()
+    }
+}
+
+impl TryFrom<&SchemaRow> for RowLevelSecurityQueryResult {
+    type Error = SchemaRowError;
+
+    fn try_from(row: &SchemaRow) -> Result<Self, Self::Error> {
+        Ok(Self {
+            row_security: row.try_get(0)?,
+            force_row_security: row.try_get(1)?,
+        })
+    }
+}
+
+impl TryFrom<&SchemaRow> for PolicyQueryResult {
+    type Error = SchemaRowError;
+
+    fn try_from(row: &SchemaRow) -> Result<Self, Self::Error> {
+        Ok(Self {
+            policy_name: row.try_get(0)?,
+            permissive: row.try_get(1)?,
+            roles: row.try_get(2)?,
+            cmd: row.try_get(3)?,
+            qual: row.try_get(4)?,
+            with_check: row.try_get(5)?,
+        })
+    }
+}
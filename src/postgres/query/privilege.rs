@@ -0,0 +1,161 @@
+use super::{PgAttribute, PgClass, PgNamespace, SchemaQueryBuilder};
+use crate::{SchemaRow, SchemaRowError};
+use sea_query::{
+    Alias, Condition, Expr, Func, Iden, JoinType, Order, Query, SeaRc, SelectStatement, SimpleExpr,
+};
+
+#[derive(Debug, Iden)]
+/// The columns returned by `aclexplode`, which expands an access privilege array
+/// Ref: https://www.postgresql.org/docs/13/functions-info.html
+pub enum AclExplode {
+    #[iden = "aclexplode"]
+    Func,
+    Grantor,
+    Grantee,
+    PrivilegeType,
+    IsGrantable,
+}
+
+#[derive(Debug, Iden)]
+enum PgGetUserById {
+    #[iden = "pg_get_userbyid"]
+    Func,
+}
+
+#[derive(Debug, Iden)]
+/// The privileges an owner has on a table whose access privileges were never changed
+enum AclDefault {
+    #[iden = "acldefault"]
+    Func,
+}
+
+#[derive(Debug, Default)]
+pub struct PrivilegeQueryResult {
+    pub grantee: String,
+    pub table_name: String,
+    pub column_name: Option<String>,
+    pub privilege_type: String,
+    pub is_grantable: bool,
+}
+
+impl SchemaQueryBuilder {
+    /// Like `information_schema.table_privileges`, the relations are the tables, views and foreign
+    /// tables. Every grant of the access privileges is listed, whoever the current user is
+    pub fn query_table_privileges(&self, schema: SeaRc<dyn Iden>) -> SelectStatement {
+        let acl = Alias::new("acl");
+        // A NULL `relacl` stands for the default privileges of the owner
+        let relacl = Func::coalesce([
+            Expr::col((PgClass::Table, PgClass::RelAcl)).into(),
+            Func::cust(AclDefault::Func)
+                .arg(Expr::cust(r#"'r'::"char""#))
+                .arg(Expr::col((PgClass::Table, PgClass::RelOwner)))
+                .into(),
+        ]);
+        Query::select()
+            .expr(grantee(&acl))
+            .column((PgClass::Table, PgClass::RelName))
+            .expr(Expr::cust("NULL"))
+            .column((acl.clone(), AclExplode::PrivilegeType))
+            .column((acl.clone(), AclExplode::IsGrantable))
+            .from(PgClass::Table)
+            .inner_join(
+                PgNamespace::Table,
+                Expr::col((PgNamespace::Table, PgNamespace::Oid))
+                    .equals((PgClass::Table, PgClass::RelNamespace)),
+            )
+            .join_lateral(
+                JoinType::InnerJoin,
+                acl_explode(relacl.into(), &acl),
+                acl.clone(),
+                Expr::cust("TRUE"),
+            )
+            .cond_where(
+                Condition::all()
+                    .add(
+                        Expr::col((PgNamespace::Table, PgNamespace::NspName))
+                            .eq(schema.to_string()),
+                    )
+                    .add(Expr::col((PgClass::Table, PgClass::RelKind)).is_in(["r", "p", "v", "f"])),
+            )
+            .order_by((PgClass::Table, PgClass::RelName), Order::Asc)
+            .order_by((acl.clone(), AclExplode::Grantee), Order::Asc)
+            .order_by((acl, AclExplode::PrivilegeType), Order::Asc)
+            .take()
+    }
+
+    pub fn query_column_privileges(&self, schema: SeaRc<dyn Iden>) -> SelectStatement {
+        let acl = Alias::new("acl");
+        Query::select()
+            .expr(grantee(&acl))
+            .column((PgClass::Table, PgClass::RelName))
+            .column((PgAttribute::Table, PgAttribute::AttName))
+            .column((acl.clone(), AclExplode::PrivilegeType))
+            .column((acl.clone(), AclExplode::IsGrantable))
+            .from(PgAttribute::Table)
+            .inner_join(
+                PgClass::Table,
+                Expr::col((PgClass::Table, PgClass::Oid))
+                    .equals((PgAttribute::Table, PgAttribute::AttRelId)),
+            )
+            .inner_join(
+                PgNamespace::Table,
+                Expr::col((PgNamespace::Table, PgNamespace::Oid))
+                    .equals((PgClass::Table, PgClass::RelNamespace)),
+            )
+            .join_lateral(
+                JoinType::InnerJoin,
+                acl_explode(
+                    Expr::col((PgAttribute::Table, PgAttribute::AttAcl)).into(),
+                    &acl,
+                ),
+                acl.clone(),
+                Expr::cust("TRUE"),
+            )
+            .cond_where(
+                Condition::all()
+                    .add(
+                        Expr::col((PgNamespace::Table, PgNamespace::NspName))
+                            .eq(schema.to_string()),
+                    )
+                    .add(Expr::col((PgAttribute::Table, PgAttribute::AttAcl)).is_not_null())
+                    .add(Expr::col((PgAttribute::Table, PgAttribute::AttIsDropped)).eq(false)),
+            )
+            .order_by((PgClass::Table, PgClass::RelName), Order::Asc)
+            .order_by((PgAttribute::Table, PgAttribute::AttName), Order::Asc)
+            .order_by((acl.clone(), AclExplode::Grantee), Order::Asc)
+            .order_by((acl, AclExplode::PrivilegeType), Order::Asc)
+            .take()
+    }
+}
+
+/// The name of the grantee of an exploded access privilege, grantee 0 standing for PUBLIC
+fn grantee(acl: &Alias) -> SimpleExpr {
+    Expr::case(
+        Expr::col((acl.clone(), AclExplode::Grantee)).eq(0),
+        "PUBLIC",
+    )
+    .finally(Func::cust(PgGetUserById::Func).arg(Expr::col((acl.clone(), AclExplode::Grantee))))
+    .into()
+}
+
+/// Expand an access privilege array into one row per grant
+fn acl_explode(acl_array: SimpleExpr, acl: &Alias) -> SelectStatement {
+    Query::select()
+        .expr(Expr::cust("*"))
+        .from_function(Func::cust(AclExplode::Func).arg(acl_array), acl.clone())
+        .take()
+}
+
+impl TryFrom<&SchemaRow> for PrivilegeQueryResult {
+    type Error = SchemaRowError;
+
+    fn try_from(row: &SchemaRow) -> Result<Self, Self::Error> {
+        Ok(Self {
+            grantee: row.try_get(0)?,
+            table_name: row.try_get(1)?,
+            column_name: row.try_get(2)?,
+            privilege_type: row.try_get(3)?,
+            is_grantable: row.try_get(4)?,
+        })
+    }
+}
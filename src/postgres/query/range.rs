@@ -0,0 +1,83 @@
+use super::{PgNamespace, PgType, SchemaQueryBuilder};
+use crate::{SchemaRow, SchemaRowError};
+use sea_query::{Expr, Iden, Order, Query, SelectStatement};
+
+#[derive(Debug, Iden)]
+/// Ref: https://www.postgresql.org/docs/14/catalog-pg-range.html
+pub enum PgRange {
+    Table,
+    #[iden = "rngtypid"]
+    RngTypId,
+    #[iden = "rngsubtype"]
+    RngSubtype,
+}
+
+#[derive(Debug, Default)]
+pub struct RangeQueryResult {
+    pub typename: String,
+    pub subtype: String,
+    pub multirange_typename: Option<String>,
+    /// The schema and the unqualified name of the range type
+    pub schema: String,
+    pub name: String,
+    /// The schema and the unqualified name of the multirange type
+    pub multirange_schema: Option<String>,
+    pub multirange_name: Option<String>,
+}
+
+impl SchemaQueryBuilder {
+    /// Query the user-defined range types, the built-in ones live in `pg_catalog`.
+    /// The names are qualified by their schema when it is not on the search path
+    pub fn query_ranges(&self) -> SelectStatement {
+        Query::select()
+            .expr(Expr::cust("format_type(pg_range.rngtypid, NULL)"))
+            .expr(Expr::cust("format_type(pg_range.rngsubtype, NULL)"))
+            // Multiranges exist since PostgreSQL 14, read the column without failing on older ones
+            .expr(Expr::cust(
+                "format_type((to_jsonb(pg_range) ->> 'rngmultitypid')::oid, NULL)",
+            ))
+            .column((PgNamespace::Table, PgNamespace::NspName))
+            .column((PgType::Table, PgType::TypeName))
+            .expr(Expr::cust(
+                "(SELECT nspname FROM pg_type JOIN pg_namespace ON pg_namespace.oid = typnamespace \
+                  WHERE pg_type.oid = (to_jsonb(pg_range) ->> 'rngmultitypid')::oid)",
+            ))
+            .expr(Expr::cust(
+                "(SELECT typname FROM pg_type \
+                  WHERE pg_type.oid = (to_jsonb(pg_range) ->> 'rngmultitypid')::oid)",
+            ))
+            .from(PgRange::Table)
+            .inner_join(
+                PgType::Table,
+                Expr::col((PgType::Table, PgType::Oid)).equals((PgRange::Table, PgRange::RngTypId)),
+            )
+            .inner_join(
+                PgNamespace::Table,
+                Expr::col((PgNamespace::Table, PgNamespace::Oid))
+                    .equals((PgType::Table, PgType::TypeNamespace)),
+            )
+            .and_where(
+                Expr::col((PgNamespace::Table, PgNamespace::NspName))
+                    .is_not_in(["pg_catalog", "information_schema"]),
+            )
+            .order_by((PgNamespace::Table, PgNamespace::NspName), Order::Asc)
+            .order_by((PgType::Table, PgType::TypeName), Order::Asc)
+            .take()
+    }
+}
+
+impl TryFrom<&SchemaRow> for RangeQueryResult {
+    type Error = SchemaRowError;
+
+    fn try_from(row: &SchemaRow) -> Result<Self, Self::Error> {
+        Ok(Self {
+            typename: row.try_get(0)?,
+            subtype: row.try_get(1)?,
+            multirange_typename: row.try_get(2)?,
+            schema: row.try_get(3)?,
+            name: row.try_get(4)?,
+            multirange_schema: row.try_get(5)?,
+            multirange_name: row.try_get(6)?,
+        })
+    }
+}
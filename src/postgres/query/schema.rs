@@ -1,6 +1,8 @@
-use sea_query::{Condition, Expr, Iden, JoinType, Query, SelectStatement};
+use super::PgNamespace;
+use crate::{SchemaRow, SchemaRowError};
+use sea_query::{Condition, Expr, Iden, JoinType, Order, Query, SelectStatement};
 
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
 pub struct SchemaQueryBuilder {}
 
 #[derive(Debug, Iden)]
@@ -17,6 +19,32 @@ pub enum InformationSchema {
     ConstraintColumnUsage,
 }
 
+#[derive(Debug, Default)]
+pub struct NamespaceQueryResult {
+    pub name: String,
+}
+
+impl SchemaQueryBuilder {
+    /// List the schemas of the database, system schemas included
+    pub fn query_schemas(&self) -> SelectStatement {
+        Query::select()
+            .column(PgNamespace::NspName)
+            .from(PgNamespace::Table)
+            .order_by(PgNamespace::NspName, Order::Asc)
+            .take()
+    }
+}
+
+impl TryFrom<&SchemaRow> for NamespaceQueryResult {
+    type Error = SchemaRowError;
+
+    fn try_from(row: &SchemaRow) -> Result<Self, Self::Error> {
+        Ok(Self {
+            name: row.try_get(0)?,
+        })
+    }
+}
+
 pub(crate) fn select_base_table_and_view() -> SelectStatement {
     #[derive(Debug, Iden)]
     enum PgClass {
@@ -1,8 +1,8 @@
 use super::{select_base_table_and_view, InformationSchema, SchemaQueryBuilder};
-use crate::sqlx_types::postgres::PgRow;
+use crate::{SchemaRow, SchemaRowError};
 use sea_query::{Expr, Iden, Query, SeaRc, SelectStatement};
 
-#[derive(Debug, sea_query::Iden)]
+#[derive(Debug, Clone, Copy, sea_query::Iden)]
 /// Ref: https://www.postgresql.org/docs/13/infoschema-tables.html
 pub enum TablesFields {
     TableCatalog,
@@ -53,21 +53,14 @@ impl SchemaQueryBuilder {
     }
 }
 
-#[cfg(feature = "sqlx-postgres")]
-impl From<&PgRow> for TableQueryResult {
-    fn from(row: &PgRow) -> Self {
-        use crate::sqlx_types::Row;
-        Self {
-            table_name: row.get(0),
-            user_defined_type_schema: row.get(1),
-            user_defined_type_name: row.get(2),
-        }
-    }
-}
+impl TryFrom<&SchemaRow> for TableQueryResult {
+    type Error = SchemaRowError;
 
-#[cfg(not(feature = "sqlx-postgres"))]
-impl From<&PgRow> for TableQueryResult {
-    fn from(_: &PgRow) -> Self {
-        Self::default()
+    fn try_from(row: &SchemaRow) -> Result<Self, Self::Error> {
+        Ok(Self {
+            table_name: row.try_get(0)?,
+            user_defined_type_schema: row.try_get(1)?,
+            user_defined_type_name: row.try_get(2)?,
+        })
     }
 }
@@ -1,4 +1,6 @@
 use crate::postgres::def::{ColumnInfo, Type};
+#[cfg(feature = "postgres-postgis")]
+use crate::postgres::def::{GeometryDef, GeometryDims};
 use sea_query::{Alias, ColumnDef, ColumnType, DynIden, IntoIden, PgInterval, RcOrArc, StringLen};
 use std::{convert::TryFrom, fmt::Write};
 
@@ -120,12 +122,50 @@ impl ColumnInfo {
                 Type::TsRange => ColumnType::Custom(Alias::new("tsrange").into_iden()),
                 Type::TsTzRange => ColumnType::Custom(Alias::new("tstzrange").into_iden()),
                 Type::DateRange => ColumnType::Custom(Alias::new("daterange").into_iden()),
+                Type::Range(range_def) => {
+                    ColumnType::Custom(Alias::new(&range_def.typename).into_iden())
+                }
+                Type::Int4MultiRange => {
+                    ColumnType::Custom(Alias::new("int4multirange").into_iden())
+                }
+                Type::Int8MultiRange => {
+                    ColumnType::Custom(Alias::new("int8multirange").into_iden())
+                }
+                Type::NumMultiRange => ColumnType::Custom(Alias::new("nummultirange").into_iden()),
+                Type::TsMultiRange => ColumnType::Custom(Alias::new("tsmultirange").into_iden()),
+                Type::TsTzMultiRange => {
+                    ColumnType::Custom(Alias::new("tstzmultirange").into_iden())
+                }
+                Type::DateMultiRange => {
+                    ColumnType::Custom(Alias::new("datemultirange").into_iden())
+                }
+                Type::MultiRange(multirange_def) => {
+                    ColumnType::Custom(Alias::new(&multirange_def.typename).into_iden())
+                }
                 Type::PgLsn => ColumnType::Custom(Alias::new("pg_lsn").into_iden()),
                 #[cfg(feature = "postgres-vector")]
                 Type::Vector(vector_attr) => match vector_attr.length {
                     Some(length) => ColumnType::Vector(Some(length)),
                     None => ColumnType::Vector(None),
                 },
+                #[cfg(feature = "postgres-postgis")]
+                Type::Geometry(geometry_def) => ColumnType::Custom(
+                    Alias::new(write_geometry_type("geometry", geometry_def)).into_iden(),
+                ),
+                #[cfg(feature = "postgres-postgis")]
+                Type::Geography(geometry_def) => ColumnType::Custom(
+                    Alias::new(write_geometry_type("geography", geometry_def)).into_iden(),
+                ),
+                #[cfg(feature = "postgres-citext")]
+                Type::CiText => ColumnType::Custom(Alias::new("citext").into_iden()),
+                #[cfg(feature = "postgres-hstore")]
+                Type::HStore => ColumnType::Custom(Alias::new("hstore").into_iden()),
+                #[cfg(feature = "postgres-ltree")]
+                Type::LTree => ColumnType::LTree,
+                #[cfg(feature = "postgres-ltree")]
+                Type::LQuery => ColumnType::Custom(Alias::new("lquery").into_iden()),
+                #[cfg(feature = "postgres-cube")]
+                Type::Cube => ColumnType::Custom(Alias::new("cube").into_iden()),
                 Type::Unknown(s) => ColumnType::Custom(Alias::new(s).into_iden()),
                 Type::Enum(enum_def) => {
                     let name = Alias::new(&enum_def.typename).into_iden();
@@ -136,11 +176,125 @@ impl ColumnInfo {
                         .collect();
                     ColumnType::Enum { name, variants }
                 }
-                Type::Array(array_def) => ColumnType::Array(RcOrArc::new(write_type(
-                    array_def.col_type.as_ref().expect("Array type not defined"),
-                ))),
+                Type::Array(array_def) => {
+                    let mut col_type = ColumnType::Array(RcOrArc::new(write_type(
+                        array_def.col_type.as_ref().expect("Array type not defined"),
+                    )));
+                    for _ in 1..array_def.dimensions {
+                        col_type = ColumnType::Array(RcOrArc::new(col_type));
+                    }
+                    col_type
+                }
             }
         }
         write_type(&self.col_type)
     }
 }
+
+#[cfg(feature = "postgres-postgis")]
+fn write_geometry_type(name: &str, def: &GeometryDef) -> String {
+    let mut string = name.to_owned();
+    if def.subtype.is_none() && def.srid.is_none() && def.dims == GeometryDims::Xy {
+        return string;
+    }
+    write!(
+        &mut string,
+        "({}",
+        def.subtype.as_deref().unwrap_or("Geometry")
+    )
+    .unwrap();
+    string.push_str(match def.dims {
+        GeometryDims::Xy => "",
+        GeometryDims::Xyz => "Z",
+        GeometryDims::Xym => "M",
+        GeometryDims::Xyzm => "ZM",
+    });
+    if let Some(srid) = def.srid {
+        write!(&mut string, ",{}", srid).unwrap();
+    }
+    string.push(')');
+    string
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::postgres::def::*;
+    use sea_query::{ColumnType, RcOrArc};
+
+    #[test]
+    fn test_multi_dimensional_array() {
+        assert_eq!(
+            ColumnInfo {
+                name: "matrix".to_owned(),
+                col_type: Type::Array(ArrayDef {
+                    col_type: Some(RcOrArc::new(Type::Integer)),
+                    dimensions: 2,
+                }),
+                default: None,
+                generated: None,
+                not_null: None,
+                is_identity: false,
+            }
+            .write_col_type(),
+            ColumnType::Array(RcOrArc::new(ColumnType::Array(RcOrArc::new(
+                ColumnType::Integer
+            ))))
+        );
+    }
+
+    #[test]
+    fn test_multirange() {
+        use sea_query::{Alias, IntoIden};
+
+        assert_eq!(
+            ColumnInfo {
+                name: "availability".to_owned(),
+                col_type: Type::MultiRange(MultiRangeDef {
+                    typename: "floatmultirange".to_owned(),
+                    range: RangeDef {
+                        typename: "floatrange".to_owned(),
+                        subtype: Some(RcOrArc::new(Type::DoublePrecision)),
+                        multirange: Some("floatmultirange".to_owned()),
+                    },
+                }),
+                default: None,
+                generated: None,
+                not_null: None,
+                is_identity: false,
+            }
+            .write_col_type(),
+            ColumnType::Custom(Alias::new("floatmultirange").into_iden())
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "postgres-postgis")]
+    fn test_geometry() {
+        use super::write_geometry_type;
+
+        let geometry = |subtype: Option<&str>, srid, dims| GeometryDef {
+            subtype: subtype.map(Into::into),
+            srid,
+            dims,
+        };
+        assert_eq!(
+            write_geometry_type("geometry", &geometry(None, None, GeometryDims::Xy)),
+            "geometry"
+        );
+        assert_eq!(
+            write_geometry_type("geometry", &geometry(None, None, GeometryDims::Xyz)),
+            "geometry(GeometryZ)"
+        );
+        assert_eq!(
+            write_geometry_type("geography", &geometry(None, None, GeometryDims::Xyzm)),
+            "geography(GeometryZM)"
+        );
+        assert_eq!(
+            write_geometry_type(
+                "geometry",
+                &geometry(Some("Point"), Some(4326), GeometryDims::Xym)
+            ),
+            "geometry(PointM,4326)"
+        );
+    }
+}
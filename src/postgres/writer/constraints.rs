@@ -25,9 +25,17 @@ impl Unique {
 
 impl References {
     pub fn write(&self) -> ForeignKeyCreateStatement {
+        self.write_in(None)
+    }
+
+    /// Write the foreign key, referencing a table of the same schema as qualified by `schema`
+    pub(crate) fn write_in(&self, schema: Option<&str>) -> ForeignKeyCreateStatement {
         let mut key = ForeignKey::create();
         key.name(&self.name);
-        key.to_tbl(Alias::new(&self.table));
+        match self.schema.as_deref().or(schema) {
+            Some(schema) => key.to_tbl((Alias::new(schema), Alias::new(&self.table))),
+            None => key.to_tbl(Alias::new(&self.table)),
+        };
         for column in self.columns.iter() {
             key.from_col(Alias::new(column.as_str()));
         }
@@ -55,3 +63,42 @@ impl References {
         key.take()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sea_query::PostgresQueryBuilder;
+
+    fn references(schema: Option<&str>) -> References {
+        References {
+            name: "fk-order-customer".to_owned(),
+            columns: vec!["customer_id".to_owned()],
+            schema: schema.map(Into::into),
+            table: "customer".to_owned(),
+            foreign_columns: vec!["id".to_owned()],
+            on_update: None,
+            on_delete: Some(ForeignKeyAction::Cascade),
+        }
+    }
+
+    #[test]
+    fn test_references_schema() {
+        let sql = |mut key: ForeignKeyCreateStatement| {
+            key.from_tbl(Alias::new("order"))
+                .to_string(PostgresQueryBuilder)
+        };
+
+        assert_eq!(
+            sql(references(None).write()),
+            r#"ALTER TABLE "order" ADD CONSTRAINT "fk-order-customer" FOREIGN KEY ("customer_id") REFERENCES "customer" ("id") ON DELETE CASCADE"#
+        );
+        assert_eq!(
+            sql(references(None).write_in(Some("shop"))),
+            r#"ALTER TABLE "order" ADD CONSTRAINT "fk-order-customer" FOREIGN KEY ("customer_id") REFERENCES "shop"."customer" ("id") ON DELETE CASCADE"#
+        );
+        assert_eq!(
+            sql(references(Some("crm")).write_in(Some("shop"))),
+            r#"ALTER TABLE "order" ADD CONSTRAINT "fk-order-customer" FOREIGN KEY ("customer_id") REFERENCES "crm"."customer" ("id") ON DELETE CASCADE"#
+        );
+    }
+}
@@ -1,4 +1,4 @@
-use crate::postgres::def::EnumDef;
+use crate::postgres::def::{self, Database, EnumDef, Schema, TableDef};
 use sea_query::{
     extension::postgres::{Type, TypeCreateStatement},
     Alias,
@@ -13,3 +13,131 @@ impl EnumDef {
             .to_owned()
     }
 }
+
+impl Schema {
+    /// Write the `CREATE TYPE` statements of the enums the columns use, to be run before the
+    /// statements of [Schema::write]
+    pub fn write_enums(&self) -> Vec<TypeCreateStatement> {
+        write_enums(self.tables.iter())
+    }
+}
+
+impl Database {
+    /// Write the `CREATE TYPE` statements of the enums the columns of every schema use, to be run
+    /// before the statements of [Database::write]
+    pub fn write_enums(&self) -> Vec<TypeCreateStatement> {
+        write_enums(self.schemas.iter().flat_map(|schema| schema.tables.iter()))
+    }
+}
+
+/// Write every enum used by the columns of `tables` once, in the order they are first used
+fn write_enums<'a>(tables: impl Iterator<Item = &'a TableDef>) -> Vec<TypeCreateStatement> {
+    let mut enums: Vec<&EnumDef> = Vec::new();
+    for column in tables.flat_map(|table| table.columns.iter()) {
+        if let Some(enum_def) = enum_of(&column.col_type) {
+            if !enums.iter().any(|e| e.typename == enum_def.typename) {
+                enums.push(enum_def);
+            }
+        }
+    }
+    enums.iter().map(|enum_def| enum_def.write()).collect()
+}
+
+/// The enum of a column type, or of the elements of an array type
+fn enum_of(col_type: &def::Type) -> Option<&EnumDef> {
+    match col_type {
+        def::Type::Enum(enum_def) => Some(enum_def),
+        def::Type::Array(array_def) => array_def.col_type.as_deref().and_then(enum_of),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::postgres::def::*;
+    use sea_query::{PostgresQueryBuilder, RcOrArc};
+
+    fn table(name: &str, col_types: Vec<Type>) -> TableDef {
+        TableDef {
+            info: TableInfo {
+                name: name.to_owned(),
+                of_type: None,
+            },
+            columns: col_types
+                .into_iter()
+                .enumerate()
+                .map(|(i, col_type)| ColumnInfo {
+                    name: format!("column_{}", i),
+                    col_type,
+                    default: None,
+                    generated: None,
+                    not_null: None,
+                    is_identity: false,
+                })
+                .collect(),
+            check_constraints: vec![],
+            not_null_constraints: vec![],
+            unique_constraints: vec![],
+            primary_key_constraints: vec![],
+            reference_constraints: vec![],
+            exclusion_constraints: vec![],
+            row_level_security: RowLevelSecurity::default(),
+            policies: vec![],
+        }
+    }
+
+    #[test]
+    fn test_1() {
+        let mood = EnumDef {
+            values: vec!["happy".to_owned(), "sad".to_owned()],
+            typename: "mood".to_owned(),
+        };
+        let size = EnumDef {
+            values: vec!["small".to_owned(), "large".to_owned()],
+            typename: "size".to_owned(),
+        };
+        let schema = |name: &str, tables| Schema {
+            schema: name.to_owned(),
+            extensions: vec![],
+            tables,
+            privileges: vec![],
+        };
+        let database = Database {
+            extensions: vec![],
+            schemas: vec![
+                schema(
+                    "public",
+                    vec![table(
+                        "person",
+                        vec![Type::Integer, Type::Enum(mood.clone())],
+                    )],
+                ),
+                schema(
+                    "shop",
+                    vec![table(
+                        "cake",
+                        vec![
+                            Type::Array(ArrayDef {
+                                col_type: Some(RcOrArc::new(Type::Enum(size))),
+                                dimensions: 1,
+                            }),
+                            Type::Enum(mood),
+                        ],
+                    )],
+                ),
+            ],
+        };
+        assert_eq!(
+            database
+                .write_enums()
+                .iter()
+                .map(|enum_def| enum_def.to_string(PostgresQueryBuilder))
+                .collect::<Vec<_>>(),
+            [
+                r#"CREATE TYPE "mood" AS ENUM ('happy', 'sad')"#,
+                r#"CREATE TYPE "size" AS ENUM ('small', 'large')"#,
+            ]
+        );
+        assert_eq!(database.schemas[0].write_enums().len(), 1);
+    }
+}
@@ -0,0 +1,92 @@
+use super::quote_iden;
+use crate::postgres::def::{Database, ExtensionDef, Schema};
+use sea_query::extension::postgres::{Extension, ExtensionCreateStatement};
+
+impl ExtensionDef {
+    /// Converts the [ExtensionDef] to an [ExtensionCreateStatement]. The version is left out,
+    /// so the default version available on the target database gets installed.
+    pub fn write(&self) -> ExtensionCreateStatement {
+        Extension::create()
+            .name(quote_iden(&self.name))
+            .schema(quote_iden(&self.schema))
+            .if_not_exists()
+            .to_owned()
+    }
+}
+
+/// The extensions installed in every database, which need not be created
+const BUILT_IN_EXTENSIONS: &[&str] = &["plpgsql"];
+
+impl ExtensionDef {
+    /// Whether the extension is installed in every database, like `plpgsql`
+    pub fn is_built_in(&self) -> bool {
+        BUILT_IN_EXTENSIONS.contains(&self.name.as_str())
+    }
+}
+
+impl Schema {
+    /// Write the `CREATE EXTENSION` statements, to be run before the statements of [Schema::write].
+    /// The built-in extensions are skipped
+    pub fn write_extensions(&self) -> Vec<ExtensionCreateStatement> {
+        write_extensions(&self.extensions)
+    }
+}
+
+impl Database {
+    /// Write the `CREATE EXTENSION` statements, to be run before the statements of
+    /// [Database::write]. The built-in extensions are skipped
+    pub fn write_extensions(&self) -> Vec<ExtensionCreateStatement> {
+        write_extensions(&self.extensions)
+    }
+}
+
+fn write_extensions(extensions: &[ExtensionDef]) -> Vec<ExtensionCreateStatement> {
+    extensions
+        .iter()
+        .filter(|extension| !extension.is_built_in())
+        .map(|extension| extension.write())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::postgres::def::*;
+    use sea_query::PostgresQueryBuilder;
+
+    #[test]
+    fn test_1() {
+        assert_eq!(
+            ExtensionDef {
+                name: "uuid-ossp".to_owned(),
+                schema: "public".to_owned(),
+                version: "1.1".to_owned(),
+            }
+            .write()
+            .to_string(PostgresQueryBuilder),
+            r#"CREATE EXTENSION IF NOT EXISTS "uuid-ossp" WITH SCHEMA "public""#
+        );
+    }
+
+    #[test]
+    fn test_2() {
+        let extension = |name: &str| ExtensionDef {
+            name: name.to_owned(),
+            schema: "public".to_owned(),
+            version: "1.0".to_owned(),
+        };
+        let schema = Schema {
+            schema: "public".to_owned(),
+            extensions: vec![extension("plpgsql"), extension("citext")],
+            tables: vec![],
+            privileges: vec![],
+        };
+        assert_eq!(
+            schema
+                .write_extensions()
+                .iter()
+                .map(|extension| extension.to_string(PostgresQueryBuilder))
+                .collect::<Vec<_>>(),
+            [r#"CREATE EXTENSION IF NOT EXISTS "citext" WITH SCHEMA "public""#]
+        );
+    }
+}
@@ -1,15 +1,50 @@
 mod column;
 mod constraints;
 mod enumeration;
+mod extension;
+mod policy;
+mod privilege;
 mod schema;
 mod table;
 mod types;
 
-use super::def::Schema;
-use sea_query::TableCreateStatement;
+use super::def::{Database, Schema};
+use sea_query::{PostgresQueryBuilder, QuotedBuilder, TableCreateStatement};
 
 impl Schema {
+    /// Write the tables, to be run after the statements of [Schema::write_extensions] and
+    /// [Schema::write_enums]
     pub fn write(&self) -> Vec<TableCreateStatement> {
         self.tables.iter().map(|table| table.write()).collect()
     }
 }
+
+impl Database {
+    /// Write the tables of every schema, qualified by the schema name. They are to be run after
+    /// the statements of [Database::write_extensions] and [Database::write_enums], and followed by
+    /// those of [Database::write_row_level_security] and [Database::write_privileges]
+    pub fn write(&self) -> Vec<TableCreateStatement> {
+        self.schemas
+            .iter()
+            .flat_map(|schema| {
+                schema
+                    .tables
+                    .iter()
+                    .map(|table| table.write_in(Some(&schema.schema)))
+            })
+            .collect()
+    }
+}
+
+/// Quote an identifier the way PostgreSQL expects, for statements SeaQuery cannot build
+pub(crate) fn quote_iden(name: &str) -> String {
+    crate::util::quote_iden(name, PostgresQueryBuilder.quote())
+}
+
+/// Quote the name of a table, qualified by `schema` if given
+pub(crate) fn quote_table(schema: Option<&str>, table: &str) -> String {
+    match schema {
+        Some(schema) => format!("{}.{}", quote_iden(schema), quote_iden(table)),
+        None => quote_iden(table),
+    }
+}
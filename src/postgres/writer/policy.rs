@@ -0,0 +1,169 @@
+use super::{quote_iden, quote_table};
+use crate::postgres::def::{Database, Policy, TableDef};
+use sea_query::Iden;
+
+impl Policy {
+    /// Converts the [Policy] into a `CREATE POLICY` statement on the given table
+    pub fn write(&self, table: &str) -> String {
+        self.write_on(&quote_iden(table))
+    }
+
+    /// Write the `CREATE POLICY` statement on `table`, already quoted
+    pub(crate) fn write_on(&self, table: &str) -> String {
+        let mut sql = format!(
+            "CREATE POLICY {} ON {} AS {} FOR {}",
+            quote_iden(&self.name),
+            table,
+            self.mode.to_string(),
+            self.command.to_string(),
+        );
+        if !self.roles.is_empty() {
+            let roles: Vec<String> = self
+                .roles
+                .iter()
+                .map(|role| match role.as_str() {
+                    "public" => "PUBLIC".to_owned(),
+                    _ => quote_iden(role),
+                })
+                .collect();
+            sql.push_str(&format!(" TO {}", roles.join(", ")));
+        }
+        if let Some(using) = &self.using {
+            sql.push_str(&format!(" USING ({})", using));
+        }
+        if let Some(with_check) = &self.with_check {
+            sql.push_str(&format!(" WITH CHECK ({})", with_check));
+        }
+        sql
+    }
+}
+
+impl TableDef {
+    /// Write the statements enabling row-level security on the table, followed by its policies
+    pub fn write_row_level_security(&self) -> Vec<String> {
+        self.write_row_level_security_in(None)
+    }
+
+    /// Write the row-level security of the table, qualified by `schema`
+    pub(crate) fn write_row_level_security_in(&self, schema: Option<&str>) -> Vec<String> {
+        let table = quote_table(schema, &self.info.name);
+        let mut stmts = Vec::new();
+        if self.row_level_security.enabled {
+            stmts.push(format!("ALTER TABLE {} ENABLE ROW LEVEL SECURITY", table));
+        }
+        if self.row_level_security.forced {
+            stmts.push(format!("ALTER TABLE {} FORCE ROW LEVEL SECURITY", table));
+        }
+        for policy in self.policies.iter() {
+            stmts.push(policy.write_on(&table));
+        }
+        stmts
+    }
+}
+
+impl Database {
+    /// Write the row-level security of the tables of every schema, qualified by the schema name
+    pub fn write_row_level_security(&self) -> Vec<String> {
+        self.schemas
+            .iter()
+            .flat_map(|schema| {
+                schema
+                    .tables
+                    .iter()
+                    .flat_map(move |table| table.write_row_level_security_in(Some(&schema.schema)))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::postgres::def::*;
+
+    #[test]
+    fn test_1() {
+        assert_eq!(
+            Policy {
+                name: "tenant_isolation".to_owned(),
+                command: PolicyCommand::All,
+                mode: PolicyMode::Permissive,
+                roles: vec!["public".to_owned()],
+                using: Some(
+                    "(tenant_id = (current_setting('app.tenant_id'::text))::integer)".to_owned()
+                ),
+                with_check: None,
+            }
+            .write("account"),
+            [
+                r#"CREATE POLICY "tenant_isolation" ON "account" AS PERMISSIVE FOR ALL TO PUBLIC"#,
+                r#"USING ((tenant_id = (current_setting('app.tenant_id'::text))::integer))"#,
+            ]
+            .join(" ")
+        );
+    }
+
+    #[test]
+    fn test_2() {
+        assert_eq!(
+            Policy {
+                name: "insert_own".to_owned(),
+                command: PolicyCommand::Insert,
+                mode: PolicyMode::Restrictive,
+                roles: vec!["app_user".to_owned(), "Auditor".to_owned()],
+                using: None,
+                with_check: Some("(owner = CURRENT_USER)".to_owned()),
+            }
+            .write("document"),
+            [
+                r#"CREATE POLICY "insert_own" ON "document" AS RESTRICTIVE FOR INSERT"#,
+                r#"TO "app_user", "Auditor" WITH CHECK ((owner = CURRENT_USER))"#,
+            ]
+            .join(" ")
+        );
+    }
+
+    #[test]
+    fn test_3() {
+        let table = TableDef {
+            info: TableInfo {
+                name: "account".to_owned(),
+                of_type: None,
+            },
+            columns: vec![],
+            check_constraints: vec![],
+            not_null_constraints: vec![],
+            unique_constraints: vec![],
+            primary_key_constraints: vec![],
+            reference_constraints: vec![],
+            exclusion_constraints: vec![],
+            row_level_security: RowLevelSecurity {
+                enabled: true,
+                forced: false,
+            },
+            policies: vec![Policy {
+                name: "owner_only".to_owned(),
+                command: PolicyCommand::Select,
+                mode: PolicyMode::Permissive,
+                roles: vec![],
+                using: Some("(owner = CURRENT_USER)".to_owned()),
+                with_check: None,
+            }],
+        };
+        let database = Database {
+            extensions: vec![],
+            schemas: vec![Schema {
+                schema: "bank".to_owned(),
+                extensions: vec![],
+                tables: vec![table],
+                privileges: vec![],
+            }],
+        };
+        assert_eq!(
+            database.write_row_level_security(),
+            [
+                r#"ALTER TABLE "bank"."account" ENABLE ROW LEVEL SECURITY"#,
+                r#"CREATE POLICY "owner_only" ON "bank"."account" AS PERMISSIVE FOR SELECT USING ((owner = CURRENT_USER))"#,
+            ]
+        );
+    }
+}
@@ -0,0 +1,114 @@
+use super::{quote_iden, quote_table};
+use crate::postgres::def::{Database, PrivilegeInfo, Schema};
+
+impl PrivilegeInfo {
+    /// Converts the [PrivilegeInfo] into a `GRANT` statement
+    pub fn write(&self) -> String {
+        self.write_in(None)
+    }
+
+    /// Write the `GRANT` statement, on the table qualified by `schema`
+    pub(crate) fn write_in(&self, schema: Option<&str>) -> String {
+        let mut sql = format!("GRANT {}", self.privilege);
+        if let Some(column) = &self.column {
+            sql.push_str(&format!(" ({})", quote_iden(column)));
+        }
+        let grantee = match self.grantee.as_str() {
+            "PUBLIC" => "PUBLIC".to_owned(),
+            _ => quote_iden(&self.grantee),
+        };
+        sql.push_str(&format!(
+            " ON {} TO {}",
+            quote_table(schema, &self.table),
+            grantee
+        ));
+        if self.grantable {
+            sql.push_str(" WITH GRANT OPTION");
+        }
+        sql
+    }
+}
+
+impl Schema {
+    /// Write the `GRANT` statements of all table and column privileges
+    pub fn write_privileges(&self) -> Vec<String> {
+        self.privileges
+            .iter()
+            .map(|privilege| privilege.write())
+            .collect()
+    }
+}
+
+impl Database {
+    /// Write the `GRANT` statements of every schema, on the tables qualified by the schema name
+    pub fn write_privileges(&self) -> Vec<String> {
+        self.schemas
+            .iter()
+            .flat_map(|schema| {
+                schema
+                    .privileges
+                    .iter()
+                    .map(move |privilege| privilege.write_in(Some(&schema.schema)))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::postgres::def::*;
+
+    #[test]
+    fn test_1() {
+        assert_eq!(
+            PrivilegeInfo {
+                grantee: "PUBLIC".to_owned(),
+                table: "actor".to_owned(),
+                column: None,
+                privilege: "SELECT".to_owned(),
+                grantable: false,
+            }
+            .write(),
+            r#"GRANT SELECT ON "actor" TO PUBLIC"#
+        );
+    }
+
+    #[test]
+    fn test_2() {
+        assert_eq!(
+            PrivilegeInfo {
+                grantee: "Clerk".to_owned(),
+                table: "customer".to_owned(),
+                column: Some("email".to_owned()),
+                privilege: "UPDATE".to_owned(),
+                grantable: true,
+            }
+            .write(),
+            r#"GRANT UPDATE ("email") ON "customer" TO "Clerk" WITH GRANT OPTION"#
+        );
+    }
+
+    #[test]
+    fn test_3() {
+        let privilege = PrivilegeInfo {
+            grantee: "PUBLIC".to_owned(),
+            table: "actor".to_owned(),
+            column: None,
+            privilege: "SELECT".to_owned(),
+            grantable: false,
+        };
+        let database = Database {
+            extensions: vec![],
+            schemas: vec![Schema {
+                schema: "film".to_owned(),
+                extensions: vec![],
+                tables: vec![],
+                privileges: vec![privilege],
+            }],
+        };
+        assert_eq!(
+            database.write_privileges(),
+            [r#"GRANT SELECT ON "film"."actor" TO PUBLIC"#]
+        );
+    }
+}
@@ -3,8 +3,16 @@ use sea_query::{Alias, Table, TableCreateStatement};
 
 impl TableDef {
     pub fn write(&self) -> TableCreateStatement {
+        self.write_in(None)
+    }
+
+    /// Write the table, qualified by `schema` along with the tables it references in the same schema
+    pub(crate) fn write_in(&self, schema: Option<&str>) -> TableCreateStatement {
         let mut table = Table::create();
-        table.table(Alias::new(&self.info.name));
+        match schema {
+            Some(schema) => table.table((Alias::new(schema), Alias::new(&self.info.name))),
+            None => table.table(Alias::new(&self.info.name)),
+        };
         for col in self.columns.iter() {
             table.col(col.write());
         }
@@ -15,7 +23,7 @@ impl TableDef {
             table.index(&mut unique.write());
         }
         for reference in self.reference_constraints.iter() {
-            table.foreign_key(&mut reference.write());
+            table.foreign_key(&mut reference.write_in(schema));
         }
         table
     }
@@ -0,0 +1,261 @@
+#[cfg(feature = "with-serde")]
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// A value of a [SchemaRow]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
+pub enum SchemaValue {
+    Null,
+    Bool(bool),
+    Int(i64),
+    /// An unsigned integer, as MySQL returns for `UNSIGNED` columns
+    UInt(u64),
+    Float(f64),
+    String(String),
+    Bytes(Vec<u8>),
+    /// A Postgres array
+    Array(Vec<SchemaValue>),
+}
+
+/// A row returned by a schema executor, detached from the driver that fetched it.
+/// The discovery reads the columns by position
+#[derive(Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
+pub struct SchemaRow {
+    values: Vec<SchemaValue>,
+}
+
+/// An error reading a column of a [SchemaRow]
+#[derive(Debug, Clone, PartialEq)]
+pub enum SchemaRowError {
+    /// The row has no column at this index
+    IndexOutOfBounds { index: usize, len: usize },
+    /// The value of the column can't be converted to the expected type
+    UnexpectedValue {
+        index: usize,
+        expected: &'static str,
+        value: SchemaValue,
+    },
+    /// The column is a string that is not valid UTF-8
+    InvalidUtf8 { index: usize, bytes: Vec<u8> },
+}
+
+/// Converts a [SchemaValue] into a Rust type, `None` if the value is of another type
+pub trait FromSchemaValue: Sized {
+    fn from_schema_value(value: &SchemaValue) -> Option<Self>;
+}
+
+impl SchemaRow {
+    pub fn new(values: Vec<SchemaValue>) -> Self {
+        Self { values }
+    }
+
+    pub fn values(&self) -> &[SchemaValue] {
+        &self.values
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Read the column at `index`
+    pub fn try_get<T>(&self, index: usize) -> Result<T, SchemaRowError>
+    where
+        T: FromSchemaValue,
+    {
+        let value = self
+            .values
+            .get(index)
+            .ok_or(SchemaRowError::IndexOutOfBounds {
+                index,
+                len: self.values.len(),
+            })?;
+        T::from_schema_value(value).ok_or_else(|| match value {
+            SchemaValue::Bytes(bytes) if std::str::from_utf8(bytes).is_err() => {
+                SchemaRowError::InvalidUtf8 {
+                    index,
+                    bytes: bytes.clone(),
+                }
+            }
+            value => SchemaRowError::UnexpectedValue {
+                index,
+                expected: std::any::type_name::<T>(),
+                value: value.clone(),
+            },
+        })
+    }
+
+    /// Read the column at `index`.
+    /// Panics if the column does not exist or has an unexpected type, like `sqlx::Row::get`
+    pub fn get<T>(&self, index: usize) -> T
+    where
+        T: FromSchemaValue,
+    {
+        match self.try_get(index) {
+            Ok(value) => value,
+            Err(err) => panic!("{}", err),
+        }
+    }
+}
+
+impl From<Vec<SchemaValue>> for SchemaRow {
+    fn from(values: Vec<SchemaValue>) -> Self {
+        Self::new(values)
+    }
+}
+
+impl std::error::Error for SchemaRowError {}
+
+impl fmt::Display for SchemaRowError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::IndexOutOfBounds { index, len } => {
+                write!(
+                    f,
+                    "Column index {index} out of bounds, the row has {len} columns"
+                )
+            }
+            Self::UnexpectedValue {
+                index,
+                expected,
+                value,
+            } => write!(f, "Expected {expected} in column {index}, found {value:?}"),
+            Self::InvalidUtf8 { index, .. } => write!(f, "Invalid UTF-8 in column {index}"),
+        }
+    }
+}
+
+impl<T> FromSchemaValue for Option<T>
+where
+    T: FromSchemaValue,
+{
+    fn from_schema_value(value: &SchemaValue) -> Option<Self> {
+        match value {
+            SchemaValue::Null => Some(None),
+            value => T::from_schema_value(value).map(Some),
+        }
+    }
+}
+
+impl FromSchemaValue for String {
+    fn from_schema_value(value: &SchemaValue) -> Option<Self> {
+        match value {
+            SchemaValue::String(string) => Some(string.clone()),
+            // MySQL returns some of its INFORMATION_SCHEMA as binary strings
+            SchemaValue::Bytes(bytes) => String::from_utf8(bytes.clone()).ok(),
+            _ => None,
+        }
+    }
+}
+
+impl FromSchemaValue for Vec<u8> {
+    fn from_schema_value(value: &SchemaValue) -> Option<Self> {
+        match value {
+            SchemaValue::Bytes(bytes) => Some(bytes.clone()),
+            SchemaValue::String(string) => Some(string.clone().into_bytes()),
+            _ => None,
+        }
+    }
+}
+
+impl FromSchemaValue for bool {
+    fn from_schema_value(value: &SchemaValue) -> Option<Self> {
+        match value {
+            SchemaValue::Bool(bool) => Some(*bool),
+            // MySQL and SQLite have no boolean type
+            SchemaValue::Int(int) => Some(*int != 0),
+            SchemaValue::UInt(int) => Some(*int != 0),
+            _ => None,
+        }
+    }
+}
+
+impl FromSchemaValue for f64 {
+    fn from_schema_value(value: &SchemaValue) -> Option<Self> {
+        match value {
+            SchemaValue::Float(float) => Some(*float),
+            SchemaValue::Int(int) => Some(*int as f64),
+            SchemaValue::UInt(int) => Some(*int as f64),
+            _ => None,
+        }
+    }
+}
+
+impl FromSchemaValue for Vec<String> {
+    fn from_schema_value(value: &SchemaValue) -> Option<Self> {
+        match value {
+            SchemaValue::Array(values) => values.iter().map(String::from_schema_value).collect(),
+            _ => None,
+        }
+    }
+}
+
+macro_rules! from_schema_value_int {
+    ( $( $int: ty ),* ) => {
+        $(
+            impl FromSchemaValue for $int {
+                fn from_schema_value(value: &SchemaValue) -> Option<Self> {
+                    match value {
+                        SchemaValue::Int(int) => <$int>::try_from(*int).ok(),
+                        SchemaValue::UInt(int) => <$int>::try_from(*int).ok(),
+                        SchemaValue::Bool(bool) => Some(<$int>::from(*bool)),
+                        _ => None,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+from_schema_value_int!(i8, i16, i32, i64, u8, u16, u32, u64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_schema_row() {
+        let row = SchemaRow::new(vec![
+            SchemaValue::Bytes(b"utf8mb4".to_vec()),
+            SchemaValue::Null,
+            SchemaValue::Int(1),
+            SchemaValue::UInt(u64::MAX),
+            SchemaValue::Array(vec![SchemaValue::String("public".to_owned())]),
+        ]);
+
+        assert_eq!(row.get::<String>(0), "utf8mb4");
+        assert_eq!(row.get::<Option<String>>(1), None);
+        assert_eq!(row.get::<Option<i32>>(2), Some(1));
+        assert!(row.get::<bool>(2));
+        assert_eq!(row.get::<u64>(3), u64::MAX);
+        assert_eq!(row.get::<Vec<String>>(4), ["public"]);
+
+        assert_eq!(
+            row.try_get::<i64>(3),
+            Err(SchemaRowError::UnexpectedValue {
+                index: 3,
+                expected: "i64",
+                value: SchemaValue::UInt(u64::MAX),
+            })
+        );
+        assert_eq!(
+            row.try_get::<String>(5),
+            Err(SchemaRowError::IndexOutOfBounds { index: 5, len: 5 })
+        );
+
+        let row = SchemaRow::new(vec![SchemaValue::Bytes(vec![0xff])]);
+        assert_eq!(
+            row.try_get::<String>(0),
+            Err(SchemaRowError::InvalidUtf8 {
+                index: 0,
+                bytes: vec![0xff],
+            })
+        );
+        assert_eq!(row.get::<Vec<u8>>(0), [0xff]);
+    }
+}
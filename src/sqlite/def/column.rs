@@ -1,12 +1,14 @@
-use super::{parse_type, DefaultType};
+use super::{parse_default, parse_type, Affinity, DefaultType};
 use sea_query::{
-    foreign_key::ForeignKeyAction as SeaQueryForeignKeyAction, Alias, ColumnType, Index,
-    IndexCreateStatement,
+    foreign_key::ForeignKeyAction as SeaQueryForeignKeyAction, Alias, ColumnType,
+    ConditionalStatement, DynIden, Expr, Iden, Index, IndexColumn, IndexCreateStatement,
+    IndexOrder, IntoIndexColumn, Quote, QuotedBuilder, SeaRc, SqliteQueryBuilder,
 };
-use std::num::ParseIntError;
+use std::fmt;
 
-#[allow(unused_imports)]
-use crate::sqlx_types::{sqlite::SqliteRow, Row};
+use crate::sqlite::error::SqliteDiscoveryError;
+use crate::util::quote_iden;
+use crate::{SchemaRow, SchemaRowError};
 
 /// An SQLite column definition
 #[derive(Debug, PartialEq, Clone)]
@@ -14,52 +16,62 @@ pub struct ColumnInfo {
     pub cid: i32,
     pub name: String,
     pub r#type: ColumnType,
+    /// The type as declared, e.g. `VARCHAR(255)`
+    pub declared_type: String,
+    /// The type affinity the declared type gives the column
+    pub affinity: Affinity,
     pub not_null: bool,
     pub default_value: DefaultType,
     pub primary_key: bool,
+    /// Whether the column is hidden, which only happens to columns of virtual tables
+    pub hidden: bool,
+    /// The collating sequence of the COLLATE clause
+    pub collation: Option<String>,
+    /// The expression of a generated column
+    pub generated: Option<GeneratedColumnInfo>,
+}
+
+/// The `GENERATED ALWAYS AS (expr)` clause of a column
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct GeneratedColumnInfo {
+    pub expr: String,
+    /// Whether the value is `STORED` rather than computed when read, `VIRTUAL`
+    pub stored: bool,
+}
+
+/// A CHECK constraint, whether declared on a column or on the table
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct CheckInfo {
+    pub name: Option<String>,
+    pub expr: String,
 }
 
-#[cfg(feature = "sqlx-sqlite")]
 impl ColumnInfo {
-    /// Map an [SqliteRow] into a column definition type [ColumnInfo]
-    pub fn to_column_def(row: &SqliteRow) -> Result<ColumnInfo, ParseIntError> {
-        let col_not_null: i8 = row.get(3);
-        let is_pk: i8 = row.get(5);
-        let default_value: &str = row.get(4);
+    /// Map a [SchemaRow] of `PRAGMA table_xinfo` into a column definition type [ColumnInfo].
+    /// The collation and the expression of a generated column have to be parsed from the
+    /// `CREATE TABLE` statement, see `TableDef::get_create_table_info`
+    pub fn to_column_def(row: &SchemaRow) -> Result<ColumnInfo, SqliteDiscoveryError> {
+        let col_not_null: i8 = row.try_get(3)?;
+        let hidden: i8 = row.try_get(6)?;
+        let declared_type: String = row.try_get(2)?;
+        let is_pk: i8 = row.try_get(5)?;
+        let default_value: Option<String> = row.try_get(4)?;
         Ok(ColumnInfo {
-            cid: row.get(0),
-            name: row.get(1),
-            r#type: parse_type(row.get(2))?,
+            cid: row.try_get(0)?,
+            name: row.try_get(1)?,
+            r#type: parse_type(&declared_type)?,
+            affinity: Affinity::from_declared_type(&declared_type),
+            declared_type,
             not_null: col_not_null != 0,
-            default_value: if default_value == "NULL" {
-                DefaultType::Null
-            } else if default_value.is_empty() {
-                DefaultType::Unspecified
-            } else {
-                let value = default_value.to_owned().replace('\'', "");
-
-                if let Ok(is_int) = value.parse::<i32>() {
-                    DefaultType::Integer(is_int)
-                } else if let Ok(is_float) = value.parse::<f32>() {
-                    DefaultType::Float(is_float)
-                } else if value == "CURRENT_TIMESTAMP" {
-                    DefaultType::CurrentTimestamp
-                } else {
-                    DefaultType::String(value)
-                }
-            },
+            default_value: parse_default(default_value.as_deref()),
             primary_key: is_pk != 0,
+            hidden: hidden == 1,
+            collation: None,
+            generated: None,
         })
     }
 }
 
-#[cfg(not(feature = "sqlx-sqlite"))]
-impl ColumnInfo {
-    pub fn to_column_def(_: &SqliteRow) -> Result<ColumnInfo, ParseIntError> {
-        unimplemented!()
-    }
-}
-
 /// Maps the index and all columns in the index which is the result of queries
 /// `PRAGMA index_list(table_name)` and
 /// `SELECT * FROM sqlite_master where name = 'index_name'`
@@ -72,7 +84,66 @@ pub struct IndexInfo {
     pub unique: bool,
     pub origin: String,
     pub partial: i32,
+    /// The names of the indexed columns, an indexed expression is given as its SQL text
     pub columns: Vec<String>,
+    /// The indexed columns and expressions with their sort order and collation
+    pub column_info: Vec<IndexColumnInfo>,
+    /// The WHERE clause of a partial index
+    pub where_clause: Option<String>,
+}
+
+/// A column or expression of an index, as a result of using query `PRAGMA index_xinfo(index_name)`
+/// and parsing the `CREATE INDEX` statement
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct IndexColumnInfo {
+    /// The name of the column, `None` if an expression is indexed
+    pub name: Option<String>,
+    /// The indexed expression
+    pub expr: Option<String>,
+    /// Whether the column is sorted in descending order
+    pub desc: bool,
+    /// The collating sequence, if not the one of the column
+    pub collation: Option<String>,
+}
+
+/// SQL written as is where sea-query expects an identifier, as it can't write expressions or
+/// collations in an SQLite index, nor some table constraints, and would quote the SQL
+pub(crate) struct RawSql(pub(crate) String);
+
+impl Iden for RawSql {
+    fn prepare(&self, s: &mut dyn fmt::Write, _: Quote) {
+        self.unquoted(s);
+    }
+
+    fn unquoted(&self, s: &mut dyn fmt::Write) {
+        write!(s, "{}", self.0).unwrap();
+    }
+}
+
+impl IndexColumnInfo {
+    /// Write the column or expression into an [IndexColumn]
+    pub fn write(&self) -> IndexColumn {
+        let column: DynIden = match (&self.name, &self.expr, &self.collation) {
+            (Some(name), _, None) => SeaRc::new(Alias::new(name)),
+            (name, expr, collation) => {
+                let mut sql = match (name, expr) {
+                    (Some(name), _) => quote(name),
+                    (None, Some(expr)) => expr.to_owned(),
+                    (None, None) => String::new(),
+                };
+                if let Some(collation) = collation {
+                    sql.push_str(" COLLATE ");
+                    sql.push_str(collation);
+                }
+                SeaRc::new(RawSql(sql))
+            }
+        };
+        if self.desc {
+            (column, IndexOrder::Desc).into_index_column()
+        } else {
+            column.into_index_column()
+        }
+    }
 }
 
 impl IndexInfo {
@@ -89,9 +160,19 @@ impl IndexInfo {
             new_index.unique();
         }
 
-        self.columns.iter().for_each(|column| {
-            new_index.col(Alias::new(column));
-        });
+        if self.column_info.is_empty() {
+            self.columns.iter().for_each(|column| {
+                new_index.col(Alias::new(column));
+            });
+        } else {
+            self.column_info.iter().for_each(|column| {
+                new_index.col(column.write());
+            });
+        }
+
+        if let Some(where_clause) = &self.where_clause {
+            new_index.and_where(Expr::cust(where_clause));
+        }
 
         new_index
     }
@@ -109,29 +190,23 @@ pub(crate) struct PartialIndexInfo {
     pub(crate) partial: i32,
 }
 
-#[cfg(feature = "sqlx-sqlite")]
-impl From<&SqliteRow> for PartialIndexInfo {
-    fn from(row: &SqliteRow) -> Self {
-        let is_unique: i8 = row.get(2);
-        Self {
-            seq: row.get(0),
-            name: row.get(1),
-            unique: is_unique != 0,
-            origin: row.get(3),
-            partial: row.get(4),
-        }
-    }
-}
+impl TryFrom<&SchemaRow> for PartialIndexInfo {
+    type Error = SchemaRowError;
 
-#[cfg(not(feature = "sqlx-sqlite"))]
-impl From<&SqliteRow> for PartialIndexInfo {
-    fn from(_: &SqliteRow) -> Self {
-        Self::default()
+    fn try_from(row: &SchemaRow) -> Result<Self, Self::Error> {
+        let is_unique: i8 = row.try_get(2)?;
+        Ok(Self {
+            seq: row.try_get(0)?,
+            name: row.try_get(1)?,
+            unique: is_unique != 0,
+            origin: row.try_get(3)?,
+            partial: row.try_get(4)?,
+        })
     }
 }
 
-/// Maps all the columns in an index as a result of using query
-/// `SELECT * FROM sqlite_master where name = 'index_name'`
+/// Maps all the columns in an index as a result of using queries
+/// `SELECT * FROM sqlite_master where name = 'index_name'` and `PRAGMA index_xinfo(index_name)`
 #[allow(dead_code)]
 #[derive(Debug, Default, Clone)]
 pub(crate) struct IndexedColumns {
@@ -139,28 +214,57 @@ pub(crate) struct IndexedColumns {
     pub(crate) name: String,
     pub(crate) table: String,
     pub(crate) root_page: i32,
-    pub(crate) indexed_columns: Vec<String>,
+    pub(crate) sql: Option<String>,
+    pub(crate) indexed_columns: Vec<IndexColumnInfo>,
+    pub(crate) where_clause: Option<String>,
 }
 
-#[cfg(feature = "sqlx-sqlite")]
-impl From<(&SqliteRow, &[SqliteRow])> for IndexedColumns {
-    fn from((row, rows): (&SqliteRow, &[SqliteRow])) -> Self {
-        let columns_to_index = rows.iter().map(|row| row.get(2)).collect::<Vec<String>>();
-
-        Self {
-            r#type: row.get(0),
-            name: row.get(1),
-            table: row.get(2),
-            root_page: row.get(3),
-            indexed_columns: columns_to_index,
-        }
+impl IndexedColumns {
+    /// The names of the indexed columns, or the SQL text of indexed expressions
+    pub(crate) fn column_names(&self) -> Vec<String> {
+        self.indexed_columns
+            .iter()
+            .map(|column| {
+                column
+                    .name
+                    .clone()
+                    .or_else(|| column.expr.clone())
+                    .unwrap_or_default()
+            })
+            .collect()
     }
 }
 
-#[cfg(not(feature = "sqlx-sqlite"))]
-impl From<(&SqliteRow, &[SqliteRow])> for IndexedColumns {
-    fn from(_: (&SqliteRow, &[SqliteRow])) -> Self {
-        Self::default()
+impl TryFrom<(&SchemaRow, &[SchemaRow])> for IndexedColumns {
+    type Error = SchemaRowError;
+
+    fn try_from((row, rows): (&SchemaRow, &[SchemaRow])) -> Result<Self, Self::Error> {
+        let mut columns_to_index = Vec::new();
+        for row in rows {
+            // Only the key columns, the others are the rowid or primary key the index refers to
+            let key: i8 = row.try_get(5)?;
+            if key == 0 {
+                continue;
+            }
+            let desc: i8 = row.try_get(3)?;
+            let collation: Option<String> = row.try_get(4)?;
+            columns_to_index.push(IndexColumnInfo {
+                name: row.try_get(2)?,
+                expr: None,
+                desc: desc != 0,
+                collation: collation.filter(|collation| collation != "BINARY"),
+            });
+        }
+
+        Ok(Self {
+            r#type: row.try_get(0)?,
+            name: row.try_get(1)?,
+            table: row.try_get(2)?,
+            root_page: row.try_get(3)?,
+            sql: row.try_get(4)?,
+            indexed_columns: columns_to_index,
+            where_clause: None,
+        })
     }
 }
 
@@ -170,64 +274,124 @@ impl From<(&SqliteRow, &[SqliteRow])> for IndexedColumns {
 #[derive(Debug, Default, Clone)]
 pub(crate) struct PrimaryKeyAutoincrement(pub(crate) u8);
 
-#[cfg(feature = "sqlx-sqlite")]
-impl From<&SqliteRow> for PrimaryKeyAutoincrement {
-    fn from(row: &SqliteRow) -> Self {
-        Self(row.get(0))
-    }
-}
+impl TryFrom<&SchemaRow> for PrimaryKeyAutoincrement {
+    type Error = SchemaRowError;
 
-#[cfg(not(feature = "sqlx-sqlite"))]
-impl From<&SqliteRow> for PrimaryKeyAutoincrement {
-    fn from(_: &SqliteRow) -> Self {
-        Self::default()
+    fn try_from(row: &SchemaRow) -> Result<Self, Self::Error> {
+        Ok(Self(row.try_get(0)?))
     }
 }
 
 /// Indexes the foreign keys
-#[allow(dead_code)]
 #[derive(Debug, Default, Clone)]
 pub struct ForeignKeysInfo {
-    pub(crate) id: i32,
-    pub(crate) seq: i32,
-    pub(crate) table: String,
-    pub(crate) from: Vec<String>,
-    pub(crate) to: Vec<String>,
-    pub(crate) on_update: ForeignKeyAction,
-    pub(crate) on_delete: ForeignKeyAction,
-    pub(crate) r#match: MatchAction,
+    pub id: i32,
+    pub seq: i32,
+    /// The referenced table
+    pub table: String,
+    pub from: Vec<String>,
+    pub to: Vec<String>,
+    pub on_update: ForeignKeyAction,
+    pub on_delete: ForeignKeyAction,
+    pub r#match: MatchAction,
+    /// The constraint name, parsed from the `CREATE TABLE` statement
+    pub name: Option<String>,
+    /// The deferrable clause, parsed from the `CREATE TABLE` statement
+    pub deferrable: Option<Deferrable>,
 }
 
-#[cfg(feature = "sqlx-sqlite")]
-impl From<&SqliteRow> for ForeignKeysInfo {
-    fn from(row: &SqliteRow) -> Self {
-        Self {
-            id: row.get(0),
-            seq: row.get(1),
-            table: row.get(2),
-            from: vec![row.get(3)],
-            to: vec![row.get(4)],
+impl TryFrom<&SchemaRow> for ForeignKeysInfo {
+    type Error = SchemaRowError;
+
+    fn try_from(row: &SchemaRow) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: row.try_get(0)?,
+            seq: row.try_get(1)?,
+            table: row.try_get(2)?,
+            from: vec![row.try_get(3)?],
+            to: vec![row.try_get(4)?],
             on_update: {
-                let op: &str = row.get(5);
-                op.into()
+                let op: String = row.try_get(5)?;
+                op.as_str().into()
             },
             on_delete: {
-                let op: &str = row.get(6);
-                op.into()
+                let op: String = row.try_get(6)?;
+                op.as_str().into()
             },
             r#match: {
-                let op: &str = row.get(7);
-                op.into()
+                let op: String = row.try_get(7)?;
+                op.as_str().into()
             },
-        }
+            name: None,
+            deferrable: None,
+        })
     }
 }
 
-#[cfg(not(feature = "sqlx-sqlite"))]
-impl From<&SqliteRow> for ForeignKeysInfo {
-    fn from(_: &SqliteRow) -> Self {
-        Self::default()
+impl ForeignKeysInfo {
+    /// Whether the foreign key has a name, MATCH or deferrable clause,
+    /// which sea-query cannot write
+    pub(crate) fn has_clauses(&self) -> bool {
+        self.name.is_some() || self.r#match != MatchAction::None || self.deferrable.is_some()
+    }
+
+    /// Write the foreign key as a column constraint,
+    /// `[CONSTRAINT name] REFERENCES table (column) ... [MATCH x] [DEFERRABLE ...]`.
+    /// Returns `None` if the foreign key has none of these clauses, or spans several columns
+    pub(crate) fn write_column_constraint(&self) -> Option<String> {
+        if self.from.len() != 1 || !self.has_clauses() {
+            return None;
+        }
+        let mut sql = String::new();
+        self.write_constraint_name(&mut sql);
+        self.write_references(&mut sql);
+        Some(sql)
     }
+
+    /// Write the foreign key as a table constraint,
+    /// `[CONSTRAINT name] FOREIGN KEY (columns) REFERENCES table (columns) ...`
+    pub(crate) fn write_table_constraint(&self) -> String {
+        let mut sql = String::new();
+        self.write_constraint_name(&mut sql);
+        sql.push_str(&format!("FOREIGN KEY ({}) ", quote_list(&self.from)));
+        self.write_references(&mut sql);
+        sql
+    }
+
+    fn write_constraint_name(&self, sql: &mut String) {
+        if let Some(name) = &self.name {
+            sql.push_str(&format!("CONSTRAINT {} ", quote(name)));
+        }
+    }
+
+    fn write_references(&self, sql: &mut String) {
+        sql.push_str(&format!(
+            "REFERENCES {} ({}) ON DELETE {} ON UPDATE {}",
+            quote(&self.table),
+            quote_list(&self.to),
+            self.on_delete.as_str(),
+            self.on_update.as_str(),
+        ));
+        if let Some(r#match) = self.r#match.as_str() {
+            sql.push_str(&format!(" MATCH {}", r#match));
+        }
+        if let Some(deferrable) = &self.deferrable {
+            sql.push(' ');
+            sql.push_str(deferrable.as_str());
+        }
+    }
+}
+
+fn quote(name: &str) -> String {
+    quote_iden(name, SqliteQueryBuilder.quote())
+}
+
+fn quote_list(names: &[String]) -> String {
+    names
+        .iter()
+        .map(|name| quote(name))
+        .collect::<Vec<_>>()
+        .join(", ")
 }
 
 /// Indexes the actions performed on the foreign keys of a table
@@ -260,6 +424,16 @@ impl From<&str> for ForeignKeyAction {
 }
 
 impl ForeignKeyAction {
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            Self::NoAction => "NO ACTION",
+            Self::Restrict => "RESTRICT",
+            Self::SetNull => "SET NULL",
+            Self::SetDefault => "SET DEFAULT",
+            Self::Cascade => "CASCADE",
+        }
+    }
+
     pub(crate) fn to_seaquery_foreign_key_action(&self) -> SeaQueryForeignKeyAction {
         match self {
             Self::NoAction => SeaQueryForeignKeyAction::NoAction,
@@ -288,12 +462,46 @@ impl Default for MatchAction {
 
 impl From<&str> for MatchAction {
     fn from(action: &str) -> Self {
-        match action {
-            "MATCH SIMPLE" => Self::Simple,
-            "MATCH PARTIAL" => Self::Partial,
-            "MATCH FULL" => Self::Full,
-            "MATCH NONE" => Self::None,
+        match action.to_uppercase().trim_start_matches("MATCH ") {
+            "SIMPLE" => Self::Simple,
+            "PARTIAL" => Self::Partial,
+            "FULL" => Self::Full,
+            "NONE" => Self::None,
             _ => Self::None,
         }
     }
 }
+
+impl MatchAction {
+    /// The name written after `MATCH`, or `None` if there is no MATCH clause
+    pub(crate) fn as_str(&self) -> Option<&'static str> {
+        match self {
+            Self::Simple => Some("SIMPLE"),
+            Self::Partial => Some("PARTIAL"),
+            Self::Full => Some("FULL"),
+            Self::None => None,
+        }
+    }
+}
+
+/// The deferrable clause of a foreign key. SQLite only defers the checks of
+/// `DEFERRABLE INITIALLY DEFERRED` constraints
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Deferrable {
+    /// `DEFERRABLE` or `DEFERRABLE INITIALLY IMMEDIATE`
+    InitiallyImmediate,
+    /// `DEFERRABLE INITIALLY DEFERRED`
+    InitiallyDeferred,
+    /// `NOT DEFERRABLE`
+    NotDeferrable,
+}
+
+impl Deferrable {
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            Self::InitiallyImmediate => "DEFERRABLE INITIALLY IMMEDIATE",
+            Self::InitiallyDeferred => "DEFERRABLE INITIALLY DEFERRED",
+            Self::NotDeferrable => "NOT DEFERRABLE",
+        }
+    }
+}
@@ -2,8 +2,10 @@ mod column;
 mod schema;
 mod table;
 mod types;
+mod virtual_table;
 
 pub use column::*;
 pub use schema::*;
 pub use table::*;
 pub use types::*;
+pub use virtual_table::*;
@@ -1,8 +1,14 @@
-use super::{IndexInfo, TableDef};
+use super::{IndexInfo, TableDef, VirtualTableDef};
+
+use crate::{SchemaRow, SchemaRowError};
 
 #[derive(Clone, Debug)]
 pub struct Schema {
+    /// The name of the database, `main`, `temp` or the name of an attached database
+    pub name: String,
     pub tables: Vec<TableDef>,
+    /// The virtual tables, apart from the tables as they are written differently
+    pub virtual_tables: Vec<VirtualTableDef>,
     pub indexes: Vec<IndexInfo>,
 }
 
@@ -18,3 +24,24 @@ impl Schema {
         self
     }
 }
+
+/// A database of the connection as a result of using query `PRAGMA database_list`
+#[allow(dead_code)]
+#[derive(Debug, Default, Clone)]
+pub(crate) struct DatabaseInfo {
+    pub(crate) seq: i32,
+    pub(crate) name: String,
+    pub(crate) file: String,
+}
+
+impl TryFrom<&SchemaRow> for DatabaseInfo {
+    type Error = SchemaRowError;
+
+    fn try_from(row: &SchemaRow) -> Result<Self, Self::Error> {
+        Ok(Self {
+            seq: row.try_get(0)?,
+            name: row.try_get(1)?,
+            file: row.try_get(2)?,
+        })
+    }
+}
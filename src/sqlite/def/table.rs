@@ -1,21 +1,31 @@
 use sea_query::{
-    Alias, ColumnDef, Expr, ForeignKey, Index, Keyword, Query, Table, TableCreateStatement, Value,
+    Alias, Asterisk, ColumnDef, Expr, ForeignKey, Func, Index, IntoTableRef, Keyword, Query,
+    SelectStatement, SqliteQueryBuilder, Table, TableCreateStatement, TableRef, Value,
 };
 
+use std::collections::HashMap;
+
 use super::{
-    ColumnInfo, DefaultType, ForeignKeysInfo, IndexInfo, IndexedColumns, PartialIndexInfo,
+    CheckInfo, ColumnInfo, DefaultType, ForeignKeysInfo, IndexInfo, IndexedColumns,
+    PartialIndexInfo,
 };
+#[cfg(feature = "parser")]
+use crate::sqlite::parser::{parse_create_index, parse_create_table};
 use crate::sqlite::query::SqliteMaster;
 use crate::sqlite::{error::DiscoveryResult, executor::Executor};
+#[cfg(feature = "parser")]
+use crate::{warning::Warnings, DiscoveryWarning};
 
-#[allow(unused_imports)]
-use crate::sqlx_types::{sqlite::SqliteRow, Row};
+use crate::{SchemaRow, SchemaRowError};
 
 /// Defines a table for SQLite
 #[derive(Debug, Default, Clone)]
 pub struct TableDef {
     /// The table name
     pub name: String,
+    /// The database the table is in, `main`, `temp` or the name of an attached database.
+    /// An empty name means the main database
+    pub schema: String,
     /// A list of foreign keys in the table
     pub foreign_keys: Vec<ForeignKeysInfo>,
     /// A list of the indexes in the table
@@ -24,41 +34,169 @@ pub struct TableDef {
     pub constraints: Vec<IndexInfo>,
     /// A list of all the columns and their types
     pub columns: Vec<ColumnInfo>,
+    /// A list of the CHECK constraints, including those declared on a column
+    pub checks: Vec<CheckInfo>,
     /// Whether the primary key should autoincrement
     pub auto_increment: bool,
+    /// Whether the table is declared `WITHOUT ROWID`
+    pub without_rowid: bool,
+    /// Whether the table is declared `STRICT`
+    pub strict: bool,
+}
+
+/// The `sql` column of `sqlite_master`, which is NULL for internal tables and indexes
+#[derive(Debug, Default, Clone)]
+pub(crate) struct MasterSql(pub(crate) Option<String>);
+
+impl TryFrom<&SchemaRow> for MasterSql {
+    type Error = SchemaRowError;
+
+    fn try_from(row: &SchemaRow) -> Result<Self, Self::Error> {
+        Ok(Self(row.try_get(0)?))
+    }
+}
+
+/// A table listed in `sqlite_master`, with the `name`, `sql` and `rootpage` columns
+#[derive(Debug, Default, Clone)]
+pub(crate) struct MasterTableInfo {
+    pub(crate) name: String,
+    pub(crate) sql: Option<String>,
+    pub(crate) rootpage: i64,
 }
 
-#[cfg(feature = "sqlx-sqlite")]
-/// Gets the table name from a `SqliteRow` and maps it to the [TableDef]
-impl From<&SqliteRow> for TableDef {
-    fn from(row: &SqliteRow) -> Self {
-        let row: String = row.get(0);
+impl MasterTableInfo {
+    /// Whether the table is virtual, which is the only kind of table without a root page
+    pub(crate) fn is_virtual(&self) -> bool {
+        self.rootpage == 0
+    }
+
+    pub(crate) fn into_table_def(self) -> TableDef {
         TableDef {
-            name: row,
+            name: self.name,
+            ..Default::default()
+        }
+    }
+}
+
+impl TryFrom<&SchemaRow> for MasterTableInfo {
+    type Error = SchemaRowError;
+
+    fn try_from(row: &SchemaRow) -> Result<Self, Self::Error> {
+        Ok(Self {
+            name: row.try_get(0)?,
+            sql: row.try_get(1)?,
+            rootpage: row.try_get(2)?,
+        })
+    }
+}
+
+/// Select every column of a pragma through its table-valued function, e.g.
+/// `SELECT * FROM pragma_table_xinfo(?, ?) AS "pragma"`, with the argument and the database
+/// the table is in bound as parameters, so that any name is safe
+pub(crate) fn pragma_function(pragma: &str, arg: &str, schema: &str) -> SelectStatement {
+    let schema = if schema.is_empty() { "main" } else { schema };
+    Query::select()
+        .column(Asterisk)
+        .from_function(
+            Func::cust(Alias::new(format!("pragma_{pragma}")))
+                .args([Expr::val(arg).into(), Expr::val(schema).into()]),
+            Alias::new("pragma"),
+        )
+        .to_owned()
+}
+
+/// The options of a table as a result of using query `PRAGMA table_list`,
+/// which is available since SQLite 3.37.0
+#[allow(dead_code)]
+#[derive(Debug, Default, Clone)]
+pub(crate) struct TableListInfo {
+    pub(crate) schema: String,
+    pub(crate) name: String,
+    pub(crate) without_rowid: bool,
+    pub(crate) strict: bool,
+}
+
+impl TryFrom<&SchemaRow> for TableListInfo {
+    type Error = SchemaRowError;
+
+    fn try_from(row: &SchemaRow) -> Result<Self, Self::Error> {
+        let without_rowid: i8 = row.try_get(4)?;
+        let strict: i8 = row.try_get(5)?;
+        Ok(Self {
+            schema: row.try_get(0)?,
+            name: row.try_get(1)?,
+            without_rowid: without_rowid != 0,
+            strict: strict != 0,
+        })
+    }
+}
+
+/// Gets the table name from a `SchemaRow` and maps it to the [TableDef]
+impl TryFrom<&SchemaRow> for TableDef {
+    type Error = SchemaRowError;
+
+    fn try_from(row: &SchemaRow) -> Result<Self, Self::Error> {
+        Ok(TableDef {
+            name: row.try_get(0)?,
+            schema: String::default(),
             foreign_keys: Vec::default(),
             indexes: Vec::default(),
             constraints: Vec::default(),
             columns: Vec::default(),
+            checks: Vec::default(),
             auto_increment: bool::default(),
-        }
+            without_rowid: bool::default(),
+            strict: bool::default(),
+        })
     }
 }
 
-#[cfg(not(feature = "sqlx-sqlite"))]
-/// Gets the table name from a `SqliteRow` and maps it to the [TableDef]
-impl From<&SqliteRow> for TableDef {
-    fn from(_: &SqliteRow) -> Self {
-        Self::default()
+impl TableDef {
+    /// The name of the database the table is in
+    pub fn schema_name(&self) -> &str {
+        if self.schema.is_empty() {
+            "main"
+        } else {
+            &self.schema
+        }
+    }
+
+    /// The `sqlite_master` of the database the table is in
+    fn sqlite_master(&self) -> TableRef {
+        if self.schema.is_empty() {
+            SqliteMaster.into_table_ref()
+        } else {
+            (Alias::new(&self.schema), SqliteMaster).into_table_ref()
+        }
+    }
+
+    /// A pragma qualified with the database the table is in, e.g. `PRAGMA "aux".table_list`
+    fn pragma(&self, pragma: &str) -> String {
+        let mut query = String::default();
+        query.push_str("PRAGMA ");
+        if !self.schema.is_empty() {
+            query.push('"');
+            query.push_str(&self.schema.replace('"', "\"\""));
+            query.push_str("\".");
+        }
+        query.push_str(pragma);
+        query
+    }
+
+    /// Select every column of a pragma of the database the table is in
+    fn pragma_function(&self, pragma: &str, arg: &str) -> SelectStatement {
+        pragma_function(pragma, arg, &self.schema)
     }
-}
 
-impl TableDef {
     /// Check if the primary key in the table is set to autoincrement as a result of using query
     /// `SELECT COUNT(*) from sqlite_sequence where name = 'table_name';
-    pub async fn pk_is_autoincrement(&mut self, executor: &Executor) -> DiscoveryResult<&mut Self> {
+    pub async fn pk_is_autoincrement(
+        &mut self,
+        executor: &Executor<'_>,
+    ) -> DiscoveryResult<&mut Self> {
         let check_autoincrement = Query::select()
             .expr(Expr::val(1))
-            .from(SqliteMaster)
+            .from(self.sqlite_master())
             .and_where(Expr::col(Alias::new("type")).eq("table"))
             .and_where(Expr::col(Alias::new("name")).eq(self.name.as_str()))
             .and_where(Expr::col(Alias::new("sql")).like("%AUTOINCREMENT%"))
@@ -75,37 +213,12 @@ impl TableDef {
     /// These are implemented by indexes in most cases. These indexes have type "u" or "pk".
     /// Note that this does not get the column name mapped by the index.
     /// To get the column name mapped by the index, the `self.get_single_indexinfo` method is invoked
-    pub async fn get_constraints(&mut self, executor: &Executor) -> DiscoveryResult<()> {
-        let mut index_query = String::default();
-        index_query.push_str("PRAGMA index_list('");
-        index_query.push_str(&self.name);
-        index_query.push_str("')");
-
-        let partial_index_info_rows = executor.fetch_all_raw(index_query).await?;
-        let mut partial_indexes: Vec<PartialIndexInfo> = Vec::default();
-
-        partial_index_info_rows.iter().for_each(|info| {
-            let partial_index_info: PartialIndexInfo = info.into();
-
-            if partial_index_info.origin.as_str() == "u" {
-                partial_indexes.push(partial_index_info);
+    pub async fn get_constraints(&mut self, executor: &Executor<'_>) -> DiscoveryResult<()> {
+        for partial_index in self.get_index_list(executor).await? {
+            if partial_index.origin.as_str() == "u" {
+                let index = self.get_index_info(executor, partial_index).await?;
+                self.constraints.push(index);
             }
-        });
-
-        for partial_index in partial_indexes {
-            let partial_index_column: IndexedColumns = self
-                .get_single_indexinfo(executor, &partial_index.name)
-                .await?;
-
-            self.constraints.push(IndexInfo {
-                r#type: partial_index_column.r#type,
-                index_name: partial_index_column.name,
-                table_name: partial_index_column.table,
-                unique: partial_index.unique,
-                origin: partial_index.origin,
-                partial: partial_index.partial,
-                columns: partial_index_column.indexed_columns,
-            });
         }
 
         Ok(())
@@ -114,54 +227,89 @@ impl TableDef {
     /// Get a list of all the indexes in the table.
     /// Note that this does not get the column name mapped by the index.
     /// To get the column name mapped by the index, the `self.get_single_indexinfo` method is invoked
-    pub async fn get_indexes(&mut self, executor: &Executor) -> DiscoveryResult<()> {
-        let mut index_query = String::default();
-        index_query.push_str("PRAGMA index_list('");
-        index_query.push_str(&self.name);
-        index_query.push_str("')");
-
-        let partial_index_info_rows = executor.fetch_all_raw(index_query).await?;
-        let mut partial_indexes: Vec<PartialIndexInfo> = Vec::default();
+    pub async fn get_indexes(&mut self, executor: &Executor<'_>) -> DiscoveryResult<()> {
+        for partial_index in self.get_index_list(executor).await? {
+            if partial_index.origin.as_str() == "c" {
+                let index = self.get_index_info(executor, partial_index).await?;
+                self.indexes.push(index);
+            }
+        }
 
-        partial_index_info_rows.iter().for_each(|info| {
-            let partial_index_info: PartialIndexInfo = info.into();
+        Ok(())
+    }
 
-            if partial_index_info.origin.as_str() == "c" {
-                partial_indexes.push(partial_index_info);
+    /// Get both the constraints and the indexes of the table with a single `PRAGMA index_list`,
+    /// as done by [Self::get_constraints] and [Self::get_indexes]
+    pub async fn get_constraints_and_indexes(
+        &mut self,
+        executor: &Executor<'_>,
+    ) -> DiscoveryResult<()> {
+        for partial_index in self.get_index_list(executor).await? {
+            match partial_index.origin.as_str() {
+                "u" => {
+                    let index = self.get_index_info(executor, partial_index).await?;
+                    self.constraints.push(index);
+                }
+                "c" => {
+                    let index = self.get_index_info(executor, partial_index).await?;
+                    self.indexes.push(index);
+                }
+                _ => (),
             }
-        });
-
-        for partial_index in partial_indexes {
-            let partial_index_column: IndexedColumns = self
-                .get_single_indexinfo(executor, &partial_index.name)
-                .await?;
-
-            self.indexes.push(IndexInfo {
-                r#type: partial_index_column.r#type,
-                index_name: partial_index_column.name,
-                table_name: partial_index_column.table,
-                unique: partial_index.unique,
-                origin: partial_index.origin,
-                partial: partial_index.partial,
-                columns: partial_index_column.indexed_columns,
-            });
         }
 
         Ok(())
     }
 
+    async fn get_index_list(
+        &self,
+        executor: &Executor<'_>,
+    ) -> DiscoveryResult<Vec<PartialIndexInfo>> {
+        let index_query = self.pragma_function("index_list", &self.name);
+
+        let partial_index_info_rows = executor.fetch_all(index_query).await?;
+
+        Ok(partial_index_info_rows
+            .iter()
+            .map(PartialIndexInfo::try_from)
+            .collect::<Result<_, _>>()?)
+    }
+
+    async fn get_index_info(
+        &self,
+        executor: &Executor<'_>,
+        partial_index: PartialIndexInfo,
+    ) -> DiscoveryResult<IndexInfo> {
+        let partial_index_column: IndexedColumns = self
+            .get_single_indexinfo(executor, &partial_index.name)
+            .await?;
+        let columns = partial_index_column.column_names();
+
+        Ok(IndexInfo {
+            r#type: partial_index_column.r#type,
+            index_name: partial_index_column.name,
+            table_name: partial_index_column.table,
+            unique: partial_index.unique,
+            origin: partial_index.origin,
+            partial: partial_index.partial,
+            columns,
+            column_info: partial_index_column.indexed_columns,
+            where_clause: partial_index_column.where_clause,
+        })
+    }
+
     /// Get a list of all the foreign keys in the table
-    pub async fn get_foreign_keys(&mut self, executor: &Executor) -> DiscoveryResult<&mut Self> {
-        let mut index_query = String::default();
-        index_query.push_str("PRAGMA foreign_key_list('");
-        index_query.push_str(&self.name);
-        index_query.push_str("')");
+    pub async fn get_foreign_keys(
+        &mut self,
+        executor: &Executor<'_>,
+    ) -> DiscoveryResult<&mut Self> {
+        let index_query = self.pragma_function("foreign_key_list", &self.name);
 
-        let index_info_rows = executor.fetch_all_raw(index_query).await?;
+        let index_info_rows = executor.fetch_all(index_query).await?;
 
         let mut last_fk_id = None;
-        index_info_rows.iter().for_each(|info| {
-            let mut index_info: ForeignKeysInfo = info.into();
+        for info in index_info_rows.iter() {
+            let mut index_info = ForeignKeysInfo::try_from(info)?;
             let fk_id = index_info.id;
             if last_fk_id == Some(fk_id) {
                 let last_fk = self.foreign_keys.last_mut().unwrap();
@@ -171,19 +319,16 @@ impl TableDef {
                 self.foreign_keys.push(index_info);
             }
             last_fk_id = Some(fk_id);
-        });
+        }
 
         Ok(self)
     }
 
     /// Get a list of all the columns in the table mapped as [ColumnInfo]
-    pub async fn get_column_info(&mut self, executor: &Executor) -> DiscoveryResult<&TableDef> {
-        let mut index_query = String::default();
-        index_query.push_str("PRAGMA table_info('");
-        index_query.push_str(&self.name);
-        index_query.push_str("')");
+    pub async fn get_column_info(&mut self, executor: &Executor<'_>) -> DiscoveryResult<&TableDef> {
+        let index_query = self.pragma_function("table_xinfo", &self.name);
 
-        let index_info_rows = executor.fetch_all_raw(index_query).await?;
+        let index_info_rows = executor.fetch_all(index_query).await?;
 
         for info in index_info_rows {
             let column = ColumnInfo::to_column_def(&info)?;
@@ -193,36 +338,215 @@ impl TableDef {
         Ok(self)
     }
 
+    #[cfg(feature = "parser")]
+    /// Recover the CHECK constraints, collations, generated column expressions, foreign key names,
+    /// MATCH and deferrable clauses and table options
+    /// by parsing the `CREATE TABLE` statement stored in `sqlite_master`, as no pragma reports most
+    /// of them. The columns and foreign keys have to be discovered first
+    pub async fn get_create_table_info(
+        &mut self,
+        executor: &Executor<'_>,
+    ) -> DiscoveryResult<&mut Self> {
+        self.get_create_table_info_lenient(executor, &Warnings::default())
+            .await
+    }
+
+    #[cfg(feature = "parser")]
+    /// Same as [TableDef::get_create_table_info], warning about a `CREATE TABLE` statement
+    /// that can't be parsed if lenient
+    pub(crate) async fn get_create_table_info_lenient(
+        &mut self,
+        executor: &Executor<'_>,
+        warnings: &Warnings,
+    ) -> DiscoveryResult<&mut Self> {
+        let sql_query = Query::select()
+            .column(Alias::new("sql"))
+            .from(self.sqlite_master())
+            .and_where(Expr::col(Alias::new("type")).eq("table"))
+            .and_where(Expr::col(Alias::new("name")).eq(self.name.as_str()))
+            .to_owned();
+
+        let sql = MasterSql::try_from(&executor.fetch_one(sql_query).await?)?;
+        let sql = sql.0.unwrap_or_default();
+
+        let info = match parse_create_table(&sql) {
+            Some(info) => info,
+            None => {
+                warnings.warn(
+                    DiscoveryWarning::new("Unparsed CREATE TABLE statement")
+                        .table(&self.name)
+                        .value(&sql),
+                );
+                Default::default()
+            }
+        };
+        for parsed in info.columns {
+            if let Some(column) = self.columns.iter_mut().find(|c| c.name == parsed.name) {
+                column.collation = parsed.collation;
+                column.generated = parsed.generated;
+            }
+        }
+        for parsed in info.foreign_keys {
+            let foreign_key = self
+                .foreign_keys
+                .iter_mut()
+                .find(|fk| fk.from == parsed.from && fk.table.eq_ignore_ascii_case(&parsed.table));
+            if let Some(foreign_key) = foreign_key {
+                foreign_key.name = parsed.name;
+                foreign_key.r#match = parsed.r#match;
+                foreign_key.deferrable = parsed.deferrable;
+            }
+        }
+        self.checks = info.checks;
+        self.without_rowid = info.without_rowid;
+        self.strict = info.strict;
+
+        Ok(self)
+    }
+
+    /// Get the `WITHOUT ROWID` and `STRICT` options from `PRAGMA table_list`.
+    /// Older SQLite versions ignore the unknown pragma, then the options parsed by
+    /// `get_create_table_info` are kept. The pragma is not queried through its table-valued
+    /// function, which would fail on these versions, nor with the table name as argument,
+    /// which can't be bound as a parameter
+    pub async fn get_table_options(
+        &mut self,
+        executor: &Executor<'_>,
+    ) -> DiscoveryResult<&mut Self> {
+        let table_list_rows = executor.fetch_all_raw(self.pragma("table_list")).await?;
+
+        for row in table_list_rows.iter() {
+            let info = TableListInfo::try_from(row)?;
+            if info.schema == self.schema_name() && info.name == self.name {
+                self.without_rowid = info.without_rowid;
+                self.strict = info.strict;
+                break;
+            }
+        }
+
+        Ok(self)
+    }
+
     /// Gets the columns that are mapped to an index
     pub(crate) async fn get_single_indexinfo(
-        &mut self,
-        executor: &Executor,
+        &self,
+        executor: &Executor<'_>,
         index_name: &str,
     ) -> DiscoveryResult<IndexedColumns> {
         let index_query = Query::select()
             .expr(Expr::cust("*"))
-            .from(SqliteMaster)
+            .from(self.sqlite_master())
             .and_where(Expr::col(Alias::new("name")).eq(index_name))
             .to_owned();
 
         let index_info = executor.fetch_one(index_query).await?;
 
-        let mut index_column_query = String::default();
-        index_column_query.push_str("PRAGMA index_info('");
-        index_column_query.push_str(index_name);
-        index_column_query.push_str("')");
+        let index_column_query = self.pragma_function("index_xinfo", index_name);
+
+        let index_column_info_rows = executor.fetch_all(index_column_query).await?;
 
-        let index_column_info_rows = executor.fetch_all_raw(index_column_query).await?;
+        let mut indexed_columns =
+            IndexedColumns::try_from((&index_info, index_column_info_rows.as_slice()))?;
+
+        // The expressions, the WHERE clause and the collations that were written
+        // are only known from the CREATE INDEX statement
+        #[cfg(feature = "parser")]
+        if let Some(info) = indexed_columns.sql.as_deref().and_then(parse_create_index) {
+            if info.columns.len() == indexed_columns.indexed_columns.len() {
+                indexed_columns.indexed_columns = info.columns;
+            }
+            indexed_columns.where_clause = info.where_clause;
+        }
+
+        // Otherwise the index of a constraint reports the collation of the column,
+        // which doesn't have to be repeated
+        if indexed_columns.sql.is_none() {
+            for indexed_column in indexed_columns.indexed_columns.iter_mut() {
+                let column = self
+                    .columns
+                    .iter()
+                    .find(|column| Some(&column.name) == indexed_column.name.as_ref());
+                if let (Some(column), Some(collation)) = (column, &indexed_column.collation) {
+                    if matches!(&column.collation, Some(c) if c.eq_ignore_ascii_case(collation)) {
+                        indexed_column.collation = None;
+                    }
+                }
+            }
+        }
 
-        Ok((&index_info, index_column_info_rows.as_slice()).into())
+        Ok(indexed_columns)
     }
 
+    /// Write the table into a [TableCreateStatement]. The name, MATCH and deferrable clauses of
+    /// a foreign key spanning several columns can't be written in it, so the key is written
+    /// without them: [TableDef::write_sql] keeps them
     pub fn write(&self) -> TableCreateStatement {
+        self.write_statement(false)
+    }
+
+    /// Write the table into a `CREATE TABLE` statement, along with the table constraints of
+    /// [TableDef::write_table_constraints]
+    pub fn write_sql(&self) -> String {
+        let constraints = self.write_table_constraints();
+        if constraints.is_empty() {
+            return self.write().to_string(SqliteQueryBuilder);
+        }
+        let sql = self.write_statement(true).to_string(SqliteQueryBuilder);
+        // Without the options, the statement ends with the closing parenthesis
+        let definitions = sql.strip_suffix(" )").unwrap_or(&sql);
+        let mut sql = format!("{}, {} )", definitions, constraints.join(", "));
+        if let Some(options) = self.write_options() {
+            sql.push(' ');
+            sql.push_str(&options);
+        }
+        sql
+    }
+
+    /// Write the table constraints [TableDef::write] can't: the foreign keys spanning several
+    /// columns that have a name, MATCH or deferrable clause
+    pub fn write_table_constraints(&self) -> Vec<String> {
+        self.foreign_keys
+            .iter()
+            .filter(|foreign_key| foreign_key.from.len() != 1 && foreign_key.has_clauses())
+            .map(|foreign_key| foreign_key.write_table_constraint())
+            .collect()
+    }
+
+    /// The `WITHOUT ROWID` and `STRICT` options, if any
+    fn write_options(&self) -> Option<String> {
+        let mut options = Vec::new();
+        if self.without_rowid {
+            options.push("WITHOUT ROWID");
+        }
+        if self.strict {
+            options.push("STRICT");
+        }
+        if options.is_empty() {
+            None
+        } else {
+            Some(options.join(", "))
+        }
+    }
+
+    /// Write the table, leaving out the table constraints and the options if `without_constraints`
+    fn write_statement(&self, without_constraints: bool) -> TableCreateStatement {
         let mut primary_keys = Vec::new();
 
         let mut new_table = Table::create();
         new_table.table(Alias::new(&self.name));
 
+        // Foreign keys with a name, MATCH or deferrable clause are written on their column,
+        // those spanning several columns being table constraints
+        let mut column_references: HashMap<_, Vec<_>> = HashMap::new();
+        for foreign_key in self.foreign_keys.iter() {
+            if let Some(references) = foreign_key.write_column_constraint() {
+                column_references
+                    .entry(foreign_key.from[0].clone())
+                    .or_default()
+                    .push(references);
+            }
+        }
+
         self.columns.iter().for_each(|column_info| {
             let mut new_column =
                 ColumnDef::new_with_type(Alias::new(&column_info.name), column_info.r#type.clone());
@@ -238,25 +562,56 @@ impl TableDef {
 
             match &column_info.default_value {
                 DefaultType::Integer(integer_value) => {
-                    new_column.default(Value::Int(Some(*integer_value)));
+                    new_column.default(Value::BigInt(Some(*integer_value)));
                 }
                 DefaultType::Float(float_value) => {
-                    new_column.default(Value::Float(Some(*float_value)));
+                    new_column.default(Value::Double(Some(*float_value)));
                 }
                 DefaultType::String(string_value) => {
                     new_column.default(Value::String(Some(Box::new(string_value.to_string()))));
                 }
+                DefaultType::Blob(bytes) => {
+                    new_column.default(Value::Bytes(Some(Box::new(bytes.clone()))));
+                }
                 DefaultType::Null => (),
                 DefaultType::Unspecified => (),
                 DefaultType::CurrentTimestamp => {
                     new_column.default(Keyword::CurrentTimestamp);
                 }
+                DefaultType::CurrentDate => {
+                    new_column.default(Keyword::CurrentDate);
+                }
+                DefaultType::CurrentTime => {
+                    new_column.default(Keyword::CurrentTime);
+                }
+                DefaultType::Expr(expr) => {
+                    new_column.default(Expr::cust(format!("({expr})")));
+                }
+            }
+
+            if let Some(collation) = &column_info.collation {
+                new_column.extra(format!("COLLATE {}", collation));
+            }
+
+            if let Some(generated) = &column_info.generated {
+                new_column.generated(Expr::cust(&generated.expr), generated.stored);
+            }
+
+            for references in column_references
+                .get(&column_info.name)
+                .into_iter()
+                .flatten()
+            {
+                new_column.extra(references);
             }
 
             new_table.col(&mut new_column);
         });
 
         self.foreign_keys.iter().for_each(|foreign_key| {
+            if foreign_key.has_clauses() && (foreign_key.from.len() == 1 || without_constraints) {
+                return;
+            }
             let mut fk = ForeignKey::create();
             for from in foreign_key.from.iter() {
                 fk.from(Alias::new(&self.name), Alias::new(from));
@@ -273,6 +628,11 @@ impl TableDef {
             new_table.index(&mut index.write());
         });
 
+        // sea-query has no way to name a CHECK constraint, so the names are not written
+        self.checks.iter().for_each(|check| {
+            new_table.check(Expr::cust(&check.expr));
+        });
+
         if !primary_keys.is_empty() {
             let mut primary_key_stmt = Index::create();
             for primary_key in primary_keys.iter() {
@@ -281,6 +641,132 @@ impl TableDef {
             new_table.primary_key(&mut primary_key_stmt);
         }
 
+        if let Some(options) = self.write_options().filter(|_| !without_constraints) {
+            new_table.extra(options);
+        }
+
         new_table
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sqlite::def::{Affinity, Deferrable, MatchAction};
+    use sea_query::{ColumnType, SqliteQueryBuilder};
+
+    fn column(cid: i32, name: &str) -> ColumnInfo {
+        ColumnInfo {
+            cid,
+            name: name.to_owned(),
+            r#type: ColumnType::Integer,
+            declared_type: "integer".to_owned(),
+            affinity: Affinity::Integer,
+            not_null: false,
+            default_value: DefaultType::Unspecified,
+            primary_key: false,
+            hidden: false,
+            collation: None,
+            generated: None,
+        }
+    }
+
+    fn foreign_key(id: i32, from: &[&str], table: &str, to: &[&str]) -> ForeignKeysInfo {
+        ForeignKeysInfo {
+            id,
+            table: table.to_owned(),
+            from: from.iter().map(|s| s.to_string()).collect(),
+            to: to.iter().map(|s| s.to_string()).collect(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_pragma_function() {
+        let select = pragma_function("table_xinfo", "cake", "")
+            .and_where(Expr::col(Alias::new("hidden")).eq(0))
+            .to_owned();
+        assert_eq!(
+            select.to_string(SqliteQueryBuilder),
+            r#"SELECT * FROM pragma_table_xinfo('cake', 'main') AS "pragma" WHERE "hidden" = 0"#
+        );
+    }
+
+    #[test]
+    fn test_write_foreign_keys() {
+        let table = TableDef {
+            name: "shipment".to_owned(),
+            columns: vec![
+                column(0, "warehouse_id"),
+                column(1, "region_id"),
+                column(2, "origin_id"),
+            ],
+            foreign_keys: vec![
+                // Two single-column foreign keys on the same column
+                ForeignKeysInfo {
+                    name: Some("fk-warehouse".to_owned()),
+                    ..foreign_key(0, &["warehouse_id"], "warehouse", &["id"])
+                },
+                ForeignKeysInfo {
+                    deferrable: Some(Deferrable::InitiallyDeferred),
+                    ..foreign_key(1, &["warehouse_id"], "depot", &["id"])
+                },
+                // A foreign key spanning several columns
+                ForeignKeysInfo {
+                    name: Some("fk-region".to_owned()),
+                    r#match: MatchAction::Full,
+                    deferrable: Some(Deferrable::InitiallyImmediate),
+                    ..foreign_key(2, &["warehouse_id", "region_id"], "region", &["wid", "id"])
+                },
+                // A foreign key without any clause sea-query can't write
+                foreign_key(3, &["origin_id"], "warehouse", &["id"]),
+            ],
+            ..Default::default()
+        };
+
+        let columns = [
+            r#"CREATE TABLE "shipment" ("#,
+            r#""warehouse_id" integer"#,
+            r#"CONSTRAINT "fk-warehouse" REFERENCES "warehouse" ("id") ON DELETE NO ACTION ON UPDATE NO ACTION"#,
+            r#"REFERENCES "depot" ("id") ON DELETE NO ACTION ON UPDATE NO ACTION DEFERRABLE INITIALLY DEFERRED,"#,
+            r#""region_id" integer,"#,
+            r#""origin_id" integer,"#,
+        ];
+        let region = r#"CONSTRAINT "fk-region" FOREIGN KEY ("warehouse_id", "region_id") REFERENCES "region" ("wid", "id") ON DELETE NO ACTION ON UPDATE NO ACTION MATCH FULL DEFERRABLE INITIALLY IMMEDIATE"#;
+        let origin = r#"FOREIGN KEY ("origin_id") REFERENCES "warehouse" ("id") ON DELETE NO ACTION ON UPDATE NO ACTION"#;
+
+        // The statement only has the columns the table has
+        let statement = table.write();
+        assert_eq!(statement.get_columns().len(), 3);
+        assert_eq!(
+            statement.to_string(SqliteQueryBuilder),
+            [
+                &columns[..],
+                &[
+                    r#"FOREIGN KEY ("warehouse_id", "region_id") REFERENCES "region" ("wid", "id") ON DELETE NO ACTION ON UPDATE NO ACTION,"#,
+                    origin,
+                    r#")"#,
+                ],
+            ]
+            .concat()
+            .join(" ")
+        );
+
+        assert_eq!(table.write_table_constraints(), [region]);
+        assert_eq!(
+            table.write_sql(),
+            [
+                &columns[..],
+                &[&format!("{},", origin), &format!("{} )", region)].map(String::as_str),
+            ]
+            .concat()
+            .join(" ")
+        );
+
+        let table = TableDef {
+            without_rowid: true,
+            ..table
+        };
+        assert!(table.write_sql().ends_with(" IMMEDIATE ) WITHOUT ROWID"));
+    }
+}
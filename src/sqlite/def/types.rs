@@ -1,11 +1,16 @@
 use sea_query::{ColumnType, StringLen};
 use std::num::ParseIntError;
 
+/// Map a declared type to a [ColumnType]. Anything after the arguments, e.g. a `COLLATE` clause,
+/// is ignored. Other types, e.g. `UNSIGNED BIG INT` or `NUMERIC(10)`, are kept as they are
+/// declared in a [ColumnType::Custom] so they are written back unchanged; see [Affinity] for
+/// how SQLite treats them
 pub fn parse_type(data_type: &str) -> Result<ColumnType, ParseIntError> {
+    let data_type = data_type.trim();
     let mut type_name = data_type;
     let mut parts: Vec<u32> = Vec::new();
     if let Some((prefix, suffix)) = data_type.split_once('(') {
-        if let Some(suffix) = suffix.strip_suffix(')') {
+        if let Some((suffix, _)) = suffix.split_once(')') {
             type_name = prefix;
             for part in suffix.split(',') {
                 if let Ok(part) = part.trim().parse() {
@@ -16,7 +21,9 @@ pub fn parse_type(data_type: &str) -> Result<ColumnType, ParseIntError> {
             }
         }
     }
-    Ok(match type_name.to_lowercase().as_str() {
+    let type_name = type_name.trim().to_lowercase();
+
+    Ok(match type_name.as_str() {
         "char" => ColumnType::Char(parts.into_iter().next()),
         "varchar" => ColumnType::String(match parts.into_iter().next() {
             Some(length) => StringLen::N(length),
@@ -29,11 +36,14 @@ pub fn parse_type(data_type: &str) -> Result<ColumnType, ParseIntError> {
         "bigint" => ColumnType::BigInteger,
         "float" => ColumnType::Float,
         "double" => ColumnType::Double,
-        "decimal" | "real" => ColumnType::Decimal(if parts.len() == 2 {
-            Some((parts[0], parts[1]))
-        } else {
-            None
-        }),
+        // sea-query can't write a precision over 16
+        "decimal" | "real" if parts.first().map_or(true, |precision| *precision <= 16) => {
+            ColumnType::Decimal(match parts.len() {
+                1 => Some((parts[0], 0)),
+                2 => Some((parts[0], parts[1])),
+                _ => None,
+            })
+        }
         "datetime_text" => ColumnType::DateTime,
         "timestamp" | "timestamp_text" => ColumnType::Timestamp,
         "timestamp_with_timezone_text" => ColumnType::TimestampWithTimeZone,
@@ -65,13 +75,206 @@ pub fn parse_type(data_type: &str) -> Result<ColumnType, ParseIntError> {
     })
 }
 
+/// The type affinity of a column, derived from its declared type by the
+/// [rules](https://www.sqlite.org/datatype3.html#determination_of_column_affinity) of SQLite
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Affinity {
+    Integer,
+    Text,
+    Blob,
+    Real,
+    Numeric,
+}
+
+impl Affinity {
+    /// Determine the affinity of a declared type, e.g. `INTEGER` for `UNSIGNED BIG INT`
+    pub fn from_declared_type(declared_type: &str) -> Self {
+        let declared_type = declared_type.to_uppercase();
+        if declared_type.contains("INT") {
+            Self::Integer
+        } else if ["CHAR", "CLOB", "TEXT"]
+            .iter()
+            .any(|name| declared_type.contains(name))
+        {
+            Self::Text
+        } else if declared_type.contains("BLOB") || declared_type.trim().is_empty() {
+            Self::Blob
+        } else if ["REAL", "FLOA", "DOUB"]
+            .iter()
+            .any(|name| declared_type.contains(name))
+        {
+            Self::Real
+        } else {
+            Self::Numeric
+        }
+    }
+}
+
 /// The default types for an SQLite `dflt_value`
 #[derive(Debug, PartialEq, Clone)]
 pub enum DefaultType {
-    Integer(i32),
-    Float(f32),
+    Integer(i64),
+    Float(f64),
+    /// A string literal, unescaped
     String(String),
+    /// A blob literal `X'..'`
+    Blob(Vec<u8>),
     Null,
     Unspecified,
     CurrentTimestamp,
+    CurrentDate,
+    CurrentTime,
+    /// Any other default as written, e.g. the expression `datetime('now')` of
+    /// `DEFAULT (datetime('now'))`, from which SQLite strips the parentheses
+    Expr(String),
+}
+
+/// Map the `dflt_value` of `PRAGMA table_xinfo`, the default as written in the
+/// `CREATE TABLE` statement, to a [DefaultType]
+pub fn parse_default(default_value: Option<&str>) -> DefaultType {
+    let value = match default_value.map(str::trim) {
+        Some(value) if !value.is_empty() => value,
+        _ => return DefaultType::Unspecified,
+    };
+
+    match value.to_uppercase().as_str() {
+        "NULL" => return DefaultType::Null,
+        "CURRENT_TIMESTAMP" => return DefaultType::CurrentTimestamp,
+        "CURRENT_DATE" => return DefaultType::CurrentDate,
+        "CURRENT_TIME" => return DefaultType::CurrentTime,
+        _ => {}
+    }
+
+    if let Some(string) = strip_quotes(value, "'") {
+        return DefaultType::String(string.replace("''", "'"));
+    }
+    if let Some(hex) = strip_quotes(value, "X'").or_else(|| strip_quotes(value, "x'")) {
+        if let Some(bytes) = parse_hex(hex) {
+            return DefaultType::Blob(bytes);
+        }
+    }
+    // Rust would also parse `inf` or `NaN`, which SQLite reads as identifiers
+    if value.starts_with(|c: char| c.is_ascii_digit() || matches!(c, '-' | '+' | '.')) {
+        if let Ok(integer) = value.parse() {
+            return DefaultType::Integer(integer);
+        }
+        if let Ok(float) = value.parse() {
+            return DefaultType::Float(float);
+        }
+    }
+
+    DefaultType::Expr(value.to_owned())
+}
+
+/// The text between `start` and a closing single quote
+fn strip_quotes<'a>(value: &'a str, start: &str) -> Option<&'a str> {
+    value
+        .strip_prefix(start)
+        .and_then(|value| value.strip_suffix('\''))
+}
+
+fn parse_hex(hex: &str) -> Option<Vec<u8>> {
+    if hex.len() % 2 != 0 {
+        return None;
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_type() {
+        assert_eq!(
+            parse_type("VARCHAR(255) COLLATE NOCASE").unwrap(),
+            ColumnType::String(StringLen::N(255))
+        );
+        assert_eq!(
+            parse_type("Char ( 16 )").unwrap(),
+            ColumnType::Char(Some(16))
+        );
+        assert_eq!(
+            parse_type("UNSIGNED BIG INT").unwrap(),
+            ColumnType::custom("UNSIGNED BIG INT")
+        );
+        assert_eq!(
+            parse_type("DECIMAL(10)").unwrap(),
+            ColumnType::Decimal(Some((10, 0)))
+        );
+        assert_eq!(
+            parse_type("DECIMAL(20, 2)").unwrap(),
+            ColumnType::custom("DECIMAL(20, 2)")
+        );
+        assert_eq!(
+            parse_type("NUMERIC(10)").unwrap(),
+            ColumnType::custom("NUMERIC(10)")
+        );
+    }
+
+    #[test]
+    fn test_parse_default() {
+        for (default_value, default_type) in [
+            (None, DefaultType::Unspecified),
+            (Some("NULL"), DefaultType::Null),
+            (
+                Some("9007199254740993"),
+                DefaultType::Integer(9007199254740993),
+            ),
+            (Some("-42"), DefaultType::Integer(-42)),
+            (
+                Some("0.30000000000000004"),
+                DefaultType::Float(0.30000000000000004),
+            ),
+            (Some("1e-3"), DefaultType::Float(0.001)),
+            (Some("'it''s'"), DefaultType::String("it's".to_owned())),
+            (Some("'12'"), DefaultType::String("12".to_owned())),
+            (Some("''"), DefaultType::String("".to_owned())),
+            (Some("X'00fF'"), DefaultType::Blob(vec![0x00, 0xff])),
+            (Some("current_date"), DefaultType::CurrentDate),
+            (Some("CURRENT_TIME"), DefaultType::CurrentTime),
+            (Some("CURRENT_TIMESTAMP"), DefaultType::CurrentTimestamp),
+            (
+                Some("datetime('now')"),
+                DefaultType::Expr("datetime('now')".to_owned()),
+            ),
+            (Some("TRUE"), DefaultType::Expr("TRUE".to_owned())),
+            (Some("0x1F"), DefaultType::Expr("0x1F".to_owned())),
+        ] {
+            assert_eq!(
+                parse_default(default_value),
+                default_type,
+                "{default_value:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_affinity() {
+        for (declared_type, affinity) in [
+            ("INT", Affinity::Integer),
+            ("UNSIGNED BIG INT", Affinity::Integer),
+            ("VARCHAR(255)", Affinity::Text),
+            ("NATIVE CHARACTER(70)", Affinity::Text),
+            ("CLOB", Affinity::Text),
+            ("BLOB", Affinity::Blob),
+            ("", Affinity::Blob),
+            ("DOUBLE PRECISION", Affinity::Real),
+            ("FLOAT", Affinity::Real),
+            ("NUMERIC(10)", Affinity::Numeric),
+            ("DECIMAL(10,5)", Affinity::Numeric),
+            ("DATETIME", Affinity::Numeric),
+            // "POINT" contains "INT"
+            ("FLOATING POINT", Affinity::Integer),
+        ] {
+            assert_eq!(
+                Affinity::from_declared_type(declared_type),
+                affinity,
+                "{declared_type}"
+            );
+        }
+    }
 }
@@ -0,0 +1,99 @@
+use sea_query::{QuotedBuilder, SqliteQueryBuilder};
+
+use super::{pragma_function, ColumnInfo};
+use crate::sqlite::{error::DiscoveryResult, executor::Executor};
+use crate::util::quote_iden;
+
+/// Defines a virtual table for SQLite, implemented by a module. Unlike a [super::TableDef],
+/// it is written as a `CREATE VIRTUAL TABLE` statement
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct VirtualTableDef {
+    /// The table name
+    pub name: String,
+    /// The database the table is in, `main`, `temp` or the name of an attached database.
+    /// An empty name means the main database
+    pub schema: String,
+    /// The name of the module implementing the table, e.g. `fts5` or `rtree`
+    pub module: String,
+    /// The module arguments as written
+    pub args: Vec<String>,
+    /// The columns the module declares, the hidden ones included
+    pub columns: Vec<ColumnInfo>,
+}
+
+impl VirtualTableDef {
+    /// The suffixes of the shadow tables the module creates to store its data, named
+    /// `<table>_<suffix>`. Only the modules built into SQLite are known
+    pub fn shadow_table_suffixes(&self) -> &'static [&'static str] {
+        match self.module.to_lowercase().as_str() {
+            "fts3" | "fts4" => &["content", "segments", "segdir", "docsize", "stat"],
+            "fts5" => &["data", "idx", "content", "docsize", "config"],
+            "rtree" | "rtree_i32" | "geopoly" => &["node", "rowid", "parent"],
+            _ => &[],
+        }
+    }
+
+    /// Whether `table_name` is one of the shadow tables of the virtual table
+    pub fn is_shadow_table(&self, table_name: &str) -> bool {
+        match table_name
+            .strip_prefix(&self.name)
+            .and_then(|s| s.strip_prefix('_'))
+        {
+            Some(suffix) => self
+                .shadow_table_suffixes()
+                .iter()
+                .any(|shadow| shadow.eq_ignore_ascii_case(suffix)),
+            None => false,
+        }
+    }
+
+    /// Get a list of all the columns in the table mapped as [ColumnInfo]
+    pub async fn get_column_info(&mut self, executor: &Executor<'_>) -> DiscoveryResult<&Self> {
+        let index_query = pragma_function("table_xinfo", &self.name, &self.schema);
+
+        let index_info_rows = executor.fetch_all(index_query).await?;
+
+        for info in index_info_rows {
+            let column = ColumnInfo::to_column_def(&info)?;
+            self.columns.push(column);
+        }
+
+        Ok(self)
+    }
+
+    /// Write the table into a `CREATE VIRTUAL TABLE` statement
+    pub fn write(&self) -> String {
+        let mut sql = String::default();
+        sql.push_str("CREATE VIRTUAL TABLE ");
+        sql.push_str(&quote_iden(&self.name, SqliteQueryBuilder.quote()));
+        sql.push_str(" USING ");
+        sql.push_str(&self.module);
+        if !self.args.is_empty() {
+            sql.push('(');
+            sql.push_str(&self.args.join(", "));
+            sql.push(')');
+        }
+        sql
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write() {
+        let virtual_table = VirtualTableDef {
+            name: "docs".to_owned(),
+            module: "fts5".to_owned(),
+            args: vec!["title".to_owned(), "body UNINDEXED".to_owned()],
+            ..Default::default()
+        };
+        assert_eq!(
+            virtual_table.write(),
+            r#"CREATE VIRTUAL TABLE "docs" USING fts5(title, body UNINDEXED)"#
+        );
+        assert!(virtual_table.is_shadow_table("docs_data"));
+        assert!(!virtual_table.is_shadow_table("docs_meta"));
+    }
+}
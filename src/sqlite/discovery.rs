@@ -1,65 +1,227 @@
+use futures::future;
 use sea_query::{Alias, Expr, SelectStatement};
 
-use super::def::{IndexInfo, Schema, TableDef};
+use super::def::{DatabaseInfo, IndexInfo, MasterTableInfo, Schema, TableDef, VirtualTableDef};
 pub use super::error::DiscoveryResult;
-use super::executor::{Executor, IntoExecutor};
+use super::error::SqliteDiscoveryError;
+pub use super::executor::{Executor, ExecutorError, IntoExecutor, SchemaExecutor, SqlxError};
+use super::parser::parse_create_virtual_table;
 use super::query::SqliteMaster;
-use crate::sqlx_types::SqlitePool;
+use crate::warning::Warnings;
+use crate::{DiscoveryWarning, TableFilter};
 
 /// Performs all the methods for schema discovery of a SQLite database
-pub struct SchemaDiscovery {
-    pub executor: Executor,
+pub struct SchemaDiscovery<'c> {
+    pub executor: Executor<'c>,
+    /// The tables to discover
+    pub filter: TableFilter,
+    warnings: Warnings,
 }
 
-impl SchemaDiscovery {
-    /// Instantiate a new database connection to the database specified
-    pub fn new(sqlite_pool: SqlitePool) -> Self {
+impl<'c> SchemaDiscovery<'c> {
+    /// Discover the database of a `SqlitePool`, or of any other [SchemaExecutor]
+    pub fn new<E>(executor: E) -> Self
+    where
+        E: IntoExecutor<'c>,
+    {
         SchemaDiscovery {
-            executor: sqlite_pool.into_executor(),
+            executor: executor.into_executor(),
+            filter: TableFilter::default(),
+            warnings: Warnings::default(),
         }
     }
 
-    /// Discover all the tables in a SQLite database
+    /// Only discover the tables passing the filter
+    pub fn with_filter(mut self, filter: TableFilter) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    /// Discover all the tables in the main database of a SQLite connection
     pub async fn discover(&self) -> DiscoveryResult<Schema> {
-        let get_tables = SelectStatement::new()
-            .column(Alias::new("name"))
-            .from(SqliteMaster)
-            .and_where(Expr::col(Alias::new("type")).eq("table"))
-            .and_where(Expr::col(Alias::new("name")).ne("sqlite_sequence"))
-            .to_owned();
+        self.discover_schema("main").await
+    }
+
+    /// Discover all the tables in the main database, skipping the tables that can't be discovered
+    /// instead of failing. Every table skipped, and every `CREATE TABLE` statement that can't be
+    /// parsed, is reported as a warning. An error of the executor still fails the discovery
+    pub async fn discover_lenient(&self) -> DiscoveryResult<(Schema, Vec<DiscoveryWarning>)> {
+        let lenient = SchemaDiscovery {
+            executor: self.executor.borrow(),
+            filter: self.filter.clone(),
+            warnings: Warnings::lenient(),
+        };
+        let schema = lenient.discover().await?;
+        Ok((schema, lenient.warnings.take()))
+    }
+
+    /// Discover every database of the connection, as listed by `PRAGMA database_list`:
+    /// `main`, `temp` and the attached databases, one [Schema] per database.
+    /// Note that databases are attached per connection, so the pool should hold only one
+    pub async fn discover_all(&self) -> DiscoveryResult<Vec<Schema>> {
+        let database_list_rows = self
+            .executor
+            .fetch_all_raw("PRAGMA database_list".to_owned())
+            .await?;
+
+        let mut schemas = Vec::new();
+        for row in database_list_rows.iter() {
+            let database = DatabaseInfo::try_from(row).map_err(SqliteDiscoveryError::from)?;
+            schemas.push(self.discover_schema(&database.name).await?);
+        }
+
+        Ok(schemas)
+    }
+
+    /// Discover all the tables in a database of a SQLite connection,
+    /// `main`, `temp` or the name of an attached database.
+    /// The tables are discovered concurrently, together with their indexes
+    pub async fn discover_schema(&self, schema: &str) -> DiscoveryResult<Schema> {
+        let master_tables = self.discover_master_tables(schema).await?;
+        let (virtual_tables, tables): (Vec<_>, Vec<_>) = master_tables
+            .into_iter()
+            .partition(|master_table| master_table.is_virtual());
+
+        let virtual_tables: Vec<_> = future::try_join_all(
+            virtual_tables
+                .into_iter()
+                .map(|master_table| self.discover_virtual_table(master_table, schema)),
+        )
+        .await?
+        .into_iter()
+        .flatten()
+        .collect();
+
+        // The shadow tables virtual tables store their data in are implicit
+        let tables = tables.into_iter().filter(|master_table| {
+            !virtual_tables
+                .iter()
+                .any(|virtual_table| virtual_table.is_shadow_table(&master_table.name))
+        });
+        let mut tables: Vec<_> = future::try_join_all(
+            tables.map(|master_table| self.discover_table(master_table.into_table_def(), schema)),
+        )
+        .await?
+        .into_iter()
+        .flatten()
+        .collect();
 
-        let mut tables = Vec::new();
-        for row in self.executor.fetch_all(get_tables).await? {
-            let mut table: TableDef = (&row).into();
+        let mut indexes = Vec::new();
+        for table in tables.iter_mut() {
+            indexes.append(&mut table.indexes);
+        }
+
+        Ok(Schema {
+            name: schema.to_owned(),
+            tables,
+            virtual_tables,
+            indexes,
+        })
+    }
+
+    /// Discover a table, or skip it if lenient and it can't be discovered
+    async fn discover_table(
+        &self,
+        mut table: TableDef,
+        schema: &str,
+    ) -> DiscoveryResult<Option<TableDef>> {
+        let name = table.name.clone();
+        let result = async {
+            table.schema = schema.to_owned();
             table.pk_is_autoincrement(&self.executor).await?;
             table.get_foreign_keys(&self.executor).await?;
             table.get_column_info(&self.executor).await?;
-            table.get_constraints(&self.executor).await?;
-            tables.push(table);
+            table
+                .get_create_table_info_lenient(&self.executor, &self.warnings)
+                .await?;
+            table.get_table_options(&self.executor).await?;
+            table.get_constraints_and_indexes(&self.executor).await?;
+            Ok(table)
         }
+        .await;
+        self.warnings
+            .recover_with(result, |err: SqliteDiscoveryError| {
+                DiscoveryWarning::new(err.to_string()).table(name)
+            })
+    }
 
-        let indexes = self.discover_indexes().await?;
+    /// Discover a virtual table, or skip it if lenient and it can't be discovered
+    async fn discover_virtual_table(
+        &self,
+        master_table: MasterTableInfo,
+        schema: &str,
+    ) -> DiscoveryResult<Option<VirtualTableDef>> {
+        let name = master_table.name;
+        let sql = master_table.sql.unwrap_or_default();
+        let result = async {
+            let mut virtual_table = parse_create_virtual_table(&sql)
+                .ok_or_else(|| SqliteDiscoveryError::UnparsedVirtualTable(sql.clone()))?;
+            virtual_table.name.clone_from(&name);
+            virtual_table.schema = schema.to_owned();
+            virtual_table.get_column_info(&self.executor).await?;
+            Ok(virtual_table)
+        }
+        .await;
+        self.warnings
+            .recover_with(result, |err: SqliteDiscoveryError| {
+                DiscoveryWarning::new(err.to_string()).table(name)
+            })
+    }
 
-        Ok(Schema { tables, indexes })
+    /// List the tables of a database passing the filter
+    async fn discover_table_names(&self, schema: &str) -> DiscoveryResult<Vec<TableDef>> {
+        Ok(self
+            .discover_master_tables(schema)
+            .await?
+            .into_iter()
+            .filter(|master_table| !master_table.is_virtual())
+            .map(MasterTableInfo::into_table_def)
+            .collect())
     }
 
-    /// Discover table indexes
-    pub async fn discover_indexes(&self) -> DiscoveryResult<Vec<IndexInfo>> {
-        let get_tables = SelectStatement::new()
-            .column(Alias::new("name"))
-            .from(SqliteMaster)
+    /// List the tables of a database passing the filter, virtual or not
+    async fn discover_master_tables(&self, schema: &str) -> DiscoveryResult<Vec<MasterTableInfo>> {
+        let mut get_tables = SelectStatement::new()
+            .columns([
+                Alias::new("name"),
+                Alias::new("sql"),
+                Alias::new("rootpage"),
+            ])
+            .from((Alias::new(schema), SqliteMaster))
             .and_where(Expr::col(Alias::new("type")).eq("table"))
             .and_where(Expr::col(Alias::new("name")).ne("sqlite_sequence"))
             .to_owned();
+        self.filter.apply(&mut get_tables, Alias::new("name"));
 
-        let mut discovered_indexes = Vec::new();
         let rows = self.executor.fetch_all(get_tables).await?;
-        for row in rows {
-            let mut table: TableDef = (&row).into();
-            table.get_indexes(&self.executor).await?;
-            discovered_indexes.append(&mut table.indexes);
-        }
 
-        Ok(discovered_indexes)
+        rows.iter()
+            .map(|row| MasterTableInfo::try_from(row).map_err(SqliteDiscoveryError::from))
+            .filter_map(|result| self.warnings.recover(result).transpose())
+            .filter(|master_table| match master_table {
+                Ok(master_table) => self.filter.is_included(&master_table.name),
+                Err(_) => true,
+            })
+            .collect()
+    }
+
+    /// Discover table indexes of the main database
+    pub async fn discover_indexes(&self) -> DiscoveryResult<Vec<IndexInfo>> {
+        self.discover_schema_indexes("main").await
+    }
+
+    /// Discover table indexes of a database of the connection
+    pub async fn discover_schema_indexes(&self, schema: &str) -> DiscoveryResult<Vec<IndexInfo>> {
+        let tables =
+            future::try_join_all(self.discover_table_names(schema).await?.into_iter().map(
+                |mut table| async move {
+                    table.schema = schema.to_owned();
+                    table.get_indexes(&self.executor).await?;
+                    DiscoveryResult::Ok(table.indexes)
+                },
+            ))
+            .await?;
+
+        Ok(tables.into_iter().flatten().collect())
     }
 }
@@ -1,6 +1,6 @@
 use std::num::{ParseFloatError, ParseIntError};
 
-use crate::sqlx_types::SqlxError;
+use crate::{sqlx_types::SqlxError, ExecutorError, SchemaRowError};
 
 /// This type simplifies error handling
 pub type DiscoveryResult<T> = Result<T, SqliteDiscoveryError>;
@@ -12,11 +12,16 @@ pub enum SqliteDiscoveryError {
     ParseIntError,
     /// An error parsing a string from the result of an SQLite query into an rust-language float
     ParseFloatError,
-    /// The error as defined in [SqlxError]
-    SqlxError(SqlxError),
+    /// An error of the executor running the queries, e.g. an [SqlxError]
+    ExecutorError(ExecutorError),
+    /// A row returned by a pragma or by `sqlite_master` does not have the expected shape
+    UnexpectedRow(SchemaRowError),
     /// An operation to discover the indexes in a table was invoked
     /// but the target table contains no indexes
     NoIndexesFound,
+    /// The `CREATE VIRTUAL TABLE` statement of a virtual table could not be parsed,
+    /// so the table could not be written back
+    UnparsedVirtualTable(String),
 }
 
 impl From<ParseIntError> for SqliteDiscoveryError {
@@ -31,9 +36,36 @@ impl From<ParseFloatError> for SqliteDiscoveryError {
     }
 }
 
+impl From<ExecutorError> for SqliteDiscoveryError {
+    fn from(error: ExecutorError) -> Self {
+        SqliteDiscoveryError::ExecutorError(error)
+    }
+}
+
 impl From<SqlxError> for SqliteDiscoveryError {
     fn from(error: SqlxError) -> Self {
-        SqliteDiscoveryError::SqlxError(error)
+        SqliteDiscoveryError::ExecutorError(error.into())
+    }
+}
+
+impl From<SchemaRowError> for SqliteDiscoveryError {
+    fn from(error: SchemaRowError) -> Self {
+        SqliteDiscoveryError::UnexpectedRow(error)
+    }
+}
+
+#[cfg(feature = "discovery")]
+impl From<SqliteDiscoveryError> for crate::DiscoveryWarning {
+    fn from(error: SqliteDiscoveryError) -> Self {
+        crate::DiscoveryWarning::new(error.to_string())
+    }
+}
+
+#[cfg(feature = "discovery")]
+impl crate::warning::Recoverable for SqliteDiscoveryError {
+    /// The errors of the executor are not recoverable, the others are about a single table
+    fn is_recoverable(&self) -> bool {
+        !matches!(self, SqliteDiscoveryError::ExecutorError(_))
     }
 }
 
@@ -44,8 +76,12 @@ impl std::fmt::Display for SqliteDiscoveryError {
         match self {
             SqliteDiscoveryError::ParseIntError => write!(f, "Parse Integer Error"),
             SqliteDiscoveryError::ParseFloatError => write!(f, "Parse Float Error Error"),
-            SqliteDiscoveryError::SqlxError(e) => write!(f, "SQLx Error: {:?}", e),
+            SqliteDiscoveryError::ExecutorError(e) => write!(f, "Executor Error: {}", e),
+            SqliteDiscoveryError::UnexpectedRow(e) => write!(f, "{}", e),
             SqliteDiscoveryError::NoIndexesFound => write!(f, "No Indexes Found Error"),
+            SqliteDiscoveryError::UnparsedVirtualTable(sql) => {
+                write!(f, "Unparsed CREATE VIRTUAL TABLE statement: {:?}", sql)
+            }
         }
     }
 }
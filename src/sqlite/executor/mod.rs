@@ -1,9 +1,94 @@
+use futures::future::BoxFuture;
+use sea_query::SelectStatement;
+
+use crate::SchemaRow;
+pub use crate::{sqlx_types::SqlxError, ExecutorError};
+
 #[cfg(feature = "sqlx-sqlite")]
 mod real;
-#[cfg(feature = "sqlx-sqlite")]
-pub use real::*;
 
-#[cfg(not(feature = "sqlx-sqlite"))]
-mod mock;
-#[cfg(not(feature = "sqlx-sqlite"))]
-pub use mock::*;
+/// Runs the queries of the schema discovery on a SQLite database.
+/// Implemented for `SqlitePool` with the `sqlx-sqlite` feature, which also provides [IntoExecutor]
+/// for a borrowed connection or transaction, so that the discovery runs in that session.
+/// Can be implemented to discover through any other driver or connection
+pub trait SchemaExecutor: Send + Sync {
+    /// Run a query, to be built with `SqliteQueryBuilder`, and fetch all the rows
+    fn fetch_all(
+        &self,
+        select: SelectStatement,
+    ) -> BoxFuture<'_, Result<Vec<SchemaRow>, ExecutorError>>;
+
+    /// Run a statement, a `PRAGMA`, and fetch all the rows
+    fn fetch_all_raw(&self, sql: String) -> BoxFuture<'_, Result<Vec<SchemaRow>, ExecutorError>>;
+}
+
+impl<E> SchemaExecutor for &E
+where
+    E: SchemaExecutor + ?Sized,
+{
+    fn fetch_all(
+        &self,
+        select: SelectStatement,
+    ) -> BoxFuture<'_, Result<Vec<SchemaRow>, ExecutorError>> {
+        (**self).fetch_all(select)
+    }
+
+    fn fetch_all_raw(&self, sql: String) -> BoxFuture<'_, Result<Vec<SchemaRow>, ExecutorError>> {
+        (**self).fetch_all_raw(sql)
+    }
+}
+
+/// The executor the discovery runs on, which may borrow a connection for `'c`
+pub struct Executor<'c> {
+    executor: Box<dyn SchemaExecutor + 'c>,
+}
+
+pub trait IntoExecutor<'c> {
+    fn into_executor(self) -> Executor<'c>;
+}
+
+impl<'c, E> IntoExecutor<'c> for E
+where
+    E: SchemaExecutor + 'c,
+{
+    fn into_executor(self) -> Executor<'c> {
+        Executor {
+            executor: Box::new(self),
+        }
+    }
+}
+
+impl<'c> IntoExecutor<'c> for Executor<'c> {
+    fn into_executor(self) -> Executor<'c> {
+        self
+    }
+}
+
+impl Executor<'_> {
+    /// An executor running the queries on this one, to share it between discoveries
+    pub(crate) fn borrow(&self) -> Executor<'_> {
+        Executor {
+            executor: Box::new(&*self.executor),
+        }
+    }
+
+    pub async fn fetch_all(
+        &self,
+        select: SelectStatement,
+    ) -> Result<Vec<SchemaRow>, ExecutorError> {
+        self.executor.fetch_all(select).await
+    }
+
+    pub async fn fetch_one(&self, select: SelectStatement) -> Result<SchemaRow, ExecutorError> {
+        self.executor
+            .fetch_all(select)
+            .await?
+            .into_iter()
+            .next()
+            .ok_or_else(|| SqlxError::RowNotFound.into())
+    }
+
+    pub async fn fetch_all_raw(&self, sql: String) -> Result<Vec<SchemaRow>, ExecutorError> {
+        self.executor.fetch_all_raw(sql).await
+    }
+}
@@ -1,47 +1,120 @@
+use futures::{future::BoxFuture, lock::Mutex};
 use sea_query::{SelectStatement, SqliteQueryBuilder};
 use sea_query_binder::SqlxBinder;
-use sqlx::{sqlite::SqliteRow, SqlitePool};
+use sqlx::{
+    pool::PoolConnection, sqlite::SqliteRow, Row, Sqlite, SqliteConnection, SqlitePool,
+    Transaction, TypeInfo, ValueRef,
+};
 
-use crate::{debug_print, sqlx_types::SqlxError};
+use super::{Executor, IntoExecutor, SchemaExecutor};
+use crate::{debug_print, ExecutorError, SchemaRow, SchemaValue};
 
-pub struct Executor {
-    pool: SqlitePool,
+impl SchemaExecutor for SqlitePool {
+    fn fetch_all(
+        &self,
+        select: SelectStatement,
+    ) -> BoxFuture<'_, Result<Vec<SchemaRow>, ExecutorError>> {
+        Box::pin(fetch_all(self, select))
+    }
+
+    fn fetch_all_raw(&self, sql: String) -> BoxFuture<'_, Result<Vec<SchemaRow>, ExecutorError>> {
+        Box::pin(fetch_all_raw(self, sql))
+    }
 }
 
-pub trait IntoExecutor {
-    fn into_executor(self) -> Executor;
+/// A borrowed connection, locked for each query as the discovery runs them concurrently
+struct ConnectionExecutor<'c> {
+    connection: Mutex<&'c mut SqliteConnection>,
 }
 
-impl IntoExecutor for SqlitePool {
-    fn into_executor(self) -> Executor {
-        Executor { pool: self }
+impl SchemaExecutor for ConnectionExecutor<'_> {
+    fn fetch_all(
+        &self,
+        select: SelectStatement,
+    ) -> BoxFuture<'_, Result<Vec<SchemaRow>, ExecutorError>> {
+        Box::pin(async move {
+            let mut connection = self.connection.lock().await;
+            fetch_all(&mut **connection, select).await
+        })
     }
-}
 
-impl Executor {
-    pub async fn fetch_all(&self, select: SelectStatement) -> Result<Vec<SqliteRow>, SqlxError> {
-        let (sql, values) = select.build_sqlx(SqliteQueryBuilder);
-        debug_print!("{}, {:?}", sql, values);
+    fn fetch_all_raw(&self, sql: String) -> BoxFuture<'_, Result<Vec<SchemaRow>, ExecutorError>> {
+        Box::pin(async move {
+            let mut connection = self.connection.lock().await;
+            fetch_all_raw(&mut **connection, sql).await
+        })
+    }
+}
 
-        sqlx::query_with(&sql, values)
-            .fetch_all(&mut *self.pool.acquire().await?)
-            .await
+impl<'c> IntoExecutor<'c> for &'c mut SqliteConnection {
+    fn into_executor(self) -> Executor<'c> {
+        ConnectionExecutor {
+            connection: Mutex::new(self),
+        }
+        .into_executor()
     }
+}
 
-    pub async fn fetch_one(&self, select: SelectStatement) -> Result<SqliteRow, SqlxError> {
-        let (sql, values) = select.build_sqlx(SqliteQueryBuilder);
-        debug_print!("{}, {:?}", sql, values);
+impl<'c> IntoExecutor<'c> for &'c mut Transaction<'_, Sqlite> {
+    fn into_executor(self) -> Executor<'c> {
+        (&mut **self).into_executor()
+    }
+}
 
-        sqlx::query_with(&sql, values)
-            .fetch_one(&mut *self.pool.acquire().await?)
-            .await
+impl<'c> IntoExecutor<'c> for &'c mut PoolConnection<Sqlite> {
+    fn into_executor(self) -> Executor<'c> {
+        (&mut **self).into_executor()
     }
+}
 
-    pub async fn fetch_all_raw(&self, sql: String) -> Result<Vec<SqliteRow>, SqlxError> {
-        debug_print!("{}", sql);
+async fn fetch_all<'e, E>(
+    executor: E,
+    select: SelectStatement,
+) -> Result<Vec<SchemaRow>, ExecutorError>
+where
+    E: sqlx::Executor<'e, Database = Sqlite>,
+{
+    let (sql, values) = select.build_sqlx(SqliteQueryBuilder);
+    debug_print!("{}, {:?}", sql, values);
+
+    sqlx::query_with(&sql, values)
+        .fetch_all(executor)
+        .await?
+        .iter()
+        .map(schema_row)
+        .collect()
+}
+
+async fn fetch_all_raw<'e, E>(executor: E, sql: String) -> Result<Vec<SchemaRow>, ExecutorError>
+where
+    E: sqlx::Executor<'e, Database = Sqlite>,
+{
+    debug_print!("{}", sql);
+
+    sqlx::query(&sql)
+        .fetch_all(executor)
+        .await?
+        .iter()
+        .map(schema_row)
+        .collect()
+}
 
-        sqlx::query(&sql)
-            .fetch_all(&mut *self.pool.acquire().await?)
-            .await
+/// Convert a row according to the storage class of each value
+fn schema_row(row: &SqliteRow) -> Result<SchemaRow, ExecutorError> {
+    let mut values = Vec::with_capacity(row.len());
+    for index in 0..row.len() {
+        let value = row.try_get_raw(index)?;
+        let value = if value.is_null() {
+            SchemaValue::Null
+        } else {
+            match value.type_info().name() {
+                "INTEGER" => SchemaValue::Int(row.try_get_unchecked(index)?),
+                "REAL" => SchemaValue::Float(row.try_get_unchecked(index)?),
+                "BLOB" => SchemaValue::Bytes(row.try_get_unchecked(index)?),
+                _ => SchemaValue::String(row.try_get_unchecked(index)?),
+            }
+        };
+        values.push(value);
     }
+    Ok(SchemaRow::new(values))
 }
@@ -15,6 +15,10 @@ pub mod discovery;
 mod error;
 mod executor;
 
+#[cfg(feature = "parser")]
+#[cfg_attr(docsrs, doc(cfg(feature = "parser")))]
+pub mod parser;
+
 #[cfg(feature = "query")]
 #[cfg_attr(docsrs, doc(cfg(feature = "query")))]
 pub mod query;
@@ -0,0 +1,166 @@
+use super::create_table::{
+    curr_is_end_of_definition, curr_is_punctuation, parse_name, strip_comments,
+};
+use crate::parser::Parser;
+use crate::sqlite::def::IndexColumnInfo;
+
+/// The parts of a `CREATE INDEX` statement that `PRAGMA index_xinfo` does not report
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct CreateIndexInfo {
+    /// The indexed columns and expressions, in order
+    pub columns: Vec<IndexColumnInfo>,
+    /// The WHERE clause of a partial index
+    pub where_clause: Option<String>,
+}
+
+/// Parse a `CREATE INDEX` statement as stored in `sqlite_master`.
+/// Returns `None` if the statement could not be understood
+pub fn parse_create_index(sql: &str) -> Option<CreateIndexInfo> {
+    let sql = strip_comments(sql);
+    let mut parser = Parser::new(&sql);
+
+    if !parser.next_if_unquoted("CREATE") {
+        return None;
+    }
+    parser.next_if_unquoted("UNIQUE");
+    if !parser.next_if_unquoted("INDEX") {
+        return None;
+    }
+    if parser.next_if_unquoted("IF")
+        && !(parser.next_if_unquoted("NOT") && parser.next_if_unquoted("EXISTS"))
+    {
+        return None;
+    }
+    parse_name(&mut parser)?;
+    if parser.next_if_punctuation(".") {
+        parse_name(&mut parser)?;
+    }
+    if !parser.next_if_unquoted("ON") {
+        return None;
+    }
+    parse_name(&mut parser)?;
+    if !parser.next_if_punctuation("(") {
+        return None;
+    }
+
+    let mut info = CreateIndexInfo::default();
+    loop {
+        info.columns.push(parse_indexed_column(&mut parser)?);
+        if parser.next_if_punctuation(",") {
+            continue;
+        }
+        if parser.next_if_punctuation(")") {
+            break;
+        }
+        return None;
+    }
+
+    if parser.next_if_unquoted("WHERE") {
+        let mut where_clause = String::new();
+        while parser.curr().is_some() {
+            push_token(&mut parser, &mut where_clause)?;
+        }
+        info.where_clause = Some(where_clause);
+    }
+    if parser.curr().is_some() {
+        return None;
+    }
+
+    Some(info)
+}
+
+/// Parse an indexed column, which is a column name or an expression
+/// followed by an optional COLLATE clause and sort order
+fn parse_indexed_column(parser: &mut Parser) -> Option<IndexColumnInfo> {
+    let mut column = IndexColumnInfo::default();
+    let mut expr = String::new();
+    let mut name = None;
+    let mut terms = 0;
+
+    while !curr_is_end_of_definition(parser)? {
+        if parser.next_if_unquoted("COLLATE") {
+            column.collation = Some(parse_name(parser)?);
+        } else if parser.next_if_unquoted("ASC") {
+            column.desc = false;
+        } else if parser.next_if_unquoted("DESC") {
+            column.desc = true;
+        } else {
+            if let Some(tok) = parser.curr() {
+                if tok.is_unquoted() {
+                    name = Some(tok.to_string());
+                } else if tok.is_quoted() {
+                    name = tok.unquote();
+                }
+            }
+            push_token(parser, &mut expr)?;
+            terms += 1;
+        }
+    }
+
+    if terms == 1 && name.is_some() {
+        column.name = name;
+    } else {
+        column.expr = Some(expr);
+    }
+
+    Some(column)
+}
+
+/// Consume the current token, or parenthesized group, and append it to `string`
+/// separated by a single space if whitespace preceded it
+pub(super) fn push_token(parser: &mut Parser, string: &mut String) -> Option<()> {
+    let space = parser.space;
+    let tok = if curr_is_punctuation(parser, "(") {
+        format!("({})", parser.next_balanced()?)
+    } else {
+        let tok = parser.curr()?.to_string();
+        parser.next();
+        tok
+    };
+    if space && !string.is_empty() {
+        string.push(' ');
+    }
+    string.push_str(&tok);
+    Some(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_partial_expression_index() {
+        assert_eq!(
+            parse_create_index(
+                r#"CREATE UNIQUE INDEX "idx-customer-email" ON customer (
+                    lower(email) COLLATE NOCASE,
+                    "region" DESC,
+                    age + 1 ASC
+                ) WHERE deleted_at IS NULL AND (age > 18)"#
+            ),
+            Some(CreateIndexInfo {
+                columns: vec![
+                    IndexColumnInfo {
+                        name: None,
+                        expr: Some("lower(email)".to_owned()),
+                        desc: false,
+                        collation: Some("NOCASE".to_owned()),
+                    },
+                    IndexColumnInfo {
+                        name: Some("region".to_owned()),
+                        expr: None,
+                        desc: true,
+                        collation: None,
+                    },
+                    IndexColumnInfo {
+                        name: None,
+                        expr: Some("age + 1".to_owned()),
+                        desc: false,
+                        collation: None,
+                    },
+                ],
+                where_clause: Some("deleted_at IS NULL AND (age > 18)".to_owned()),
+            })
+        );
+    }
+}
@@ -0,0 +1,484 @@
+use crate::parser::Parser;
+use crate::sqlite::def::{CheckInfo, Deferrable, GeneratedColumnInfo, MatchAction};
+
+/// The parts of a `CREATE TABLE` statement that no pragma reports
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct CreateTableInfo {
+    /// The columns in the order they are declared
+    pub columns: Vec<CreateTableColumnInfo>,
+    /// The CHECK constraints, both those declared on a column and on the table
+    pub checks: Vec<CheckInfo>,
+    /// The foreign key clauses, both those declared on a column and on the table
+    pub foreign_keys: Vec<ForeignKeyClauseInfo>,
+    /// The `WITHOUT ROWID` table option
+    pub without_rowid: bool,
+    /// The `STRICT` table option
+    pub strict: bool,
+}
+
+/// The parts of a column definition that `PRAGMA table_xinfo` does not report
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct CreateTableColumnInfo {
+    pub name: String,
+    /// The collating sequence of the COLLATE clause
+    pub collation: Option<String>,
+    /// The expression of a generated column
+    pub generated: Option<GeneratedColumnInfo>,
+}
+
+/// The parts of a foreign key clause that `PRAGMA foreign_key_list` does not report
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ForeignKeyClauseInfo {
+    /// The constraint name
+    pub name: Option<String>,
+    /// The referencing columns
+    pub from: Vec<String>,
+    /// The referenced table
+    pub table: String,
+    /// The MATCH clause, which `PRAGMA foreign_key_list` always reports as `NONE`
+    pub r#match: MatchAction,
+    pub deferrable: Option<Deferrable>,
+}
+
+/// Parse a `CREATE TABLE` statement as stored in `sqlite_master`.
+/// Returns `None` if the statement is not a `CREATE TABLE (...)` or could not be understood,
+/// e.g. `CREATE VIRTUAL TABLE` or `CREATE TABLE ... AS SELECT`
+pub fn parse_create_table(sql: &str) -> Option<CreateTableInfo> {
+    let sql = strip_comments(sql);
+    let mut parser = Parser::new(&sql);
+
+    if !parser.next_if_unquoted("CREATE") {
+        return None;
+    }
+    if !parser.next_if_unquoted("TEMP") {
+        parser.next_if_unquoted("TEMPORARY");
+    }
+    if !parser.next_if_unquoted("TABLE") {
+        return None;
+    }
+    if parser.next_if_unquoted("IF")
+        && !(parser.next_if_unquoted("NOT") && parser.next_if_unquoted("EXISTS"))
+    {
+        return None;
+    }
+    parse_name(&mut parser)?;
+    if parser.next_if_punctuation(".") {
+        parse_name(&mut parser)?;
+    }
+    if !parser.next_if_punctuation("(") {
+        return None;
+    }
+
+    let mut info = CreateTableInfo::default();
+    loop {
+        if curr_is_table_constraint(&mut parser) {
+            parse_table_constraint(&mut parser, &mut info)?;
+        } else {
+            let column = parse_column(&mut parser, &mut info)?;
+            info.columns.push(column);
+        }
+        if parser.next_if_punctuation(",") {
+            continue;
+        }
+        if parser.next_if_punctuation(")") {
+            break;
+        }
+        return None;
+    }
+
+    // The table options, separated by commas
+    while parser.curr().is_some() {
+        if parser.next_if_unquoted("STRICT") {
+            info.strict = true;
+        } else if parser.next_if_unquoted("WITHOUT") && parser.next_if_unquoted("ROWID") {
+            info.without_rowid = true;
+        } else {
+            return None;
+        }
+        parser.next_if_punctuation(",");
+    }
+
+    Some(info)
+}
+
+fn parse_column(parser: &mut Parser, info: &mut CreateTableInfo) -> Option<CreateTableColumnInfo> {
+    let mut column = CreateTableColumnInfo {
+        name: parse_name(parser)?,
+        ..Default::default()
+    };
+
+    while !curr_is_end_of_definition(parser)? {
+        if parser.next_if_unquoted("CONSTRAINT") {
+            let name = parse_name(parser)?;
+            // The name only matters to CHECK and REFERENCES constraints
+            if parser.next_if_unquoted("CHECK") {
+                info.checks.push(CheckInfo {
+                    name: Some(name),
+                    expr: parser.next_balanced()?,
+                });
+            } else if parser.next_if_unquoted("REFERENCES") {
+                let foreign_key =
+                    parse_foreign_key_clause(parser, Some(name), vec![column.name.clone()])?;
+                info.foreign_keys.push(foreign_key);
+            }
+        } else if parser.next_if_unquoted("REFERENCES") {
+            let foreign_key = parse_foreign_key_clause(parser, None, vec![column.name.clone()])?;
+            info.foreign_keys.push(foreign_key);
+        } else if parser.next_if_unquoted("CHECK") {
+            info.checks.push(CheckInfo {
+                name: None,
+                expr: parser.next_balanced()?,
+            });
+        } else if parser.next_if_unquoted("COLLATE") {
+            column.collation = Some(parse_name(parser)?);
+        } else if parser.next_if_unquoted("AS") {
+            let expr = parser.next_balanced()?;
+            let stored = if parser.next_if_unquoted("STORED") {
+                true
+            } else {
+                parser.next_if_unquoted("VIRTUAL");
+                false
+            };
+            column.generated = Some(GeneratedColumnInfo { expr, stored });
+        } else if curr_is_punctuation(parser, "(") {
+            // Type arguments and DEFAULT expressions
+            parser.next_balanced()?;
+        } else {
+            parser.next();
+        }
+    }
+
+    Some(column)
+}
+
+fn parse_table_constraint(parser: &mut Parser, info: &mut CreateTableInfo) -> Option<()> {
+    let name = if parser.next_if_unquoted("CONSTRAINT") {
+        Some(parse_name(parser)?)
+    } else {
+        None
+    };
+    if parser.next_if_unquoted("CHECK") {
+        info.checks.push(CheckInfo {
+            name,
+            expr: parser.next_balanced()?,
+        });
+    } else if parser.next_if_unquoted("FOREIGN") {
+        if !(parser.next_if_unquoted("KEY") && parser.next_if_punctuation("(")) {
+            return None;
+        }
+        let mut from = Vec::new();
+        loop {
+            from.push(parse_name(parser)?);
+            if parser.next_if_punctuation(",") {
+                continue;
+            }
+            if parser.next_if_punctuation(")") {
+                break;
+            }
+            return None;
+        }
+        if !parser.next_if_unquoted("REFERENCES") {
+            return None;
+        }
+        let foreign_key = parse_foreign_key_clause(parser, name, from)?;
+        info.foreign_keys.push(foreign_key);
+    }
+
+    while !curr_is_end_of_definition(parser)? {
+        if curr_is_punctuation(parser, "(") {
+            parser.next_balanced()?;
+        } else {
+            parser.next();
+        }
+    }
+
+    Some(())
+}
+
+/// Parse the foreign key clause following `REFERENCES`. Stops at the first token
+/// that is not part of the clause, which is left for the caller
+fn parse_foreign_key_clause(
+    parser: &mut Parser,
+    name: Option<String>,
+    from: Vec<String>,
+) -> Option<ForeignKeyClauseInfo> {
+    let mut foreign_key = ForeignKeyClauseInfo {
+        name,
+        from,
+        table: parse_name(parser)?,
+        ..Default::default()
+    };
+    if curr_is_punctuation(parser, "(") {
+        // The referenced columns are reported by the pragma
+        parser.next_balanced()?;
+    }
+
+    loop {
+        if parser.next_if_unquoted("ON") {
+            // ON DELETE | UPDATE, followed by an action of one or two words
+            // which are reported by the pragma
+            parser.next();
+            if !parser.next_if_unquoted("SET") {
+                parser.next_if_unquoted("NO");
+            }
+            parser.next();
+        } else if parser.next_if_unquoted("MATCH") {
+            foreign_key.r#match = parse_name(parser)?.as_str().into();
+        } else if parser.next_if_unquoted("DEFERRABLE") {
+            foreign_key.deferrable = Some(parse_initially(parser, false));
+        } else if parser.next_if_unquoted("NOT") {
+            if !parser.next_if_unquoted("DEFERRABLE") {
+                // e.g. NOT NULL, which the caller skips
+                break;
+            }
+            foreign_key.deferrable = Some(parse_initially(parser, true));
+        } else {
+            break;
+        }
+    }
+
+    Some(foreign_key)
+}
+
+/// Parse the optional `INITIALLY DEFERRED | IMMEDIATE` following `[NOT] DEFERRABLE`
+fn parse_initially(parser: &mut Parser, not: bool) -> Deferrable {
+    let deferred = parser.next_if_unquoted("INITIALLY") && parser.next_if_unquoted("DEFERRED");
+    parser.next_if_unquoted("IMMEDIATE");
+    match (not, deferred) {
+        (true, _) => Deferrable::NotDeferrable,
+        (false, true) => Deferrable::InitiallyDeferred,
+        (false, false) => Deferrable::InitiallyImmediate,
+    }
+}
+
+/// Parse an identifier, which may be quoted in any of the ways SQLite accepts
+pub(super) fn parse_name(parser: &mut Parser) -> Option<String> {
+    if let Some(tok) = parser.next_if_quoted_any() {
+        return Some(tok.unquote().unwrap_or_default());
+    }
+    parser.next_if_unquoted_any().map(|tok| tok.to_string())
+}
+
+fn curr_is_table_constraint(parser: &mut Parser) -> bool {
+    parser.curr_is_unquoted()
+        && matches!(
+            parser.curr_as_str().to_lowercase().as_str(),
+            "constraint" | "primary" | "unique" | "check" | "foreign"
+        )
+}
+
+pub(super) fn curr_is_punctuation(parser: &mut Parser, word: &str) -> bool {
+    matches!(parser.curr(), Some(tok) if tok.is_punctuation() && tok.as_str() == word)
+}
+
+/// Whether the current token ends a column definition or table constraint,
+/// or `None` if the statement ended prematurely
+pub(super) fn curr_is_end_of_definition(parser: &mut Parser) -> Option<bool> {
+    parser.curr()?;
+    Some(curr_is_punctuation(parser, ",") || curr_is_punctuation(parser, ")"))
+}
+
+/// Replace the `--` and `/* */` comments outside of quotes with a space
+pub(super) fn strip_comments(sql: &str) -> String {
+    let mut string = String::with_capacity(sql.len());
+    let mut chars = sql.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\'' | '"' | '`' | '[' => {
+                let end = if c == '[' { ']' } else { c };
+                string.push(c);
+                for c in chars.by_ref() {
+                    string.push(c);
+                    if c == end {
+                        break;
+                    }
+                }
+            }
+            '-' if chars.peek() == Some(&'-') => {
+                for c in chars.by_ref() {
+                    if c == '\n' {
+                        break;
+                    }
+                }
+                string.push(' ');
+            }
+            '/' if chars.peek() == Some(&'*') => {
+                chars.next();
+                let mut last = ' ';
+                for c in chars.by_ref() {
+                    if last == '*' && c == '/' {
+                        break;
+                    }
+                    last = c;
+                }
+                string.push(' ');
+            }
+            _ => string.push(c),
+        }
+    }
+    string
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_collate_generated() {
+        assert_eq!(
+            parse_create_table(
+                r#"CREATE TABLE "order" (
+                    id INTEGER PRIMARY KEY, -- the rowid
+                    code VARCHAR(16) NOT NULL COLLATE NOCASE CHECK (length(code) > 0),
+                    price REAL DEFAULT (0.0) CONSTRAINT positive_price CHECK ( price >= 0 ),
+                    qty INTEGER /* units */ DEFAULT 1,
+                    total REAL GENERATED ALWAYS AS (price * qty) STORED,
+                    label TEXT AS (upper(code)),
+                    CONSTRAINT "total limit" CHECK (total < 1000000),
+                    UNIQUE (code, qty),
+                    CHECK (qty <> 13)
+                )"#
+            ),
+            Some(CreateTableInfo {
+                columns: vec![
+                    CreateTableColumnInfo {
+                        name: "id".to_owned(),
+                        ..Default::default()
+                    },
+                    CreateTableColumnInfo {
+                        name: "code".to_owned(),
+                        collation: Some("NOCASE".to_owned()),
+                        generated: None,
+                    },
+                    CreateTableColumnInfo {
+                        name: "price".to_owned(),
+                        ..Default::default()
+                    },
+                    CreateTableColumnInfo {
+                        name: "qty".to_owned(),
+                        ..Default::default()
+                    },
+                    CreateTableColumnInfo {
+                        name: "total".to_owned(),
+                        collation: None,
+                        generated: Some(GeneratedColumnInfo {
+                            expr: "price * qty".to_owned(),
+                            stored: true,
+                        }),
+                    },
+                    CreateTableColumnInfo {
+                        name: "label".to_owned(),
+                        collation: None,
+                        generated: Some(GeneratedColumnInfo {
+                            expr: "upper(code)".to_owned(),
+                            stored: false,
+                        }),
+                    },
+                ],
+                checks: vec![
+                    CheckInfo {
+                        name: None,
+                        expr: "length(code) > 0".to_owned(),
+                    },
+                    CheckInfo {
+                        name: Some("positive_price".to_owned()),
+                        expr: "price >= 0".to_owned(),
+                    },
+                    CheckInfo {
+                        name: Some("total limit".to_owned()),
+                        expr: "total < 1000000".to_owned(),
+                    },
+                    CheckInfo {
+                        name: None,
+                        expr: "qty <> 13".to_owned(),
+                    },
+                ],
+                foreign_keys: vec![],
+                without_rowid: false,
+                strict: false,
+            })
+        );
+    }
+
+    #[test]
+    fn test_foreign_keys() {
+        let info = parse_create_table(
+            r#"CREATE TABLE line (
+                id INTEGER PRIMARY KEY,
+                order_id INTEGER NOT NULL CONSTRAINT "fk-line-order" REFERENCES "order" (id)
+                    ON DELETE CASCADE DEFERRABLE INITIALLY DEFERRED,
+                product_id INTEGER REFERENCES product MATCH FULL NOT NULL,
+                shelf TEXT,
+                bin INTEGER,
+                FOREIGN KEY (shelf, bin) REFERENCES slot (shelf, bin)
+                    ON UPDATE SET NULL NOT DEFERRABLE,
+                CONSTRAINT fk_line_batch FOREIGN KEY ("bin") REFERENCES batch DEFERRABLE
+            )"#,
+        )
+        .unwrap();
+        assert_eq!(
+            info.foreign_keys,
+            vec![
+                ForeignKeyClauseInfo {
+                    name: Some("fk-line-order".to_owned()),
+                    from: vec!["order_id".to_owned()],
+                    table: "order".to_owned(),
+                    r#match: MatchAction::None,
+                    deferrable: Some(Deferrable::InitiallyDeferred),
+                },
+                ForeignKeyClauseInfo {
+                    name: None,
+                    from: vec!["product_id".to_owned()],
+                    table: "product".to_owned(),
+                    r#match: MatchAction::Full,
+                    deferrable: None,
+                },
+                ForeignKeyClauseInfo {
+                    name: None,
+                    from: vec!["shelf".to_owned(), "bin".to_owned()],
+                    table: "slot".to_owned(),
+                    r#match: MatchAction::None,
+                    deferrable: Some(Deferrable::NotDeferrable),
+                },
+                ForeignKeyClauseInfo {
+                    name: Some("fk_line_batch".to_owned()),
+                    from: vec!["bin".to_owned()],
+                    table: "batch".to_owned(),
+                    r#match: MatchAction::None,
+                    deferrable: Some(Deferrable::InitiallyImmediate),
+                },
+            ]
+        );
+        assert_eq!(info.columns.len(), 5);
+    }
+
+    #[test]
+    fn test_table_options() {
+        let info =
+            parse_create_table("CREATE TABLE kv (k TEXT PRIMARY KEY, v ANY) WITHOUT ROWID, STRICT")
+                .unwrap();
+        assert!(info.without_rowid);
+        assert!(info.strict);
+
+        let info =
+            parse_create_table("CREATE TABLE kv (k TEXT PRIMARY KEY, v ANY) STRICT").unwrap();
+        assert!(!info.without_rowid);
+        assert!(info.strict);
+
+        let info = parse_create_table("CREATE TABLE kv (k TEXT PRIMARY KEY, v ANY)").unwrap();
+        assert!(!info.without_rowid);
+        assert!(!info.strict);
+    }
+
+    #[test]
+    fn test_not_create_table() {
+        assert_eq!(
+            parse_create_table("CREATE VIRTUAL TABLE docs USING fts5(body)"),
+            None
+        );
+        assert_eq!(
+            parse_create_table("CREATE TABLE t2 AS SELECT * FROM t1"),
+            None
+        );
+    }
+}
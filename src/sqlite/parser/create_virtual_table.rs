@@ -0,0 +1,96 @@
+use super::create_index::push_token;
+use super::create_table::{curr_is_end_of_definition, parse_name, strip_comments};
+use crate::parser::Parser;
+use crate::sqlite::def::VirtualTableDef;
+
+/// Parse a `CREATE VIRTUAL TABLE` statement as stored in `sqlite_master`.
+/// Returns `None` if the statement is not a `CREATE VIRTUAL TABLE` or could not be understood
+pub fn parse_create_virtual_table(sql: &str) -> Option<VirtualTableDef> {
+    let sql = strip_comments(sql);
+    let mut parser = Parser::new(&sql);
+
+    if !(parser.next_if_unquoted("CREATE")
+        && parser.next_if_unquoted("VIRTUAL")
+        && parser.next_if_unquoted("TABLE"))
+    {
+        return None;
+    }
+    if parser.next_if_unquoted("IF")
+        && !(parser.next_if_unquoted("NOT") && parser.next_if_unquoted("EXISTS"))
+    {
+        return None;
+    }
+    let mut schema = String::new();
+    let mut name = parse_name(&mut parser)?;
+    if parser.next_if_punctuation(".") {
+        schema = name;
+        name = parse_name(&mut parser)?;
+    }
+    if !parser.next_if_unquoted("USING") {
+        return None;
+    }
+
+    let mut virtual_table = VirtualTableDef {
+        name,
+        schema,
+        module: parse_name(&mut parser)?,
+        args: Vec::new(),
+        columns: Vec::new(),
+    };
+
+    if parser.next_if_punctuation("(") && !parser.next_if_punctuation(")") {
+        loop {
+            let mut arg = String::new();
+            while !curr_is_end_of_definition(&mut parser)? {
+                push_token(&mut parser, &mut arg)?;
+            }
+            virtual_table.args.push(arg);
+            if parser.next_if_punctuation(",") {
+                continue;
+            }
+            if parser.next_if_punctuation(")") {
+                break;
+            }
+            return None;
+        }
+    }
+    if parser.curr().is_some() {
+        return None;
+    }
+
+    Some(virtual_table)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_virtual_table() {
+        assert_eq!(
+            parse_create_virtual_table(
+                r#"CREATE VIRTUAL TABLE "docs" USING fts5(title, body UNINDEXED, tokenize = 'porter ascii')"#
+            ),
+            Some(VirtualTableDef {
+                name: "docs".to_owned(),
+                module: "fts5".to_owned(),
+                args: vec![
+                    "title".to_owned(),
+                    "body UNINDEXED".to_owned(),
+                    "tokenize = 'porter ascii'".to_owned(),
+                ],
+                ..Default::default()
+            })
+        );
+        assert_eq!(
+            parse_create_virtual_table("CREATE VIRTUAL TABLE temp.t1 USING dbstat"),
+            Some(VirtualTableDef {
+                name: "t1".to_owned(),
+                schema: "temp".to_owned(),
+                module: "dbstat".to_owned(),
+                ..Default::default()
+            })
+        );
+        assert_eq!(parse_create_virtual_table("CREATE TABLE t1 (a)"), None);
+    }
+}
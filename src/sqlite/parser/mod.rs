@@ -0,0 +1,9 @@
+//! To parse the SQL statements SQLite stores in `sqlite_master`
+
+mod create_index;
+mod create_table;
+mod create_virtual_table;
+
+pub use create_index::*;
+pub use create_table::*;
+pub use create_virtual_table::*;
@@ -1,29 +1,18 @@
-#![allow(dead_code)]
-
-pub struct MySqlPool;
-
-pub mod mysql {
-    pub struct MySqlRow;
-}
-
-pub struct PgPool;
-
-pub mod postgres {
-    pub struct PgRow;
-}
-
-pub struct SqlitePool;
-
-pub mod sqlite {
-    pub struct SqliteRow;
-}
-
-pub trait Row {}
-
-#[derive(Debug)]
-pub struct Error;
-
+/// Stands in for `sqlx::Error` without the `sqlx-*` features
 #[derive(Debug)]
 pub enum SqlxError {
     RowNotFound,
 }
+
+impl std::error::Error for SqlxError {}
+
+impl std::fmt::Display for SqlxError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            SqlxError::RowNotFound => write!(
+                f,
+                "no rows returned by a query that expected to return at least one row"
+            ),
+        }
+    }
+}
@@ -1,3 +1 @@
-pub use sqlx::*;
-
 pub type SqlxError = sqlx::Error;
@@ -12,3 +12,13 @@ macro_rules! debug_print {
         true;
     };
 }
+
+/// Quote an identifier with the given quotes, for statements SeaQuery cannot build
+#[allow(dead_code)]
+pub(crate) fn quote_iden(name: &str, quote: sea_query::Quote) -> String {
+    use sea_query::Iden;
+
+    let mut string = String::new();
+    sea_query::Alias::new(name).prepare(&mut string, quote);
+    string
+}
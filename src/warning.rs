@@ -0,0 +1,179 @@
+use std::sync::{Arc, Mutex};
+
+/// Something a lenient discovery could not understand, and skipped or discovered approximately
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiscoveryWarning {
+    /// The table where it was found, if any
+    pub table: Option<String>,
+    /// The column where it was found, if any
+    pub column: Option<String>,
+    /// The value as returned by the database, if any
+    pub value: Option<String>,
+    pub reason: String,
+}
+
+/// Collects the warnings of a lenient discovery. A strict discovery fails instead.
+/// Every lenient discovery gets a collector of its own, shared by the tables it discovers
+/// concurrently, so discoveries running at the same time don't mix up their warnings
+#[derive(Debug, Default, Clone)]
+pub(crate) struct Warnings {
+    warnings: Option<Arc<Mutex<Vec<DiscoveryWarning>>>>,
+}
+
+/// An error a lenient discovery can skip, as opposed to a failure of the executor
+pub(crate) trait Recoverable: Into<DiscoveryWarning> {
+    fn is_recoverable(&self) -> bool;
+}
+
+impl DiscoveryWarning {
+    pub fn new<T>(reason: T) -> Self
+    where
+        T: Into<String>,
+    {
+        Self {
+            table: None,
+            column: None,
+            value: None,
+            reason: reason.into(),
+        }
+    }
+
+    pub fn table<T>(mut self, table: T) -> Self
+    where
+        T: Into<String>,
+    {
+        self.table = Some(table.into());
+        self
+    }
+
+    pub fn column<T>(mut self, column: T) -> Self
+    where
+        T: Into<String>,
+    {
+        self.column = Some(column.into());
+        self
+    }
+
+    pub fn value<T>(mut self, value: T) -> Self
+    where
+        T: Into<String>,
+    {
+        self.value = Some(value.into());
+        self
+    }
+}
+
+impl std::fmt::Display for DiscoveryWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.reason)?;
+        if let Some(value) = &self.value {
+            write!(f, ": {:?}", value)?;
+        }
+        match (&self.table, &self.column) {
+            (Some(table), Some(column)) => write!(f, " in column `{}`.`{}`", table, column),
+            (Some(table), None) => write!(f, " in table `{}`", table),
+            (None, Some(column)) => write!(f, " in column `{}`", column),
+            (None, None) => Ok(()),
+        }
+    }
+}
+
+impl Warnings {
+    /// A collector recording the warnings, instead of failing
+    pub fn lenient() -> Self {
+        Self {
+            warnings: Some(Arc::default()),
+        }
+    }
+
+    /// Take the warnings collected
+    pub fn take(&self) -> Vec<DiscoveryWarning> {
+        match &self.warnings {
+            Some(warnings) => std::mem::take(&mut *warnings.lock().unwrap()),
+            None => Vec::new(),
+        }
+    }
+
+    /// Record a warning, if lenient
+    pub fn warn(&self, warning: DiscoveryWarning) {
+        if let Some(warnings) = &self.warnings {
+            warnings.lock().unwrap().push(warning);
+        }
+    }
+
+    /// If lenient and the error is recoverable, record it as a warning and return `None` to skip
+    /// what failed
+    pub fn recover<T, E>(&self, result: Result<T, E>) -> Result<Option<T>, E>
+    where
+        E: Recoverable,
+    {
+        self.recover_with(result, Into::into)
+    }
+
+    /// Same as [Warnings::recover], turning the error into a warning with `warning`
+    pub fn recover_with<T, E, F>(&self, result: Result<T, E>, warning: F) -> Result<Option<T>, E>
+    where
+        E: Recoverable,
+        F: FnOnce(E) -> DiscoveryWarning,
+    {
+        match (result, &self.warnings) {
+            (Ok(value), _) => Ok(Some(value)),
+            (Err(error), Some(warnings)) if error.is_recoverable() => {
+                warnings.lock().unwrap().push(warning(error));
+                Ok(None)
+            }
+            (Err(error), _) => Err(error),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Error {
+        UnknownType,
+        Disconnected,
+    }
+
+    impl From<Error> for DiscoveryWarning {
+        fn from(error: Error) -> Self {
+            DiscoveryWarning::new(format!("{:?}", error))
+        }
+    }
+
+    impl Recoverable for Error {
+        fn is_recoverable(&self) -> bool {
+            *self != Error::Disconnected
+        }
+    }
+
+    #[test]
+    fn test_warnings() {
+        let strict = Warnings::default();
+        strict.warn(DiscoveryWarning::new("Ignored"));
+        assert_eq!(
+            strict.recover(Err::<(), _>(Error::UnknownType)),
+            Err(Error::UnknownType)
+        );
+        assert!(strict.take().is_empty());
+
+        let warnings = Warnings::lenient();
+        warnings.warn(DiscoveryWarning::new("Unknown default").table("cake"));
+        assert_eq!(warnings.recover(Err::<(), _>(Error::UnknownType)), Ok(None));
+        assert_eq!(
+            warnings.recover(Err::<(), _>(Error::Disconnected)),
+            Err(Error::Disconnected)
+        );
+        assert_eq!(warnings.recover(Ok::<_, Error>(1)), Ok(Some(1)));
+        assert_eq!(
+            warnings.clone().take(),
+            [
+                DiscoveryWarning::new("Unknown default").table("cake"),
+                DiscoveryWarning::new("UnknownType"),
+            ]
+        );
+        assert!(warnings.take().is_empty());
+    }
+}
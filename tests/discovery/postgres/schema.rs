@@ -1,6 +1,105 @@
 Schema {
     schema: "public",
+    extensions: [
+        ExtensionDef {
+            name: "plpgsql",
+            schema: "pg_catalog",
+            version: "1.0",
+        },
+    ],
     tables: [
+        TableDef {
+            info: TableInfo {
+                name: "language",
+                of_type: None,
+            },
+            columns: [
+                ColumnInfo {
+                    name: "language_id",
+                    col_type: Integer,
+                    default: Some(
+                        ColumnExpression(
+                            "nextval('language_language_id_seq'::regclass)",
+                        ),
+                    ),
+                    generated: None,
+                    not_null: Some(
+                        NotNull,
+                    ),
+                    is_identity: false,
+                },
+                ColumnInfo {
+                    name: "name",
+                    col_type: Char(
+                        StringAttr {
+                            length: Some(
+                                20,
+                            ),
+                        },
+                    ),
+                    default: None,
+                    generated: None,
+                    not_null: Some(
+                        NotNull,
+                    ),
+                    is_identity: false,
+                },
+                ColumnInfo {
+                    name: "last_update",
+                    col_type: Timestamp(
+                        TimeAttr {
+                            precision: Some(
+                                6,
+                            ),
+                        },
+                    ),
+                    default: Some(
+                        ColumnExpression(
+                            "now()",
+                        ),
+                    ),
+                    generated: None,
+                    not_null: Some(
+                        NotNull,
+                    ),
+                    is_identity: false,
+                },
+            ],
+            check_constraints: [
+                Check {
+                    name: "2200_20035_1_not_null",
+                    expr: "language_id IS NOT NULL",
+                    no_inherit: false,
+                },
+                Check {
+                    name: "2200_20035_2_not_null",
+                    expr: "name IS NOT NULL",
+                    no_inherit: false,
+                },
+                Check {
+                    name: "2200_20035_3_not_null",
+                    expr: "last_update IS NOT NULL",
+                    no_inherit: false,
+                },
+            ],
+            not_null_constraints: [],
+            unique_constraints: [],
+            primary_key_constraints: [
+                PrimaryKey {
+                    name: "language_pkey",
+                    columns: [
+                        "language_id",
+                    ],
+                },
+            ],
+            reference_constraints: [],
+            exclusion_constraints: [],
+            row_level_security: RowLevelSecurity {
+                enabled: false,
+                forced: false,
+            },
+            policies: [],
+        },
         TableDef {
             info: TableInfo {
                 name: "actor",
@@ -12,13 +111,14 @@ Schema {
                     col_type: Integer,
                     default: Some(
                         ColumnExpression(
-                            "nextval(\'actor_actor_id_seq\'::regclass)",
+                            "nextval('actor_actor_id_seq'::regclass)",
                         ),
                     ),
                     generated: None,
                     not_null: Some(
                         NotNull,
                     ),
+                    is_identity: false,
                 },
                 ColumnInfo {
                     name: "first_name",
@@ -34,6 +134,7 @@ Schema {
                     not_null: Some(
                         NotNull,
                     ),
+                    is_identity: false,
                 },
                 ColumnInfo {
                     name: "last_name",
@@ -49,6 +150,7 @@ Schema {
                     not_null: Some(
                         NotNull,
                     ),
+                    is_identity: false,
                 },
                 ColumnInfo {
                     name: "last_update",
@@ -68,26 +170,27 @@ Schema {
                     not_null: Some(
                         NotNull,
                     ),
+                    is_identity: false,
                 },
             ],
             check_constraints: [
                 Check {
-                    name: "2200_16388_1_not_null",
+                    name: "2200_19909_1_not_null",
                     expr: "actor_id IS NOT NULL",
                     no_inherit: false,
                 },
                 Check {
-                    name: "2200_16388_2_not_null",
+                    name: "2200_19909_2_not_null",
                     expr: "first_name IS NOT NULL",
                     no_inherit: false,
                 },
                 Check {
-                    name: "2200_16388_3_not_null",
+                    name: "2200_19909_3_not_null",
                     expr: "last_name IS NOT NULL",
                     no_inherit: false,
                 },
                 Check {
-                    name: "2200_16388_4_not_null",
+                    name: "2200_19909_4_not_null",
                     expr: "last_update IS NOT NULL",
                     no_inherit: false,
                 },
@@ -104,32 +207,38 @@ Schema {
             ],
             reference_constraints: [],
             exclusion_constraints: [],
+            row_level_security: RowLevelSecurity {
+                enabled: false,
+                forced: false,
+            },
+            policies: [],
         },
         TableDef {
             info: TableInfo {
-                name: "film",
+                name: "address",
                 of_type: None,
             },
             columns: [
                 ColumnInfo {
-                    name: "film_id",
+                    name: "address_id",
                     col_type: Integer,
                     default: Some(
                         ColumnExpression(
-                            "nextval(\'film_film_id_seq\'::regclass)",
+                            "nextval('address_address_id_seq'::regclass)",
                         ),
                     ),
                     generated: None,
                     not_null: Some(
                         NotNull,
                     ),
+                    is_identity: false,
                 },
                 ColumnInfo {
-                    name: "title",
+                    name: "address",
                     col_type: Varchar(
                         StringAttr {
                             length: Some(
-                                255,
+                                50,
                             ),
                         },
                     ),
@@ -138,113 +247,77 @@ Schema {
                     not_null: Some(
                         NotNull,
                     ),
+                    is_identity: false,
                 },
                 ColumnInfo {
-                    name: "description",
-                    col_type: Text,
-                    default: None,
-                    generated: None,
-                    not_null: None,
-                },
-                ColumnInfo {
-                    name: "release_year",
-                    col_type: Integer,
+                    name: "address2",
+                    col_type: Varchar(
+                        StringAttr {
+                            length: Some(
+                                50,
+                            ),
+                        },
+                    ),
                     default: None,
                     generated: None,
                     not_null: None,
+                    is_identity: false,
                 },
                 ColumnInfo {
-                    name: "language_id",
-                    col_type: SmallInt,
+                    name: "district",
+                    col_type: Varchar(
+                        StringAttr {
+                            length: Some(
+                                20,
+                            ),
+                        },
+                    ),
                     default: None,
                     generated: None,
                     not_null: Some(
                         NotNull,
                     ),
+                    is_identity: false,
                 },
                 ColumnInfo {
-                    name: "original_language_id",
+                    name: "city_id",
                     col_type: SmallInt,
                     default: None,
                     generated: None,
-                    not_null: None,
-                },
-                ColumnInfo {
-                    name: "rental_duration",
-                    col_type: SmallInt,
-                    default: Some(
-                        ColumnExpression(
-                            "3",
-                        ),
-                    ),
-                    generated: None,
                     not_null: Some(
                         NotNull,
                     ),
+                    is_identity: false,
                 },
                 ColumnInfo {
-                    name: "rental_rate",
-                    col_type: Numeric(
-                        ArbitraryPrecisionNumericAttr {
-                            precision: Some(
-                                4,
-                            ),
-                            scale: Some(
-                                2,
+                    name: "postal_code",
+                    col_type: Varchar(
+                        StringAttr {
+                            length: Some(
+                                10,
                             ),
                         },
                     ),
-                    default: Some(
-                        ColumnExpression(
-                            "4.99",
-                        ),
-                    ),
-                    generated: None,
-                    not_null: Some(
-                        NotNull,
-                    ),
-                },
-                ColumnInfo {
-                    name: "length",
-                    col_type: SmallInt,
                     default: None,
                     generated: None,
                     not_null: None,
+                    is_identity: false,
                 },
                 ColumnInfo {
-                    name: "replacement_cost",
-                    col_type: Numeric(
-                        ArbitraryPrecisionNumericAttr {
-                            precision: Some(
-                                5,
-                            ),
-                            scale: Some(
-                                2,
+                    name: "phone",
+                    col_type: Varchar(
+                        StringAttr {
+                            length: Some(
+                                20,
                             ),
                         },
                     ),
-                    default: Some(
-                        ColumnExpression(
-                            "19.99",
-                        ),
-                    ),
+                    default: None,
                     generated: None,
                     not_null: Some(
                         NotNull,
                     ),
-                },
-                ColumnInfo {
-                    name: "rating",
-                    col_type: Unknown(
-                        "USER-DEFINED",
-                    ),
-                    default: Some(
-                        ColumnExpression(
-                            "\'G\'::mpaa_rating",
-                        ),
-                    ),
-                    generated: None,
-                    not_null: None,
+                    is_identity: false,
                 },
                 ColumnInfo {
                     name: "last_update",
@@ -264,63 +337,38 @@ Schema {
                     not_null: Some(
                         NotNull,
                     ),
-                },
-                ColumnInfo {
-                    name: "special_features",
-                    col_type: Array,
-                    default: None,
-                    generated: None,
-                    not_null: None,
-                },
-                ColumnInfo {
-                    name: "fulltext",
-                    col_type: TsVector,
-                    default: None,
-                    generated: None,
-                    not_null: Some(
-                        NotNull,
-                    ),
+                    is_identity: false,
                 },
             ],
             check_constraints: [
                 Check {
-                    name: "2200_16419_10_not_null",
-                    expr: "replacement_cost IS NOT NULL",
-                    no_inherit: false,
-                },
-                Check {
-                    name: "2200_16419_12_not_null",
-                    expr: "last_update IS NOT NULL",
-                    no_inherit: false,
-                },
-                Check {
-                    name: "2200_16419_14_not_null",
-                    expr: "fulltext IS NOT NULL",
+                    name: "2200_19993_1_not_null",
+                    expr: "address_id IS NOT NULL",
                     no_inherit: false,
                 },
                 Check {
-                    name: "2200_16419_1_not_null",
-                    expr: "film_id IS NOT NULL",
+                    name: "2200_19993_2_not_null",
+                    expr: "address IS NOT NULL",
                     no_inherit: false,
                 },
                 Check {
-                    name: "2200_16419_2_not_null",
-                    expr: "title IS NOT NULL",
+                    name: "2200_19993_4_not_null",
+                    expr: "district IS NOT NULL",
                     no_inherit: false,
                 },
                 Check {
-                    name: "2200_16419_5_not_null",
-                    expr: "language_id IS NOT NULL",
+                    name: "2200_19993_5_not_null",
+                    expr: "city_id IS NOT NULL",
                     no_inherit: false,
                 },
                 Check {
-                    name: "2200_16419_7_not_null",
-                    expr: "rental_duration IS NOT NULL",
+                    name: "2200_19993_7_not_null",
+                    expr: "phone IS NOT NULL",
                     no_inherit: false,
                 },
                 Check {
-                    name: "2200_16419_8_not_null",
-                    expr: "rental_rate IS NOT NULL",
+                    name: "2200_19993_8_not_null",
+                    expr: "last_update IS NOT NULL",
                     no_inherit: false,
                 },
             ],
@@ -328,37 +376,22 @@ Schema {
             unique_constraints: [],
             primary_key_constraints: [
                 PrimaryKey {
-                    name: "film_pkey",
+                    name: "address_pkey",
                     columns: [
-                        "film_id",
+                        "address_id",
                     ],
                 },
             ],
             reference_constraints: [
                 References {
-                    name: "film_language_id_fkey",
-                    columns: [
-                        "language_id",
-                    ],
-                    table: "language",
-                    foreign_columns: [
-                        "language_id",
-                    ],
-                    on_update: Some(
-                        Cascade,
-                    ),
-                    on_delete: Some(
-                        Restrict,
-                    ),
-                },
-                References {
-                    name: "film_original_language_id_fkey",
+                    name: "address_city_id_fkey",
                     columns: [
-                        "original_language_id",
+                        "city_id",
                     ],
-                    table: "language",
+                    schema: None,
+                    table: "city",
                     foreign_columns: [
-                        "language_id",
+                        "city_id",
                     ],
                     on_update: Some(
                         Cascade,
@@ -369,62 +402,38 @@ Schema {
                 },
             ],
             exclusion_constraints: [],
+            row_level_security: RowLevelSecurity {
+                enabled: false,
+                forced: false,
+            },
+            policies: [],
         },
         TableDef {
             info: TableInfo {
-                name: "payment_p2007_02",
+                name: "category",
                 of_type: None,
             },
             columns: [
                 ColumnInfo {
-                    name: "payment_id",
+                    name: "category_id",
                     col_type: Integer,
                     default: Some(
                         ColumnExpression(
-                            "nextval(\'payment_payment_id_seq\'::regclass)",
+                            "nextval('category_category_id_seq'::regclass)",
                         ),
                     ),
                     generated: None,
                     not_null: Some(
                         NotNull,
                     ),
+                    is_identity: false,
                 },
                 ColumnInfo {
-                    name: "customer_id",
-                    col_type: SmallInt,
-                    default: None,
-                    generated: None,
-                    not_null: Some(
-                        NotNull,
-                    ),
-                },
-                ColumnInfo {
-                    name: "staff_id",
-                    col_type: SmallInt,
-                    default: None,
-                    generated: None,
-                    not_null: Some(
-                        NotNull,
-                    ),
-                },
-                ColumnInfo {
-                    name: "rental_id",
-                    col_type: Integer,
-                    default: None,
-                    generated: None,
-                    not_null: Some(
-                        NotNull,
-                    ),
-                },
-                ColumnInfo {
-                    name: "amount",
-                    col_type: Numeric(
-                        ArbitraryPrecisionNumericAttr {
-                            precision: Some(
-                                5,
-                            ),
-                            scale: Some(
-                                2,
+                    name: "name",
+                    col_type: Varchar(
+                        StringAttr {
+                            length: Some(
+                                25,
                             ),
                         },
                     ),
@@ -433,9 +442,10 @@ Schema {
                     not_null: Some(
                         NotNull,
                     ),
+                    is_identity: false,
                 },
                 ColumnInfo {
-                    name: "payment_date",
+                    name: "last_update",
                     col_type: Timestamp(
                         TimeAttr {
                             precision: Some(
@@ -443,171 +453,101 @@ Schema {
                             ),
                         },
                     ),
-                    default: None,
+                    default: Some(
+                        ColumnExpression(
+                            "now()",
+                        ),
+                    ),
                     generated: None,
                     not_null: Some(
                         NotNull,
                     ),
+                    is_identity: false,
                 },
             ],
             check_constraints: [
                 Check {
-                    name: "2200_16514_1_not_null",
-                    expr: "payment_id IS NOT NULL",
-                    no_inherit: false,
-                },
-                Check {
-                    name: "2200_16514_2_not_null",
-                    expr: "customer_id IS NOT NULL",
-                    no_inherit: false,
-                },
-                Check {
-                    name: "2200_16514_3_not_null",
-                    expr: "staff_id IS NOT NULL",
-                    no_inherit: false,
-                },
-                Check {
-                    name: "2200_16514_4_not_null",
-                    expr: "rental_id IS NOT NULL",
-                    no_inherit: false,
-                },
-                Check {
-                    name: "2200_16514_5_not_null",
-                    expr: "amount IS NOT NULL",
+                    name: "2200_19931_1_not_null",
+                    expr: "category_id IS NOT NULL",
                     no_inherit: false,
                 },
                 Check {
-                    name: "2200_16514_6_not_null",
-                    expr: "payment_date IS NOT NULL",
+                    name: "2200_19931_2_not_null",
+                    expr: "name IS NOT NULL",
                     no_inherit: false,
                 },
                 Check {
-                    name: "payment_p2007_02_payment_date_check",
-                    expr: "(((payment_date >= \'2007-02-01 00:00:00\'::timestamp without time zone) AND (payment_date < \'2007-03-01 00:00:00\'::timestamp without time zone)))",
+                    name: "2200_19931_3_not_null",
+                    expr: "last_update IS NOT NULL",
                     no_inherit: false,
                 },
             ],
             not_null_constraints: [],
             unique_constraints: [],
-            primary_key_constraints: [],
-            reference_constraints: [
-                References {
-                    name: "payment_p2007_02_customer_id_fkey",
-                    columns: [
-                        "customer_id",
-                    ],
-                    table: "customer",
-                    foreign_columns: [
-                        "customer_id",
-                    ],
-                    on_update: Some(
-                        NoAction,
-                    ),
-                    on_delete: Some(
-                        NoAction,
-                    ),
-                },
-                References {
-                    name: "payment_p2007_02_rental_id_fkey",
-                    columns: [
-                        "rental_id",
-                    ],
-                    table: "rental",
-                    foreign_columns: [
-                        "rental_id",
-                    ],
-                    on_update: Some(
-                        NoAction,
-                    ),
-                    on_delete: Some(
-                        NoAction,
-                    ),
-                },
-                References {
-                    name: "payment_p2007_02_staff_id_fkey",
+            primary_key_constraints: [
+                PrimaryKey {
+                    name: "category_pkey",
                     columns: [
-                        "staff_id",
-                    ],
-                    table: "staff",
-                    foreign_columns: [
-                        "staff_id",
+                        "category_id",
                     ],
-                    on_update: Some(
-                        NoAction,
-                    ),
-                    on_delete: Some(
-                        NoAction,
-                    ),
                 },
             ],
+            reference_constraints: [],
             exclusion_constraints: [],
+            row_level_security: RowLevelSecurity {
+                enabled: false,
+                forced: false,
+            },
+            policies: [],
         },
         TableDef {
             info: TableInfo {
-                name: "payment_p2007_03",
+                name: "city",
                 of_type: None,
             },
             columns: [
                 ColumnInfo {
-                    name: "payment_id",
+                    name: "city_id",
                     col_type: Integer,
                     default: Some(
                         ColumnExpression(
-                            "nextval(\'payment_payment_id_seq\'::regclass)",
+                            "nextval('city_city_id_seq'::regclass)",
                         ),
                     ),
                     generated: None,
                     not_null: Some(
                         NotNull,
                     ),
+                    is_identity: false,
                 },
                 ColumnInfo {
-                    name: "customer_id",
-                    col_type: SmallInt,
-                    default: None,
-                    generated: None,
-                    not_null: Some(
-                        NotNull,
-                    ),
-                },
-                ColumnInfo {
-                    name: "staff_id",
-                    col_type: SmallInt,
-                    default: None,
-                    generated: None,
-                    not_null: Some(
-                        NotNull,
+                    name: "city",
+                    col_type: Varchar(
+                        StringAttr {
+                            length: Some(
+                                50,
+                            ),
+                        },
                     ),
-                },
-                ColumnInfo {
-                    name: "rental_id",
-                    col_type: Integer,
                     default: None,
                     generated: None,
                     not_null: Some(
                         NotNull,
                     ),
+                    is_identity: false,
                 },
                 ColumnInfo {
-                    name: "amount",
-                    col_type: Numeric(
-                        ArbitraryPrecisionNumericAttr {
-                            precision: Some(
-                                5,
-                            ),
-                            scale: Some(
-                                2,
-                            ),
-                        },
-                    ),
+                    name: "country_id",
+                    col_type: SmallInt,
                     default: None,
                     generated: None,
                     not_null: Some(
                         NotNull,
                     ),
+                    is_identity: false,
                 },
                 ColumnInfo {
-                    name: "payment_date",
+                    name: "last_update",
                     col_type: Timestamp(
                         TimeAttr {
                             precision: Some(
@@ -615,171 +555,239 @@ Schema {
                             ),
                         },
                     ),
-                    default: None,
+                    default: Some(
+                        ColumnExpression(
+                            "now()",
+                        ),
+                    ),
                     generated: None,
                     not_null: Some(
                         NotNull,
                     ),
+                    is_identity: false,
                 },
             ],
             check_constraints: [
                 Check {
-                    name: "2200_16519_1_not_null",
-                    expr: "payment_id IS NOT NULL",
-                    no_inherit: false,
-                },
-                Check {
-                    name: "2200_16519_2_not_null",
-                    expr: "customer_id IS NOT NULL",
-                    no_inherit: false,
-                },
-                Check {
-                    name: "2200_16519_3_not_null",
-                    expr: "staff_id IS NOT NULL",
-                    no_inherit: false,
-                },
-                Check {
-                    name: "2200_16519_4_not_null",
-                    expr: "rental_id IS NOT NULL",
+                    name: "2200_19999_1_not_null",
+                    expr: "city_id IS NOT NULL",
                     no_inherit: false,
                 },
                 Check {
-                    name: "2200_16519_5_not_null",
-                    expr: "amount IS NOT NULL",
+                    name: "2200_19999_2_not_null",
+                    expr: "city IS NOT NULL",
                     no_inherit: false,
                 },
                 Check {
-                    name: "2200_16519_6_not_null",
-                    expr: "payment_date IS NOT NULL",
+                    name: "2200_19999_3_not_null",
+                    expr: "country_id IS NOT NULL",
                     no_inherit: false,
                 },
                 Check {
-                    name: "payment_p2007_03_payment_date_check",
-                    expr: "(((payment_date >= \'2007-03-01 00:00:00\'::timestamp without time zone) AND (payment_date < \'2007-04-01 00:00:00\'::timestamp without time zone)))",
+                    name: "2200_19999_4_not_null",
+                    expr: "last_update IS NOT NULL",
                     no_inherit: false,
                 },
             ],
             not_null_constraints: [],
             unique_constraints: [],
-            primary_key_constraints: [],
-            reference_constraints: [
-                References {
-                    name: "payment_p2007_03_customer_id_fkey",
-                    columns: [
-                        "customer_id",
-                    ],
-                    table: "customer",
-                    foreign_columns: [
-                        "customer_id",
-                    ],
-                    on_update: Some(
-                        NoAction,
-                    ),
-                    on_delete: Some(
-                        NoAction,
-                    ),
-                },
-                References {
-                    name: "payment_p2007_03_rental_id_fkey",
+            primary_key_constraints: [
+                PrimaryKey {
+                    name: "city_pkey",
                     columns: [
-                        "rental_id",
-                    ],
-                    table: "rental",
-                    foreign_columns: [
-                        "rental_id",
+                        "city_id",
                     ],
-                    on_update: Some(
-                        NoAction,
-                    ),
-                    on_delete: Some(
-                        NoAction,
-                    ),
                 },
+            ],
+            reference_constraints: [
                 References {
-                    name: "payment_p2007_03_staff_id_fkey",
+                    name: "city_country_id_fkey",
                     columns: [
-                        "staff_id",
+                        "country_id",
                     ],
-                    table: "staff",
+                    schema: None,
+                    table: "country",
                     foreign_columns: [
-                        "staff_id",
+                        "country_id",
                     ],
                     on_update: Some(
-                        NoAction,
+                        Cascade,
                     ),
                     on_delete: Some(
-                        NoAction,
+                        Restrict,
                     ),
                 },
             ],
             exclusion_constraints: [],
+            row_level_security: RowLevelSecurity {
+                enabled: false,
+                forced: false,
+            },
+            policies: [],
         },
         TableDef {
             info: TableInfo {
-                name: "payment_p2007_04",
+                name: "film",
                 of_type: None,
             },
             columns: [
                 ColumnInfo {
-                    name: "payment_id",
+                    name: "film_id",
                     col_type: Integer,
                     default: Some(
                         ColumnExpression(
-                            "nextval(\'payment_payment_id_seq\'::regclass)",
+                            "nextval('film_film_id_seq'::regclass)",
                         ),
                     ),
                     generated: None,
                     not_null: Some(
                         NotNull,
                     ),
+                    is_identity: false,
                 },
                 ColumnInfo {
-                    name: "customer_id",
-                    col_type: SmallInt,
+                    name: "title",
+                    col_type: Varchar(
+                        StringAttr {
+                            length: Some(
+                                255,
+                            ),
+                        },
+                    ),
                     default: None,
                     generated: None,
                     not_null: Some(
                         NotNull,
                     ),
+                    is_identity: false,
                 },
                 ColumnInfo {
-                    name: "staff_id",
-                    col_type: SmallInt,
+                    name: "description",
+                    col_type: Text,
                     default: None,
                     generated: None,
-                    not_null: Some(
-                        NotNull,
-                    ),
+                    not_null: None,
+                    is_identity: false,
                 },
                 ColumnInfo {
-                    name: "rental_id",
+                    name: "release_year",
                     col_type: Integer,
                     default: None,
                     generated: None,
-                    not_null: Some(
-                        NotNull,
+                    not_null: None,
+                    is_identity: false,
+                },
+                ColumnInfo {
+                    name: "language_id",
+                    col_type: SmallInt,
+                    default: None,
+                    generated: None,
+                    not_null: Some(
+                        NotNull,
                     ),
+                    is_identity: false,
                 },
                 ColumnInfo {
-                    name: "amount",
+                    name: "original_language_id",
+                    col_type: SmallInt,
+                    default: None,
+                    generated: None,
+                    not_null: None,
+                    is_identity: false,
+                },
+                ColumnInfo {
+                    name: "rental_duration",
+                    col_type: SmallInt,
+                    default: Some(
+                        ColumnExpression(
+                            "3",
+                        ),
+                    ),
+                    generated: None,
+                    not_null: Some(
+                        NotNull,
+                    ),
+                    is_identity: false,
+                },
+                ColumnInfo {
+                    name: "rental_rate",
                     col_type: Numeric(
                         ArbitraryPrecisionNumericAttr {
                             precision: Some(
-                                5,
+                                4,
                             ),
                             scale: Some(
                                 2,
                             ),
                         },
                     ),
+                    default: Some(
+                        ColumnExpression(
+                            "4.99",
+                        ),
+                    ),
+                    generated: None,
+                    not_null: Some(
+                        NotNull,
+                    ),
+                    is_identity: false,
+                },
+                ColumnInfo {
+                    name: "length",
+                    col_type: SmallInt,
                     default: None,
                     generated: None,
+                    not_null: None,
+                    is_identity: false,
+                },
+                ColumnInfo {
+                    name: "replacement_cost",
+                    col_type: Numeric(
+                        ArbitraryPrecisionNumericAttr {
+                            precision: Some(
+                                5,
+                            ),
+                            scale: Some(
+                                2,
+                            ),
+                        },
+                    ),
+                    default: Some(
+                        ColumnExpression(
+                            "19.99",
+                        ),
+                    ),
+                    generated: None,
                     not_null: Some(
                         NotNull,
                     ),
+                    is_identity: false,
                 },
                 ColumnInfo {
-                    name: "payment_date",
+                    name: "rating",
+                    col_type: Enum(
+                        EnumDef {
+                            values: [
+                                "G",
+                                "NC-17",
+                                "PG",
+                                "PG-13",
+                                "R",
+                            ],
+                            typename: "mpaa_rating",
+                        },
+                    ),
+                    default: Some(
+                        ColumnExpression(
+                            "'G'::mpaa_rating",
+                        ),
+                    ),
+                    generated: None,
+                    not_null: None,
+                    is_identity: false,
+                },
+                ColumnInfo {
+                    name: "last_update",
                     col_type: Timestamp(
                         TimeAttr {
                             precision: Some(
@@ -787,160 +795,168 @@ Schema {
                             ),
                         },
                     ),
+                    default: Some(
+                        ColumnExpression(
+                            "now()",
+                        ),
+                    ),
+                    generated: None,
+                    not_null: Some(
+                        NotNull,
+                    ),
+                    is_identity: false,
+                },
+                ColumnInfo {
+                    name: "special_features",
+                    col_type: Array(
+                        ArrayDef {
+                            col_type: Some(
+                                Text,
+                            ),
+                            dimensions: 1,
+                        },
+                    ),
+                    default: None,
+                    generated: None,
+                    not_null: None,
+                    is_identity: false,
+                },
+                ColumnInfo {
+                    name: "fulltext",
+                    col_type: TsVector,
                     default: None,
                     generated: None,
                     not_null: Some(
                         NotNull,
                     ),
+                    is_identity: false,
                 },
             ],
             check_constraints: [
                 Check {
-                    name: "2200_16524_1_not_null",
-                    expr: "payment_id IS NOT NULL",
+                    name: "2200_19937_10_not_null",
+                    expr: "replacement_cost IS NOT NULL",
                     no_inherit: false,
                 },
                 Check {
-                    name: "2200_16524_2_not_null",
-                    expr: "customer_id IS NOT NULL",
+                    name: "2200_19937_12_not_null",
+                    expr: "last_update IS NOT NULL",
                     no_inherit: false,
                 },
                 Check {
-                    name: "2200_16524_3_not_null",
-                    expr: "staff_id IS NOT NULL",
+                    name: "2200_19937_14_not_null",
+                    expr: "fulltext IS NOT NULL",
                     no_inherit: false,
                 },
                 Check {
-                    name: "2200_16524_4_not_null",
-                    expr: "rental_id IS NOT NULL",
+                    name: "2200_19937_1_not_null",
+                    expr: "film_id IS NOT NULL",
                     no_inherit: false,
                 },
                 Check {
-                    name: "2200_16524_5_not_null",
-                    expr: "amount IS NOT NULL",
+                    name: "2200_19937_2_not_null",
+                    expr: "title IS NOT NULL",
                     no_inherit: false,
                 },
                 Check {
-                    name: "2200_16524_6_not_null",
-                    expr: "payment_date IS NOT NULL",
+                    name: "2200_19937_5_not_null",
+                    expr: "language_id IS NOT NULL",
+                    no_inherit: false,
+                },
+                Check {
+                    name: "2200_19937_7_not_null",
+                    expr: "rental_duration IS NOT NULL",
                     no_inherit: false,
                 },
                 Check {
-                    name: "payment_p2007_04_payment_date_check",
-                    expr: "(((payment_date >= \'2007-04-01 00:00:00\'::timestamp without time zone) AND (payment_date < \'2007-05-01 00:00:00\'::timestamp without time zone)))",
+                    name: "2200_19937_8_not_null",
+                    expr: "rental_rate IS NOT NULL",
                     no_inherit: false,
                 },
             ],
             not_null_constraints: [],
             unique_constraints: [],
-            primary_key_constraints: [],
-            reference_constraints: [
-                References {
-                    name: "payment_p2007_04_customer_id_fkey",
+            primary_key_constraints: [
+                PrimaryKey {
+                    name: "film_pkey",
                     columns: [
-                        "customer_id",
-                    ],
-                    table: "customer",
-                    foreign_columns: [
-                        "customer_id",
+                        "film_id",
                     ],
-                    on_update: Some(
-                        NoAction,
-                    ),
-                    on_delete: Some(
-                        NoAction,
-                    ),
                 },
+            ],
+            reference_constraints: [
                 References {
-                    name: "payment_p2007_04_rental_id_fkey",
+                    name: "film_language_id_fkey",
                     columns: [
-                        "rental_id",
+                        "language_id",
+                        "language_id",
                     ],
-                    table: "rental",
+                    schema: None,
+                    table: "language",
                     foreign_columns: [
-                        "rental_id",
+                        "language_id",
+                        "language_id",
                     ],
                     on_update: Some(
-                        NoAction,
+                        Cascade,
                     ),
                     on_delete: Some(
-                        NoAction,
+                        Restrict,
                     ),
                 },
                 References {
-                    name: "payment_p2007_04_staff_id_fkey",
+                    name: "film_original_language_id_fkey",
                     columns: [
-                        "staff_id",
+                        "original_language_id",
+                        "original_language_id",
                     ],
-                    table: "staff",
+                    schema: None,
+                    table: "language",
                     foreign_columns: [
-                        "staff_id",
+                        "language_id",
+                        "language_id",
                     ],
                     on_update: Some(
-                        NoAction,
+                        Cascade,
                     ),
                     on_delete: Some(
-                        NoAction,
+                        Restrict,
                     ),
                 },
             ],
             exclusion_constraints: [],
+            row_level_security: RowLevelSecurity {
+                enabled: false,
+                forced: false,
+            },
+            policies: [],
         },
         TableDef {
             info: TableInfo {
-                name: "payment_p2007_05",
+                name: "country",
                 of_type: None,
             },
             columns: [
                 ColumnInfo {
-                    name: "payment_id",
+                    name: "country_id",
                     col_type: Integer,
                     default: Some(
                         ColumnExpression(
-                            "nextval(\'payment_payment_id_seq\'::regclass)",
+                            "nextval('country_country_id_seq'::regclass)",
                         ),
                     ),
                     generated: None,
                     not_null: Some(
                         NotNull,
                     ),
+                    is_identity: false,
                 },
                 ColumnInfo {
-                    name: "customer_id",
-                    col_type: SmallInt,
-                    default: None,
-                    generated: None,
-                    not_null: Some(
-                        NotNull,
-                    ),
-                },
-                ColumnInfo {
-                    name: "staff_id",
-                    col_type: SmallInt,
-                    default: None,
-                    generated: None,
-                    not_null: Some(
-                        NotNull,
-                    ),
-                },
-                ColumnInfo {
-                    name: "rental_id",
-                    col_type: Integer,
-                    default: None,
-                    generated: None,
-                    not_null: Some(
-                        NotNull,
-                    ),
-                },
-                ColumnInfo {
-                    name: "amount",
-                    col_type: Numeric(
-                        ArbitraryPrecisionNumericAttr {
-                            precision: Some(
-                                5,
-                            ),
-                            scale: Some(
-                                2,
+                    name: "country",
+                    col_type: Varchar(
+                        StringAttr {
+                            length: Some(
+                                50,
                             ),
                         },
                     ),
@@ -949,9 +965,10 @@ Schema {
                     not_null: Some(
                         NotNull,
                     ),
+                    is_identity: false,
                 },
                 ColumnInfo {
-                    name: "payment_date",
+                    name: "last_update",
                     col_type: Timestamp(
                         TimeAttr {
                             precision: Some(
@@ -959,171 +976,169 @@ Schema {
                             ),
                         },
                     ),
-                    default: None,
+                    default: Some(
+                        ColumnExpression(
+                            "now()",
+                        ),
+                    ),
                     generated: None,
                     not_null: Some(
                         NotNull,
                     ),
+                    is_identity: false,
                 },
             ],
             check_constraints: [
                 Check {
-                    name: "2200_16529_1_not_null",
-                    expr: "payment_id IS NOT NULL",
-                    no_inherit: false,
-                },
-                Check {
-                    name: "2200_16529_2_not_null",
-                    expr: "customer_id IS NOT NULL",
-                    no_inherit: false,
-                },
-                Check {
-                    name: "2200_16529_3_not_null",
-                    expr: "staff_id IS NOT NULL",
-                    no_inherit: false,
-                },
-                Check {
-                    name: "2200_16529_4_not_null",
-                    expr: "rental_id IS NOT NULL",
-                    no_inherit: false,
-                },
-                Check {
-                    name: "2200_16529_5_not_null",
-                    expr: "amount IS NOT NULL",
+                    name: "2200_20005_1_not_null",
+                    expr: "country_id IS NOT NULL",
                     no_inherit: false,
                 },
                 Check {
-                    name: "2200_16529_6_not_null",
-                    expr: "payment_date IS NOT NULL",
+                    name: "2200_20005_2_not_null",
+                    expr: "country IS NOT NULL",
                     no_inherit: false,
                 },
                 Check {
-                    name: "payment_p2007_05_payment_date_check",
-                    expr: "(((payment_date >= \'2007-05-01 00:00:00\'::timestamp without time zone) AND (payment_date < \'2007-06-01 00:00:00\'::timestamp without time zone)))",
+                    name: "2200_20005_3_not_null",
+                    expr: "last_update IS NOT NULL",
                     no_inherit: false,
                 },
             ],
             not_null_constraints: [],
             unique_constraints: [],
-            primary_key_constraints: [],
-            reference_constraints: [
-                References {
-                    name: "payment_p2007_05_customer_id_fkey",
+            primary_key_constraints: [
+                PrimaryKey {
+                    name: "country_pkey",
                     columns: [
-                        "customer_id",
-                    ],
-                    table: "customer",
-                    foreign_columns: [
-                        "customer_id",
+                        "country_id",
                     ],
-                    on_update: Some(
-                        NoAction,
-                    ),
-                    on_delete: Some(
-                        NoAction,
-                    ),
-                },
-                References {
-                    name: "payment_p2007_05_rental_id_fkey",
-                    columns: [
-                        "rental_id",
-                    ],
-                    table: "rental",
-                    foreign_columns: [
-                        "rental_id",
-                    ],
-                    on_update: Some(
-                        NoAction,
-                    ),
-                    on_delete: Some(
-                        NoAction,
-                    ),
-                },
-                References {
-                    name: "payment_p2007_05_staff_id_fkey",
-                    columns: [
-                        "staff_id",
-                    ],
-                    table: "staff",
-                    foreign_columns: [
-                        "staff_id",
-                    ],
-                    on_update: Some(
-                        NoAction,
-                    ),
-                    on_delete: Some(
-                        NoAction,
-                    ),
                 },
             ],
+            reference_constraints: [],
             exclusion_constraints: [],
+            row_level_security: RowLevelSecurity {
+                enabled: false,
+                forced: false,
+            },
+            policies: [],
         },
         TableDef {
             info: TableInfo {
-                name: "payment_p2007_06",
+                name: "customer",
                 of_type: None,
             },
             columns: [
                 ColumnInfo {
-                    name: "payment_id",
+                    name: "customer_id",
                     col_type: Integer,
                     default: Some(
                         ColumnExpression(
-                            "nextval(\'payment_payment_id_seq\'::regclass)",
+                            "nextval('customer_customer_id_seq'::regclass)",
                         ),
                     ),
                     generated: None,
                     not_null: Some(
                         NotNull,
                     ),
+                    is_identity: false,
                 },
                 ColumnInfo {
-                    name: "customer_id",
+                    name: "store_id",
                     col_type: SmallInt,
                     default: None,
                     generated: None,
                     not_null: Some(
                         NotNull,
                     ),
+                    is_identity: false,
                 },
                 ColumnInfo {
-                    name: "staff_id",
-                    col_type: SmallInt,
+                    name: "first_name",
+                    col_type: Varchar(
+                        StringAttr {
+                            length: Some(
+                                45,
+                            ),
+                        },
+                    ),
                     default: None,
                     generated: None,
                     not_null: Some(
                         NotNull,
                     ),
+                    is_identity: false,
                 },
                 ColumnInfo {
-                    name: "rental_id",
-                    col_type: Integer,
+                    name: "last_name",
+                    col_type: Varchar(
+                        StringAttr {
+                            length: Some(
+                                45,
+                            ),
+                        },
+                    ),
                     default: None,
                     generated: None,
                     not_null: Some(
                         NotNull,
                     ),
+                    is_identity: false,
                 },
                 ColumnInfo {
-                    name: "amount",
-                    col_type: Numeric(
-                        ArbitraryPrecisionNumericAttr {
-                            precision: Some(
-                                5,
-                            ),
-                            scale: Some(
-                                2,
+                    name: "email",
+                    col_type: Varchar(
+                        StringAttr {
+                            length: Some(
+                                50,
                             ),
                         },
                     ),
                     default: None,
                     generated: None,
+                    not_null: None,
+                    is_identity: false,
+                },
+                ColumnInfo {
+                    name: "address_id",
+                    col_type: SmallInt,
+                    default: None,
+                    generated: None,
                     not_null: Some(
                         NotNull,
                     ),
+                    is_identity: false,
                 },
                 ColumnInfo {
-                    name: "payment_date",
+                    name: "activebool",
+                    col_type: Boolean,
+                    default: Some(
+                        ColumnExpression(
+                            "true",
+                        ),
+                    ),
+                    generated: None,
+                    not_null: Some(
+                        NotNull,
+                    ),
+                    is_identity: false,
+                },
+                ColumnInfo {
+                    name: "create_date",
+                    col_type: Date,
+                    default: Some(
+                        ColumnExpression(
+                            "('now'::text)::date",
+                        ),
+                    ),
+                    generated: None,
+                    not_null: Some(
+                        NotNull,
+                    ),
+                    is_identity: false,
+                },
+                ColumnInfo {
+                    name: "last_update",
                     col_type: Timestamp(
                         TimeAttr {
                             precision: Some(
@@ -1131,171 +1146,142 @@ Schema {
                             ),
                         },
                     ),
+                    default: Some(
+                        ColumnExpression(
+                            "now()",
+                        ),
+                    ),
+                    generated: None,
+                    not_null: None,
+                    is_identity: false,
+                },
+                ColumnInfo {
+                    name: "active",
+                    col_type: Integer,
                     default: None,
                     generated: None,
-                    not_null: Some(
-                        NotNull,
-                    ),
+                    not_null: None,
+                    is_identity: false,
                 },
             ],
             check_constraints: [
                 Check {
-                    name: "2200_16534_1_not_null",
-                    expr: "payment_id IS NOT NULL",
+                    name: "2200_20011_1_not_null",
+                    expr: "customer_id IS NOT NULL",
                     no_inherit: false,
                 },
                 Check {
-                    name: "2200_16534_2_not_null",
-                    expr: "customer_id IS NOT NULL",
+                    name: "2200_20011_2_not_null",
+                    expr: "store_id IS NOT NULL",
                     no_inherit: false,
                 },
                 Check {
-                    name: "2200_16534_3_not_null",
-                    expr: "staff_id IS NOT NULL",
+                    name: "2200_20011_3_not_null",
+                    expr: "first_name IS NOT NULL",
                     no_inherit: false,
                 },
                 Check {
-                    name: "2200_16534_4_not_null",
-                    expr: "rental_id IS NOT NULL",
+                    name: "2200_20011_4_not_null",
+                    expr: "last_name IS NOT NULL",
                     no_inherit: false,
                 },
                 Check {
-                    name: "2200_16534_5_not_null",
-                    expr: "amount IS NOT NULL",
+                    name: "2200_20011_6_not_null",
+                    expr: "address_id IS NOT NULL",
                     no_inherit: false,
                 },
                 Check {
-                    name: "2200_16534_6_not_null",
-                    expr: "payment_date IS NOT NULL",
+                    name: "2200_20011_7_not_null",
+                    expr: "activebool IS NOT NULL",
                     no_inherit: false,
                 },
                 Check {
-                    name: "payment_p2007_06_payment_date_check",
-                    expr: "(((payment_date >= \'2007-06-01 00:00:00\'::timestamp without time zone) AND (payment_date < \'2007-07-01 00:00:00\'::timestamp without time zone)))",
+                    name: "2200_20011_8_not_null",
+                    expr: "create_date IS NOT NULL",
                     no_inherit: false,
                 },
             ],
             not_null_constraints: [],
             unique_constraints: [],
-            primary_key_constraints: [],
-            reference_constraints: [
-                References {
-                    name: "payment_p2007_06_customer_id_fkey",
+            primary_key_constraints: [
+                PrimaryKey {
+                    name: "customer_pkey",
                     columns: [
                         "customer_id",
                     ],
-                    table: "customer",
-                    foreign_columns: [
-                        "customer_id",
-                    ],
-                    on_update: Some(
-                        NoAction,
-                    ),
-                    on_delete: Some(
-                        NoAction,
-                    ),
                 },
+            ],
+            reference_constraints: [
                 References {
-                    name: "payment_p2007_06_rental_id_fkey",
+                    name: "customer_address_id_fkey",
                     columns: [
-                        "rental_id",
+                        "address_id",
                     ],
-                    table: "rental",
+                    schema: None,
+                    table: "address",
                     foreign_columns: [
-                        "rental_id",
+                        "address_id",
                     ],
                     on_update: Some(
-                        NoAction,
+                        Cascade,
                     ),
                     on_delete: Some(
-                        NoAction,
+                        Restrict,
                     ),
                 },
                 References {
-                    name: "payment_p2007_06_staff_id_fkey",
+                    name: "customer_store_id_fkey",
                     columns: [
-                        "staff_id",
+                        "store_id",
                     ],
-                    table: "staff",
+                    schema: None,
+                    table: "store",
                     foreign_columns: [
-                        "staff_id",
+                        "store_id",
                     ],
                     on_update: Some(
-                        NoAction,
+                        Cascade,
                     ),
                     on_delete: Some(
-                        NoAction,
+                        Restrict,
                     ),
                 },
             ],
             exclusion_constraints: [],
+            row_level_security: RowLevelSecurity {
+                enabled: false,
+                forced: false,
+            },
+            policies: [],
         },
         TableDef {
             info: TableInfo {
-                name: "payment_p2007_01",
+                name: "film_actor",
                 of_type: None,
             },
             columns: [
                 ColumnInfo {
-                    name: "payment_id",
-                    col_type: Integer,
-                    default: Some(
-                        ColumnExpression(
-                            "nextval(\'payment_payment_id_seq\'::regclass)",
-                        ),
-                    ),
-                    generated: None,
-                    not_null: Some(
-                        NotNull,
-                    ),
-                },
-                ColumnInfo {
-                    name: "customer_id",
+                    name: "actor_id",
                     col_type: SmallInt,
                     default: None,
                     generated: None,
                     not_null: Some(
                         NotNull,
                     ),
+                    is_identity: false,
                 },
                 ColumnInfo {
-                    name: "staff_id",
+                    name: "film_id",
                     col_type: SmallInt,
                     default: None,
                     generated: None,
                     not_null: Some(
                         NotNull,
                     ),
+                    is_identity: false,
                 },
                 ColumnInfo {
-                    name: "rental_id",
-                    col_type: Integer,
-                    default: None,
-                    generated: None,
-                    not_null: Some(
-                        NotNull,
-                    ),
-                },
-                ColumnInfo {
-                    name: "amount",
-                    col_type: Numeric(
-                        ArbitraryPrecisionNumericAttr {
-                            precision: Some(
-                                5,
-                            ),
-                            scale: Some(
-                                2,
-                            ),
-                        },
-                    ),
-                    default: None,
-                    generated: None,
-                    not_null: Some(
-                        NotNull,
-                    ),
-                },
-                ColumnInfo {
-                    name: "payment_date",
+                    name: "last_update",
                     col_type: Timestamp(
                         TimeAttr {
                             precision: Some(
@@ -1303,203 +1289,97 @@ Schema {
                             ),
                         },
                     ),
-                    default: None,
+                    default: Some(
+                        ColumnExpression(
+                            "now()",
+                        ),
+                    ),
                     generated: None,
                     not_null: Some(
                         NotNull,
                     ),
+                    is_identity: false,
                 },
             ],
             check_constraints: [
                 Check {
-                    name: "2200_16509_1_not_null",
-                    expr: "payment_id IS NOT NULL",
-                    no_inherit: false,
-                },
-                Check {
-                    name: "2200_16509_2_not_null",
-                    expr: "customer_id IS NOT NULL",
-                    no_inherit: false,
-                },
-                Check {
-                    name: "2200_16509_3_not_null",
-                    expr: "staff_id IS NOT NULL",
-                    no_inherit: false,
-                },
-                Check {
-                    name: "2200_16509_4_not_null",
-                    expr: "rental_id IS NOT NULL",
-                    no_inherit: false,
-                },
-                Check {
-                    name: "2200_16509_5_not_null",
-                    expr: "amount IS NOT NULL",
+                    name: "2200_19979_1_not_null",
+                    expr: "actor_id IS NOT NULL",
                     no_inherit: false,
                 },
                 Check {
-                    name: "2200_16509_6_not_null",
-                    expr: "payment_date IS NOT NULL",
+                    name: "2200_19979_2_not_null",
+                    expr: "film_id IS NOT NULL",
                     no_inherit: false,
                 },
                 Check {
-                    name: "payment_p2007_01_payment_date_check",
-                    expr: "(((payment_date >= \'2007-01-01 00:00:00\'::timestamp without time zone) AND (payment_date < \'2007-02-01 00:00:00\'::timestamp without time zone)))",
+                    name: "2200_19979_3_not_null",
+                    expr: "last_update IS NOT NULL",
                     no_inherit: false,
                 },
             ],
             not_null_constraints: [],
             unique_constraints: [],
-            primary_key_constraints: [],
+            primary_key_constraints: [
+                PrimaryKey {
+                    name: "film_actor_pkey",
+                    columns: [
+                        "actor_id",
+                        "film_id",
+                    ],
+                },
+            ],
             reference_constraints: [
                 References {
-                    name: "payment_p2007_01_customer_id_fkey",
+                    name: "film_actor_actor_id_fkey",
                     columns: [
-                        "customer_id",
+                        "actor_id",
                     ],
-                    table: "customer",
+                    schema: None,
+                    table: "actor",
                     foreign_columns: [
-                        "customer_id",
-                    ],
-                    on_update: Some(
-                        NoAction,
-                    ),
-                    on_delete: Some(
-                        NoAction,
-                    ),
-                },
-                References {
-                    name: "payment_p2007_01_rental_id_fkey",
-                    columns: [
-                        "rental_id",
-                    ],
-                    table: "rental",
-                    foreign_columns: [
-                        "rental_id",
-                    ],
-                    on_update: Some(
-                        NoAction,
-                    ),
-                    on_delete: Some(
-                        NoAction,
-                    ),
-                },
-                References {
-                    name: "payment_p2007_01_staff_id_fkey",
-                    columns: [
-                        "staff_id",
-                    ],
-                    table: "staff",
-                    foreign_columns: [
-                        "staff_id",
+                        "actor_id",
                     ],
                     on_update: Some(
-                        NoAction,
+                        Cascade,
                     ),
                     on_delete: Some(
-                        NoAction,
+                        Restrict,
                     ),
                 },
             ],
             exclusion_constraints: [],
+            row_level_security: RowLevelSecurity {
+                enabled: false,
+                forced: false,
+            },
+            policies: [],
         },
         TableDef {
             info: TableInfo {
-                name: "address",
+                name: "film_category",
                 of_type: None,
             },
             columns: [
                 ColumnInfo {
-                    name: "address_id",
-                    col_type: Integer,
-                    default: Some(
-                        ColumnExpression(
-                            "nextval(\'address_address_id_seq\'::regclass)",
-                        ),
-                    ),
-                    generated: None,
-                    not_null: Some(
-                        NotNull,
-                    ),
-                },
-                ColumnInfo {
-                    name: "address",
-                    col_type: Varchar(
-                        StringAttr {
-                            length: Some(
-                                50,
-                            ),
-                        },
-                    ),
-                    default: None,
-                    generated: None,
-                    not_null: Some(
-                        NotNull,
-                    ),
-                },
-                ColumnInfo {
-                    name: "address2",
-                    col_type: Varchar(
-                        StringAttr {
-                            length: Some(
-                                50,
-                            ),
-                        },
-                    ),
-                    default: None,
-                    generated: None,
-                    not_null: None,
-                },
-                ColumnInfo {
-                    name: "district",
-                    col_type: Varchar(
-                        StringAttr {
-                            length: Some(
-                                20,
-                            ),
-                        },
-                    ),
-                    default: None,
-                    generated: None,
-                    not_null: Some(
-                        NotNull,
-                    ),
-                },
-                ColumnInfo {
-                    name: "city_id",
+                    name: "film_id",
                     col_type: SmallInt,
                     default: None,
                     generated: None,
                     not_null: Some(
                         NotNull,
                     ),
+                    is_identity: false,
                 },
                 ColumnInfo {
-                    name: "postal_code",
-                    col_type: Varchar(
-                        StringAttr {
-                            length: Some(
-                                10,
-                            ),
-                        },
-                    ),
-                    default: None,
-                    generated: None,
-                    not_null: None,
-                },
-                ColumnInfo {
-                    name: "phone",
-                    col_type: Varchar(
-                        StringAttr {
-                            length: Some(
-                                20,
-                            ),
-                        },
-                    ),
+                    name: "category_id",
+                    col_type: SmallInt,
                     default: None,
                     generated: None,
                     not_null: Some(
                         NotNull,
                     ),
+                    is_identity: false,
                 },
                 ColumnInfo {
                     name: "last_update",
@@ -1519,36 +1399,22 @@ Schema {
                     not_null: Some(
                         NotNull,
                     ),
+                    is_identity: false,
                 },
             ],
             check_constraints: [
                 Check {
-                    name: "2200_16446_1_not_null",
-                    expr: "address_id IS NOT NULL",
-                    no_inherit: false,
-                },
-                Check {
-                    name: "2200_16446_2_not_null",
-                    expr: "address IS NOT NULL",
-                    no_inherit: false,
-                },
-                Check {
-                    name: "2200_16446_4_not_null",
-                    expr: "district IS NOT NULL",
-                    no_inherit: false,
-                },
-                Check {
-                    name: "2200_16446_5_not_null",
-                    expr: "city_id IS NOT NULL",
+                    name: "2200_19983_1_not_null",
+                    expr: "film_id IS NOT NULL",
                     no_inherit: false,
                 },
                 Check {
-                    name: "2200_16446_7_not_null",
-                    expr: "phone IS NOT NULL",
+                    name: "2200_19983_2_not_null",
+                    expr: "category_id IS NOT NULL",
                     no_inherit: false,
                 },
                 Check {
-                    name: "2200_16446_8_not_null",
+                    name: "2200_19983_3_not_null",
                     expr: "last_update IS NOT NULL",
                     no_inherit: false,
                 },
@@ -1557,21 +1423,23 @@ Schema {
             unique_constraints: [],
             primary_key_constraints: [
                 PrimaryKey {
-                    name: "address_pkey",
+                    name: "film_category_pkey",
                     columns: [
-                        "address_id",
+                        "film_id",
+                        "category_id",
                     ],
                 },
             ],
             reference_constraints: [
                 References {
-                    name: "address_city_id_fkey",
+                    name: "film_category_category_id_fkey",
                     columns: [
-                        "city_id",
+                        "category_id",
                     ],
-                    table: "city",
+                    schema: None,
+                    table: "category",
                     foreign_columns: [
-                        "city_id",
+                        "category_id",
                     ],
                     on_update: Some(
                         Cascade,
@@ -1582,40 +1450,51 @@ Schema {
                 },
             ],
             exclusion_constraints: [],
+            row_level_security: RowLevelSecurity {
+                enabled: false,
+                forced: false,
+            },
+            policies: [],
         },
         TableDef {
             info: TableInfo {
-                name: "category",
+                name: "inventory",
                 of_type: None,
             },
             columns: [
                 ColumnInfo {
-                    name: "category_id",
+                    name: "inventory_id",
                     col_type: Integer,
                     default: Some(
                         ColumnExpression(
-                            "nextval(\'category_category_id_seq\'::regclass)",
+                            "nextval('inventory_inventory_id_seq'::regclass)",
                         ),
                     ),
                     generated: None,
                     not_null: Some(
                         NotNull,
                     ),
+                    is_identity: false,
                 },
                 ColumnInfo {
-                    name: "name",
-                    col_type: Varchar(
-                        StringAttr {
-                            length: Some(
-                                25,
-                            ),
-                        },
+                    name: "film_id",
+                    col_type: SmallInt,
+                    default: None,
+                    generated: None,
+                    not_null: Some(
+                        NotNull,
                     ),
+                    is_identity: false,
+                },
+                ColumnInfo {
+                    name: "store_id",
+                    col_type: SmallInt,
                     default: None,
                     generated: None,
                     not_null: Some(
                         NotNull,
                     ),
+                    is_identity: false,
                 },
                 ColumnInfo {
                     name: "last_update",
@@ -1635,21 +1514,27 @@ Schema {
                     not_null: Some(
                         NotNull,
                     ),
+                    is_identity: false,
                 },
             ],
             check_constraints: [
                 Check {
-                    name: "2200_16412_1_not_null",
-                    expr: "category_id IS NOT NULL",
+                    name: "2200_20029_1_not_null",
+                    expr: "inventory_id IS NOT NULL",
                     no_inherit: false,
                 },
                 Check {
-                    name: "2200_16412_2_not_null",
-                    expr: "name IS NOT NULL",
+                    name: "2200_20029_2_not_null",
+                    expr: "film_id IS NOT NULL",
+                    no_inherit: false,
+                },
+                Check {
+                    name: "2200_20029_3_not_null",
+                    expr: "store_id IS NOT NULL",
                     no_inherit: false,
                 },
                 Check {
-                    name: "2200_16412_3_not_null",
+                    name: "2200_20029_4_not_null",
                     expr: "last_update IS NOT NULL",
                     no_inherit: false,
                 },
@@ -1658,40 +1543,64 @@ Schema {
             unique_constraints: [],
             primary_key_constraints: [
                 PrimaryKey {
-                    name: "category_pkey",
+                    name: "inventory_pkey",
                     columns: [
-                        "category_id",
+                        "inventory_id",
                     ],
                 },
             ],
-            reference_constraints: [],
+            reference_constraints: [
+                References {
+                    name: "inventory_store_id_fkey",
+                    columns: [
+                        "store_id",
+                    ],
+                    schema: None,
+                    table: "store",
+                    foreign_columns: [
+                        "store_id",
+                    ],
+                    on_update: Some(
+                        Cascade,
+                    ),
+                    on_delete: Some(
+                        Restrict,
+                    ),
+                },
+            ],
             exclusion_constraints: [],
+            row_level_security: RowLevelSecurity {
+                enabled: false,
+                forced: false,
+            },
+            policies: [],
         },
         TableDef {
             info: TableInfo {
-                name: "city",
+                name: "rental",
                 of_type: None,
             },
             columns: [
                 ColumnInfo {
-                    name: "city_id",
+                    name: "rental_id",
                     col_type: Integer,
                     default: Some(
                         ColumnExpression(
-                            "nextval(\'city_city_id_seq\'::regclass)",
+                            "nextval('rental_rental_id_seq'::regclass)",
                         ),
                     ),
                     generated: None,
                     not_null: Some(
                         NotNull,
                     ),
+                    is_identity: false,
                 },
                 ColumnInfo {
-                    name: "city",
-                    col_type: Varchar(
-                        StringAttr {
-                            length: Some(
-                                50,
+                    name: "rental_date",
+                    col_type: Timestamp(
+                        TimeAttr {
+                            precision: Some(
+                                6,
                             ),
                         },
                     ),
@@ -1700,15 +1609,51 @@ Schema {
                     not_null: Some(
                         NotNull,
                     ),
+                    is_identity: false,
                 },
                 ColumnInfo {
-                    name: "country_id",
+                    name: "inventory_id",
+                    col_type: Integer,
+                    default: None,
+                    generated: None,
+                    not_null: Some(
+                        NotNull,
+                    ),
+                    is_identity: false,
+                },
+                ColumnInfo {
+                    name: "customer_id",
+                    col_type: SmallInt,
+                    default: None,
+                    generated: None,
+                    not_null: Some(
+                        NotNull,
+                    ),
+                    is_identity: false,
+                },
+                ColumnInfo {
+                    name: "return_date",
+                    col_type: Timestamp(
+                        TimeAttr {
+                            precision: Some(
+                                6,
+                            ),
+                        },
+                    ),
+                    default: None,
+                    generated: None,
+                    not_null: None,
+                    is_identity: false,
+                },
+                ColumnInfo {
+                    name: "staff_id",
                     col_type: SmallInt,
                     default: None,
                     generated: None,
                     not_null: Some(
                         NotNull,
                     ),
+                    is_identity: false,
                 },
                 ColumnInfo {
                     name: "last_update",
@@ -1728,49 +1673,70 @@ Schema {
                     not_null: Some(
                         NotNull,
                     ),
+                    is_identity: false,
                 },
             ],
             check_constraints: [
                 Check {
-                    name: "2200_16453_1_not_null",
-                    expr: "city_id IS NOT NULL",
+                    name: "2200_20081_1_not_null",
+                    expr: "rental_id IS NOT NULL",
                     no_inherit: false,
                 },
                 Check {
-                    name: "2200_16453_2_not_null",
-                    expr: "city IS NOT NULL",
+                    name: "2200_20081_2_not_null",
+                    expr: "rental_date IS NOT NULL",
                     no_inherit: false,
                 },
                 Check {
-                    name: "2200_16453_3_not_null",
-                    expr: "country_id IS NOT NULL",
+                    name: "2200_20081_3_not_null",
+                    expr: "inventory_id IS NOT NULL",
                     no_inherit: false,
                 },
                 Check {
-                    name: "2200_16453_4_not_null",
-                    expr: "last_update IS NOT NULL",
+                    name: "2200_20081_4_not_null",
+                    expr: "customer_id IS NOT NULL",
+                    no_inherit: false,
+                },
+                Check {
+                    name: "2200_20081_6_not_null",
+                    expr: "staff_id IS NOT NULL",
+                    no_inherit: false,
+                },
+                Check {
+                    name: "2200_20081_7_not_null",
+                    expr: "last_update IS NOT NULL",
                     no_inherit: false,
                 },
             ],
             not_null_constraints: [],
-            unique_constraints: [],
+            unique_constraints: [
+                Unique {
+                    name: "idx_unq_rental_rental_date_inventory_id_customer_id",
+                    columns: [
+                        "customer_id",
+                        "inventory_id",
+                        "rental_date",
+                    ],
+                },
+            ],
             primary_key_constraints: [
                 PrimaryKey {
-                    name: "city_pkey",
+                    name: "rental_pkey",
                     columns: [
-                        "city_id",
+                        "rental_id",
                     ],
                 },
             ],
             reference_constraints: [
                 References {
-                    name: "city_country_id_fkey",
+                    name: "rental_customer_id_fkey",
                     columns: [
-                        "country_id",
+                        "customer_id",
                     ],
-                    table: "country",
+                    schema: None,
+                    table: "customer",
                     foreign_columns: [
-                        "country_id",
+                        "customer_id",
                     ],
                     on_update: Some(
                         Cascade,
@@ -1779,120 +1745,67 @@ Schema {
                         Restrict,
                     ),
                 },
-            ],
-            exclusion_constraints: [],
-        },
-        TableDef {
-            info: TableInfo {
-                name: "country",
-                of_type: None,
-            },
-            columns: [
-                ColumnInfo {
-                    name: "country_id",
-                    col_type: Integer,
-                    default: Some(
-                        ColumnExpression(
-                            "nextval(\'country_country_id_seq\'::regclass)",
-                        ),
-                    ),
-                    generated: None,
-                    not_null: Some(
-                        NotNull,
-                    ),
-                },
-                ColumnInfo {
-                    name: "country",
-                    col_type: Varchar(
-                        StringAttr {
-                            length: Some(
-                                50,
-                            ),
-                        },
-                    ),
-                    default: None,
-                    generated: None,
-                    not_null: Some(
-                        NotNull,
-                    ),
-                },
-                ColumnInfo {
-                    name: "last_update",
-                    col_type: Timestamp(
-                        TimeAttr {
-                            precision: Some(
-                                6,
-                            ),
-                        },
-                    ),
-                    default: Some(
-                        ColumnExpression(
-                            "now()",
-                        ),
+                References {
+                    name: "rental_inventory_id_fkey",
+                    columns: [
+                        "inventory_id",
+                    ],
+                    schema: None,
+                    table: "inventory",
+                    foreign_columns: [
+                        "inventory_id",
+                    ],
+                    on_update: Some(
+                        Cascade,
                     ),
-                    generated: None,
-                    not_null: Some(
-                        NotNull,
+                    on_delete: Some(
+                        Restrict,
                     ),
                 },
-            ],
-            check_constraints: [
-                Check {
-                    name: "2200_16460_1_not_null",
-                    expr: "country_id IS NOT NULL",
-                    no_inherit: false,
-                },
-                Check {
-                    name: "2200_16460_2_not_null",
-                    expr: "country IS NOT NULL",
-                    no_inherit: false,
-                },
-                Check {
-                    name: "2200_16460_3_not_null",
-                    expr: "last_update IS NOT NULL",
-                    no_inherit: false,
-                },
-            ],
-            not_null_constraints: [],
-            unique_constraints: [],
-            primary_key_constraints: [
-                PrimaryKey {
-                    name: "country_pkey",
+                References {
+                    name: "rental_staff_id_fkey",
                     columns: [
-                        "country_id",
+                        "staff_id",
+                    ],
+                    schema: None,
+                    table: "staff",
+                    foreign_columns: [
+                        "staff_id",
                     ],
+                    on_update: Some(
+                        Cascade,
+                    ),
+                    on_delete: Some(
+                        Restrict,
+                    ),
                 },
             ],
-            reference_constraints: [],
             exclusion_constraints: [],
+            row_level_security: RowLevelSecurity {
+                enabled: false,
+                forced: false,
+            },
+            policies: [],
         },
         TableDef {
             info: TableInfo {
-                name: "customer",
+                name: "staff",
                 of_type: None,
             },
             columns: [
                 ColumnInfo {
-                    name: "customer_id",
+                    name: "staff_id",
                     col_type: Integer,
                     default: Some(
                         ColumnExpression(
-                            "nextval(\'customer_customer_id_seq\'::regclass)",
+                            "nextval('staff_staff_id_seq'::regclass)",
                         ),
                     ),
                     generated: None,
                     not_null: Some(
                         NotNull,
                     ),
-                },
-                ColumnInfo {
-                    name: "store_id",
-                    col_type: SmallInt,
-                    default: None,
-                    generated: None,
-                    not_null: Some(
-                        NotNull,
-                    ),
+                    is_identity: false,
                 },
                 ColumnInfo {
                     name: "first_name",
@@ -1908,6 +1821,7 @@ Schema {
                     not_null: Some(
                         NotNull,
                     ),
+                    is_identity: false,
                 },
                 ColumnInfo {
                     name: "last_name",
@@ -1923,6 +1837,17 @@ Schema {
                     not_null: Some(
                         NotNull,
                     ),
+                    is_identity: false,
+                },
+                ColumnInfo {
+                    name: "address_id",
+                    col_type: SmallInt,
+                    default: None,
+                    generated: None,
+                    not_null: Some(
+                        NotNull,
+                    ),
+                    is_identity: false,
                 },
                 ColumnInfo {
                     name: "email",
@@ -1936,18 +1861,20 @@ Schema {
                     default: None,
                     generated: None,
                     not_null: None,
+                    is_identity: false,
                 },
                 ColumnInfo {
-                    name: "address_id",
+                    name: "store_id",
                     col_type: SmallInt,
                     default: None,
                     generated: None,
                     not_null: Some(
                         NotNull,
                     ),
+                    is_identity: false,
                 },
                 ColumnInfo {
-                    name: "activebool",
+                    name: "active",
                     col_type: Boolean,
                     default: Some(
                         ColumnExpression(
@@ -1958,19 +1885,37 @@ Schema {
                     not_null: Some(
                         NotNull,
                     ),
+                    is_identity: false,
                 },
                 ColumnInfo {
-                    name: "create_date",
-                    col_type: Date,
-                    default: Some(
-                        ColumnExpression(
-                            "(\'now\'::text)::date",
-                        ),
+                    name: "username",
+                    col_type: Varchar(
+                        StringAttr {
+                            length: Some(
+                                16,
+                            ),
+                        },
                     ),
+                    default: None,
                     generated: None,
                     not_null: Some(
                         NotNull,
                     ),
+                    is_identity: false,
+                },
+                ColumnInfo {
+                    name: "password",
+                    col_type: Varchar(
+                        StringAttr {
+                            length: Some(
+                                40,
+                            ),
+                        },
+                    ),
+                    default: None,
+                    generated: None,
+                    not_null: None,
+                    is_identity: false,
                 },
                 ColumnInfo {
                     name: "last_update",
@@ -1987,50 +1932,59 @@ Schema {
                         ),
                     ),
                     generated: None,
-                    not_null: None,
+                    not_null: Some(
+                        NotNull,
+                    ),
+                    is_identity: false,
                 },
                 ColumnInfo {
-                    name: "active",
-                    col_type: Integer,
+                    name: "picture",
+                    col_type: Bytea,
                     default: None,
                     generated: None,
                     not_null: None,
+                    is_identity: false,
                 },
             ],
             check_constraints: [
                 Check {
-                    name: "2200_16467_1_not_null",
-                    expr: "customer_id IS NOT NULL",
+                    name: "2200_20092_10_not_null",
+                    expr: "last_update IS NOT NULL",
                     no_inherit: false,
                 },
                 Check {
-                    name: "2200_16467_2_not_null",
-                    expr: "store_id IS NOT NULL",
+                    name: "2200_20092_1_not_null",
+                    expr: "staff_id IS NOT NULL",
                     no_inherit: false,
                 },
                 Check {
-                    name: "2200_16467_3_not_null",
+                    name: "2200_20092_2_not_null",
                     expr: "first_name IS NOT NULL",
                     no_inherit: false,
                 },
                 Check {
-                    name: "2200_16467_4_not_null",
+                    name: "2200_20092_3_not_null",
                     expr: "last_name IS NOT NULL",
                     no_inherit: false,
                 },
                 Check {
-                    name: "2200_16467_6_not_null",
+                    name: "2200_20092_4_not_null",
                     expr: "address_id IS NOT NULL",
                     no_inherit: false,
                 },
                 Check {
-                    name: "2200_16467_7_not_null",
-                    expr: "activebool IS NOT NULL",
+                    name: "2200_20092_6_not_null",
+                    expr: "store_id IS NOT NULL",
                     no_inherit: false,
                 },
                 Check {
-                    name: "2200_16467_8_not_null",
-                    expr: "create_date IS NOT NULL",
+                    name: "2200_20092_7_not_null",
+                    expr: "active IS NOT NULL",
+                    no_inherit: false,
+                },
+                Check {
+                    name: "2200_20092_8_not_null",
+                    expr: "username IS NOT NULL",
                     no_inherit: false,
                 },
             ],
@@ -2038,18 +1992,19 @@ Schema {
             unique_constraints: [],
             primary_key_constraints: [
                 PrimaryKey {
-                    name: "customer_pkey",
+                    name: "staff_pkey",
                     columns: [
-                        "customer_id",
+                        "staff_id",
                     ],
                 },
             ],
             reference_constraints: [
                 References {
-                    name: "customer_address_id_fkey",
+                    name: "staff_address_id_fkey",
                     columns: [
                         "address_id",
                     ],
+                    schema: None,
                     table: "address",
                     foreign_columns: [
                         "address_id",
@@ -2062,158 +2017,101 @@ Schema {
                     ),
                 },
                 References {
-                    name: "customer_store_id_fkey",
+                    name: "staff_store_id_fkey",
                     columns: [
                         "store_id",
                     ],
+                    schema: None,
                     table: "store",
                     foreign_columns: [
                         "store_id",
                     ],
                     on_update: Some(
-                        Cascade,
+                        NoAction,
                     ),
                     on_delete: Some(
-                        Restrict,
+                        NoAction,
                     ),
                 },
             ],
             exclusion_constraints: [],
+            row_level_security: RowLevelSecurity {
+                enabled: false,
+                forced: false,
+            },
+            policies: [],
         },
         TableDef {
             info: TableInfo {
-                name: "film_actor",
+                name: "payment",
                 of_type: None,
             },
             columns: [
                 ColumnInfo {
-                    name: "actor_id",
+                    name: "payment_id",
+                    col_type: Integer,
+                    default: Some(
+                        ColumnExpression(
+                            "nextval('payment_payment_id_seq'::regclass)",
+                        ),
+                    ),
+                    generated: None,
+                    not_null: Some(
+                        NotNull,
+                    ),
+                    is_identity: false,
+                },
+                ColumnInfo {
+                    name: "customer_id",
                     col_type: SmallInt,
                     default: None,
                     generated: None,
                     not_null: Some(
                         NotNull,
                     ),
+                    is_identity: false,
                 },
                 ColumnInfo {
-                    name: "film_id",
+                    name: "staff_id",
                     col_type: SmallInt,
                     default: None,
                     generated: None,
                     not_null: Some(
                         NotNull,
                     ),
+                    is_identity: false,
                 },
                 ColumnInfo {
-                    name: "last_update",
-                    col_type: Timestamp(
-                        TimeAttr {
-                            precision: Some(
-                                6,
-                            ),
-                        },
-                    ),
-                    default: Some(
-                        ColumnExpression(
-                            "now()",
-                        ),
-                    ),
+                    name: "rental_id",
+                    col_type: Integer,
+                    default: None,
                     generated: None,
                     not_null: Some(
                         NotNull,
                     ),
+                    is_identity: false,
                 },
-            ],
-            check_constraints: [
-                Check {
-                    name: "2200_16431_1_not_null",
-                    expr: "actor_id IS NOT NULL",
-                    no_inherit: false,
-                },
-                Check {
-                    name: "2200_16431_2_not_null",
-                    expr: "film_id IS NOT NULL",
-                    no_inherit: false,
-                },
-                Check {
-                    name: "2200_16431_3_not_null",
-                    expr: "last_update IS NOT NULL",
-                    no_inherit: false,
-                },
-            ],
-            not_null_constraints: [],
-            unique_constraints: [],
-            primary_key_constraints: [
-                PrimaryKey {
-                    name: "film_actor_pkey",
-                    columns: [
-                        "actor_id",
-                        "film_id",
-                    ],
-                },
-            ],
-            reference_constraints: [
-                References {
-                    name: "film_actor_actor_id_fkey",
-                    columns: [
-                        "actor_id",
-                    ],
-                    table: "actor",
-                    foreign_columns: [
-                        "actor_id",
-                    ],
-                    on_update: Some(
-                        Cascade,
-                    ),
-                    on_delete: Some(
-                        Restrict,
-                    ),
-                },
-                References {
-                    name: "film_actor_film_id_fkey",
-                    columns: [
-                        "film_id",
-                    ],
-                    table: "film",
-                    foreign_columns: [
-                        "film_id",
-                    ],
-                    on_update: Some(
-                        Cascade,
-                    ),
-                    on_delete: Some(
-                        Restrict,
-                    ),
-                },
-            ],
-            exclusion_constraints: [],
-        },
-        TableDef {
-            info: TableInfo {
-                name: "film_category",
-                of_type: None,
-            },
-            columns: [
                 ColumnInfo {
-                    name: "film_id",
-                    col_type: SmallInt,
-                    default: None,
-                    generated: None,
-                    not_null: Some(
-                        NotNull,
+                    name: "amount",
+                    col_type: Numeric(
+                        ArbitraryPrecisionNumericAttr {
+                            precision: Some(
+                                5,
+                            ),
+                            scale: Some(
+                                2,
+                            ),
+                        },
                     ),
-                },
-                ColumnInfo {
-                    name: "category_id",
-                    col_type: SmallInt,
                     default: None,
                     generated: None,
                     not_null: Some(
                         NotNull,
                     ),
+                    is_identity: false,
                 },
                 ColumnInfo {
-                    name: "last_update",
+                    name: "payment_date",
                     col_type: Timestamp(
                         TimeAttr {
                             precision: Some(
@@ -2221,31 +2119,43 @@ Schema {
                             ),
                         },
                     ),
-                    default: Some(
-                        ColumnExpression(
-                            "now()",
-                        ),
-                    ),
+                    default: None,
                     generated: None,
                     not_null: Some(
                         NotNull,
                     ),
+                    is_identity: false,
                 },
             ],
             check_constraints: [
                 Check {
-                    name: "2200_16435_1_not_null",
-                    expr: "film_id IS NOT NULL",
+                    name: "2200_20046_1_not_null",
+                    expr: "payment_id IS NOT NULL",
                     no_inherit: false,
                 },
                 Check {
-                    name: "2200_16435_2_not_null",
-                    expr: "category_id IS NOT NULL",
+                    name: "2200_20046_2_not_null",
+                    expr: "customer_id IS NOT NULL",
                     no_inherit: false,
                 },
                 Check {
-                    name: "2200_16435_3_not_null",
-                    expr: "last_update IS NOT NULL",
+                    name: "2200_20046_3_not_null",
+                    expr: "staff_id IS NOT NULL",
+                    no_inherit: false,
+                },
+                Check {
+                    name: "2200_20046_4_not_null",
+                    expr: "rental_id IS NOT NULL",
+                    no_inherit: false,
+                },
+                Check {
+                    name: "2200_20046_5_not_null",
+                    expr: "amount IS NOT NULL",
+                    no_inherit: false,
+                },
+                Check {
+                    name: "2200_20046_6_not_null",
+                    expr: "payment_date IS NOT NULL",
                     no_inherit: false,
                 },
             ],
@@ -2253,22 +2163,22 @@ Schema {
             unique_constraints: [],
             primary_key_constraints: [
                 PrimaryKey {
-                    name: "film_category_pkey",
+                    name: "payment_pkey",
                     columns: [
-                        "film_id",
-                        "category_id",
+                        "payment_id",
                     ],
                 },
             ],
             reference_constraints: [
                 References {
-                    name: "film_category_category_id_fkey",
+                    name: "payment_customer_id_fkey",
                     columns: [
-                        "category_id",
+                        "customer_id",
                     ],
-                    table: "category",
+                    schema: None,
+                    table: "customer",
                     foreign_columns: [
-                        "category_id",
+                        "customer_id",
                     ],
                     on_update: Some(
                         Cascade,
@@ -2278,13 +2188,31 @@ Schema {
                     ),
                 },
                 References {
-                    name: "film_category_film_id_fkey",
+                    name: "payment_rental_id_fkey",
                     columns: [
-                        "film_id",
+                        "rental_id",
                     ],
-                    table: "film",
+                    schema: None,
+                    table: "rental",
                     foreign_columns: [
-                        "film_id",
+                        "rental_id",
+                    ],
+                    on_update: Some(
+                        Cascade,
+                    ),
+                    on_delete: Some(
+                        SetNull,
+                    ),
+                },
+                References {
+                    name: "payment_staff_id_fkey",
+                    columns: [
+                        "staff_id",
+                    ],
+                    schema: None,
+                    table: "staff",
+                    foreign_columns: [
+                        "staff_id",
                     ],
                     on_update: Some(
                         Cascade,
@@ -2295,43 +2223,51 @@ Schema {
                 },
             ],
             exclusion_constraints: [],
+            row_level_security: RowLevelSecurity {
+                enabled: false,
+                forced: false,
+            },
+            policies: [],
         },
         TableDef {
             info: TableInfo {
-                name: "inventory",
+                name: "store",
                 of_type: None,
             },
             columns: [
                 ColumnInfo {
-                    name: "inventory_id",
+                    name: "store_id",
                     col_type: Integer,
                     default: Some(
                         ColumnExpression(
-                            "nextval(\'inventory_inventory_id_seq\'::regclass)",
+                            "nextval('store_store_id_seq'::regclass)",
                         ),
                     ),
                     generated: None,
                     not_null: Some(
                         NotNull,
                     ),
+                    is_identity: false,
                 },
                 ColumnInfo {
-                    name: "film_id",
+                    name: "manager_staff_id",
                     col_type: SmallInt,
                     default: None,
                     generated: None,
                     not_null: Some(
                         NotNull,
                     ),
+                    is_identity: false,
                 },
                 ColumnInfo {
-                    name: "store_id",
+                    name: "address_id",
                     col_type: SmallInt,
                     default: None,
                     generated: None,
                     not_null: Some(
                         NotNull,
                     ),
+                    is_identity: false,
                 },
                 ColumnInfo {
                     name: "last_update",
@@ -2351,49 +2287,58 @@ Schema {
                     not_null: Some(
                         NotNull,
                     ),
+                    is_identity: false,
                 },
             ],
             check_constraints: [
                 Check {
-                    name: "2200_16486_1_not_null",
-                    expr: "inventory_id IS NOT NULL",
+                    name: "2200_20101_1_not_null",
+                    expr: "store_id IS NOT NULL",
                     no_inherit: false,
                 },
                 Check {
-                    name: "2200_16486_2_not_null",
-                    expr: "film_id IS NOT NULL",
+                    name: "2200_20101_2_not_null",
+                    expr: "manager_staff_id IS NOT NULL",
                     no_inherit: false,
                 },
                 Check {
-                    name: "2200_16486_3_not_null",
-                    expr: "store_id IS NOT NULL",
+                    name: "2200_20101_3_not_null",
+                    expr: "address_id IS NOT NULL",
                     no_inherit: false,
                 },
                 Check {
-                    name: "2200_16486_4_not_null",
+                    name: "2200_20101_4_not_null",
                     expr: "last_update IS NOT NULL",
                     no_inherit: false,
                 },
             ],
             not_null_constraints: [],
-            unique_constraints: [],
+            unique_constraints: [
+                Unique {
+                    name: "idx_unq_manager_staff_id",
+                    columns: [
+                        "manager_staff_id",
+                    ],
+                },
+            ],
             primary_key_constraints: [
                 PrimaryKey {
-                    name: "inventory_pkey",
+                    name: "store_pkey",
                     columns: [
-                        "inventory_id",
+                        "store_id",
                     ],
                 },
             ],
             reference_constraints: [
                 References {
-                    name: "inventory_film_id_fkey",
+                    name: "store_address_id_fkey",
                     columns: [
-                        "film_id",
+                        "address_id",
                     ],
-                    table: "film",
+                    schema: None,
+                    table: "address",
                     foreign_columns: [
-                        "film_id",
+                        "address_id",
                     ],
                     on_update: Some(
                         Cascade,
@@ -2403,13 +2348,14 @@ Schema {
                     ),
                 },
                 References {
-                    name: "inventory_store_id_fkey",
+                    name: "store_manager_staff_id_fkey",
                     columns: [
-                        "store_id",
+                        "manager_staff_id",
                     ],
-                    table: "store",
+                    schema: None,
+                    table: "staff",
                     foreign_columns: [
-                        "store_id",
+                        "staff_id",
                     ],
                     on_update: Some(
                         Cascade,
@@ -2420,813 +2366,1532 @@ Schema {
                 },
             ],
             exclusion_constraints: [],
-        },
-        TableDef {
-            info: TableInfo {
-                name: "language",
-                of_type: None,
+            row_level_security: RowLevelSecurity {
+                enabled: false,
+                forced: false,
             },
-            columns: [
-                ColumnInfo {
-                    name: "language_id",
-                    col_type: Integer,
-                    default: Some(
-                        ColumnExpression(
-                            "nextval(\'language_language_id_seq\'::regclass)",
-                        ),
-                    ),
-                    generated: None,
-                    not_null: Some(
-                        NotNull,
-                    ),
-                },
-                ColumnInfo {
-                    name: "name",
-                    col_type: Char(
-                        StringAttr {
-                            length: Some(
-                                20,
-                            ),
-                        },
-                    ),
-                    default: None,
-                    generated: None,
-                    not_null: Some(
-                        NotNull,
-                    ),
-                },
-                ColumnInfo {
-                    name: "last_update",
-                    col_type: Timestamp(
-                        TimeAttr {
-                            precision: Some(
-                                6,
-                            ),
-                        },
-                    ),
-                    default: Some(
-                        ColumnExpression(
-                            "now()",
-                        ),
-                    ),
-                    generated: None,
-                    not_null: Some(
-                        NotNull,
-                    ),
-                },
-            ],
-            check_constraints: [
-                Check {
-                    name: "2200_16493_1_not_null",
-                    expr: "language_id IS NOT NULL",
-                    no_inherit: false,
-                },
-                Check {
-                    name: "2200_16493_2_not_null",
-                    expr: "name IS NOT NULL",
-                    no_inherit: false,
-                },
-                Check {
-                    name: "2200_16493_3_not_null",
-                    expr: "last_update IS NOT NULL",
-                    no_inherit: false,
-                },
-            ],
-            not_null_constraints: [],
-            unique_constraints: [],
-            primary_key_constraints: [
-                PrimaryKey {
-                    name: "language_pkey",
-                    columns: [
-                        "language_id",
-                    ],
-                },
-            ],
-            reference_constraints: [],
-            exclusion_constraints: [],
+            policies: [],
         },
-        TableDef {
-            info: TableInfo {
-                name: "rental",
-                of_type: None,
-            },
-            columns: [
-                ColumnInfo {
-                    name: "rental_id",
-                    col_type: Integer,
-                    default: Some(
-                        ColumnExpression(
-                            "nextval(\'rental_rental_id_seq\'::regclass)",
-                        ),
-                    ),
-                    generated: None,
-                    not_null: Some(
-                        NotNull,
-                    ),
-                },
-                ColumnInfo {
-                    name: "rental_date",
-                    col_type: Timestamp(
-                        TimeAttr {
-                            precision: Some(
-                                6,
-                            ),
-                        },
-                    ),
-                    default: None,
-                    generated: None,
-                    not_null: Some(
-                        NotNull,
-                    ),
-                },
-                ColumnInfo {
-                    name: "inventory_id",
-                    col_type: Integer,
-                    default: None,
-                    generated: None,
-                    not_null: Some(
-                        NotNull,
-                    ),
-                },
-                ColumnInfo {
-                    name: "customer_id",
-                    col_type: SmallInt,
-                    default: None,
-                    generated: None,
-                    not_null: Some(
-                        NotNull,
-                    ),
-                },
-                ColumnInfo {
-                    name: "return_date",
-                    col_type: Timestamp(
-                        TimeAttr {
-                            precision: Some(
-                                6,
-                            ),
-                        },
-                    ),
-                    default: None,
-                    generated: None,
-                    not_null: None,
-                },
-                ColumnInfo {
-                    name: "staff_id",
-                    col_type: SmallInt,
-                    default: None,
-                    generated: None,
-                    not_null: Some(
-                        NotNull,
-                    ),
-                },
-                ColumnInfo {
-                    name: "last_update",
-                    col_type: Timestamp(
-                        TimeAttr {
-                            precision: Some(
-                                6,
-                            ),
-                        },
-                    ),
-                    default: Some(
-                        ColumnExpression(
-                            "now()",
-                        ),
-                    ),
-                    generated: None,
-                    not_null: Some(
-                        NotNull,
-                    ),
-                },
-            ],
-            check_constraints: [
-                Check {
-                    name: "2200_16541_1_not_null",
-                    expr: "rental_id IS NOT NULL",
-                    no_inherit: false,
-                },
-                Check {
-                    name: "2200_16541_2_not_null",
-                    expr: "rental_date IS NOT NULL",
-                    no_inherit: false,
-                },
-                Check {
-                    name: "2200_16541_3_not_null",
-                    expr: "inventory_id IS NOT NULL",
-                    no_inherit: false,
-                },
-                Check {
-                    name: "2200_16541_4_not_null",
-                    expr: "customer_id IS NOT NULL",
-                    no_inherit: false,
-                },
-                Check {
-                    name: "2200_16541_6_not_null",
-                    expr: "staff_id IS NOT NULL",
-                    no_inherit: false,
-                },
-                Check {
-                    name: "2200_16541_7_not_null",
-                    expr: "last_update IS NOT NULL",
-                    no_inherit: false,
-                },
-            ],
-            not_null_constraints: [],
-            unique_constraints: [],
-            primary_key_constraints: [
-                PrimaryKey {
-                    name: "rental_pkey",
-                    columns: [
-                        "rental_id",
-                    ],
-                },
-            ],
-            reference_constraints: [
-                References {
-                    name: "rental_customer_id_fkey",
-                    columns: [
-                        "customer_id",
-                    ],
-                    table: "customer",
-                    foreign_columns: [
-                        "customer_id",
-                    ],
-                    on_update: Some(
-                        Cascade,
-                    ),
-                    on_delete: Some(
-                        Restrict,
-                    ),
-                },
-                References {
-                    name: "rental_inventory_id_fkey",
-                    columns: [
-                        "inventory_id",
-                    ],
-                    table: "inventory",
-                    foreign_columns: [
-                        "inventory_id",
-                    ],
-                    on_update: Some(
-                        Cascade,
-                    ),
-                    on_delete: Some(
-                        Restrict,
-                    ),
-                },
-                References {
-                    name: "rental_staff_id_fkey",
-                    columns: [
-                        "staff_id",
-                    ],
-                    table: "staff",
-                    foreign_columns: [
-                        "staff_id",
-                    ],
-                    on_update: Some(
-                        Cascade,
-                    ),
-                    on_delete: Some(
-                        Restrict,
-                    ),
-                },
-            ],
-            exclusion_constraints: [],
+    ],
+    privileges: [
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "actor",
+            column: None,
+            privilege: "DELETE",
+            grantable: false,
         },
-        TableDef {
-            info: TableInfo {
-                name: "staff",
-                of_type: None,
-            },
-            columns: [
-                ColumnInfo {
-                    name: "staff_id",
-                    col_type: Integer,
-                    default: Some(
-                        ColumnExpression(
-                            "nextval(\'staff_staff_id_seq\'::regclass)",
-                        ),
-                    ),
-                    generated: None,
-                    not_null: Some(
-                        NotNull,
-                    ),
-                },
-                ColumnInfo {
-                    name: "first_name",
-                    col_type: Varchar(
-                        StringAttr {
-                            length: Some(
-                                45,
-                            ),
-                        },
-                    ),
-                    default: None,
-                    generated: None,
-                    not_null: Some(
-                        NotNull,
-                    ),
-                },
-                ColumnInfo {
-                    name: "last_name",
-                    col_type: Varchar(
-                        StringAttr {
-                            length: Some(
-                                45,
-                            ),
-                        },
-                    ),
-                    default: None,
-                    generated: None,
-                    not_null: Some(
-                        NotNull,
-                    ),
-                },
-                ColumnInfo {
-                    name: "address_id",
-                    col_type: SmallInt,
-                    default: None,
-                    generated: None,
-                    not_null: Some(
-                        NotNull,
-                    ),
-                },
-                ColumnInfo {
-                    name: "email",
-                    col_type: Varchar(
-                        StringAttr {
-                            length: Some(
-                                50,
-                            ),
-                        },
-                    ),
-                    default: None,
-                    generated: None,
-                    not_null: None,
-                },
-                ColumnInfo {
-                    name: "store_id",
-                    col_type: SmallInt,
-                    default: None,
-                    generated: None,
-                    not_null: Some(
-                        NotNull,
-                    ),
-                },
-                ColumnInfo {
-                    name: "active",
-                    col_type: Boolean,
-                    default: Some(
-                        ColumnExpression(
-                            "true",
-                        ),
-                    ),
-                    generated: None,
-                    not_null: Some(
-                        NotNull,
-                    ),
-                },
-                ColumnInfo {
-                    name: "username",
-                    col_type: Varchar(
-                        StringAttr {
-                            length: Some(
-                                16,
-                            ),
-                        },
-                    ),
-                    default: None,
-                    generated: None,
-                    not_null: Some(
-                        NotNull,
-                    ),
-                },
-                ColumnInfo {
-                    name: "password",
-                    col_type: Varchar(
-                        StringAttr {
-                            length: Some(
-                                40,
-                            ),
-                        },
-                    ),
-                    default: None,
-                    generated: None,
-                    not_null: None,
-                },
-                ColumnInfo {
-                    name: "last_update",
-                    col_type: Timestamp(
-                        TimeAttr {
-                            precision: Some(
-                                6,
-                            ),
-                        },
-                    ),
-                    default: Some(
-                        ColumnExpression(
-                            "now()",
-                        ),
-                    ),
-                    generated: None,
-                    not_null: Some(
-                        NotNull,
-                    ),
-                },
-                ColumnInfo {
-                    name: "picture",
-                    col_type: Bytea,
-                    default: None,
-                    generated: None,
-                    not_null: None,
-                },
-            ],
-            check_constraints: [
-                Check {
-                    name: "2200_16553_10_not_null",
-                    expr: "last_update IS NOT NULL",
-                    no_inherit: false,
-                },
-                Check {
-                    name: "2200_16553_1_not_null",
-                    expr: "staff_id IS NOT NULL",
-                    no_inherit: false,
-                },
-                Check {
-                    name: "2200_16553_2_not_null",
-                    expr: "first_name IS NOT NULL",
-                    no_inherit: false,
-                },
-                Check {
-                    name: "2200_16553_3_not_null",
-                    expr: "last_name IS NOT NULL",
-                    no_inherit: false,
-                },
-                Check {
-                    name: "2200_16553_4_not_null",
-                    expr: "address_id IS NOT NULL",
-                    no_inherit: false,
-                },
-                Check {
-                    name: "2200_16553_6_not_null",
-                    expr: "store_id IS NOT NULL",
-                    no_inherit: false,
-                },
-                Check {
-                    name: "2200_16553_7_not_null",
-                    expr: "active IS NOT NULL",
-                    no_inherit: false,
-                },
-                Check {
-                    name: "2200_16553_8_not_null",
-                    expr: "username IS NOT NULL",
-                    no_inherit: false,
-                },
-            ],
-            not_null_constraints: [],
-            unique_constraints: [],
-            primary_key_constraints: [
-                PrimaryKey {
-                    name: "staff_pkey",
-                    columns: [
-                        "staff_id",
-                    ],
-                },
-            ],
-            reference_constraints: [
-                References {
-                    name: "staff_address_id_fkey",
-                    columns: [
-                        "address_id",
-                    ],
-                    table: "address",
-                    foreign_columns: [
-                        "address_id",
-                    ],
-                    on_update: Some(
-                        Cascade,
-                    ),
-                    on_delete: Some(
-                        Restrict,
-                    ),
-                },
-                References {
-                    name: "staff_store_id_fkey",
-                    columns: [
-                        "store_id",
-                    ],
-                    table: "store",
-                    foreign_columns: [
-                        "store_id",
-                    ],
-                    on_update: Some(
-                        NoAction,
-                    ),
-                    on_delete: Some(
-                        NoAction,
-                    ),
-                },
-            ],
-            exclusion_constraints: [],
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "actor",
+            column: None,
+            privilege: "INSERT",
+            grantable: false,
         },
-        TableDef {
-            info: TableInfo {
-                name: "store",
-                of_type: None,
-            },
-            columns: [
-                ColumnInfo {
-                    name: "store_id",
-                    col_type: Integer,
-                    default: Some(
-                        ColumnExpression(
-                            "nextval(\'store_store_id_seq\'::regclass)",
-                        ),
-                    ),
-                    generated: None,
-                    not_null: Some(
-                        NotNull,
-                    ),
-                },
-                ColumnInfo {
-                    name: "manager_staff_id",
-                    col_type: SmallInt,
-                    default: None,
-                    generated: None,
-                    not_null: Some(
-                        NotNull,
-                    ),
-                },
-                ColumnInfo {
-                    name: "address_id",
-                    col_type: SmallInt,
-                    default: None,
-                    generated: None,
-                    not_null: Some(
-                        NotNull,
-                    ),
-                },
-                ColumnInfo {
-                    name: "last_update",
-                    col_type: Timestamp(
-                        TimeAttr {
-                            precision: Some(
-                                6,
-                            ),
-                        },
-                    ),
-                    default: Some(
-                        ColumnExpression(
-                            "now()",
-                        ),
-                    ),
-                    generated: None,
-                    not_null: Some(
-                        NotNull,
-                    ),
-                },
-            ],
-            check_constraints: [
-                Check {
-                    name: "2200_16564_1_not_null",
-                    expr: "store_id IS NOT NULL",
-                    no_inherit: false,
-                },
-                Check {
-                    name: "2200_16564_2_not_null",
-                    expr: "manager_staff_id IS NOT NULL",
-                    no_inherit: false,
-                },
-                Check {
-                    name: "2200_16564_3_not_null",
-                    expr: "address_id IS NOT NULL",
-                    no_inherit: false,
-                },
-                Check {
-                    name: "2200_16564_4_not_null",
-                    expr: "last_update IS NOT NULL",
-                    no_inherit: false,
-                },
-            ],
-            not_null_constraints: [],
-            unique_constraints: [],
-            primary_key_constraints: [
-                PrimaryKey {
-                    name: "store_pkey",
-                    columns: [
-                        "store_id",
-                    ],
-                },
-            ],
-            reference_constraints: [
-                References {
-                    name: "store_address_id_fkey",
-                    columns: [
-                        "address_id",
-                    ],
-                    table: "address",
-                    foreign_columns: [
-                        "address_id",
-                    ],
-                    on_update: Some(
-                        Cascade,
-                    ),
-                    on_delete: Some(
-                        Restrict,
-                    ),
-                },
-                References {
-                    name: "store_manager_staff_id_fkey",
-                    columns: [
-                        "manager_staff_id",
-                    ],
-                    table: "staff",
-                    foreign_columns: [
-                        "staff_id",
-                    ],
-                    on_update: Some(
-                        Cascade,
-                    ),
-                    on_delete: Some(
-                        Restrict,
-                    ),
-                },
-            ],
-            exclusion_constraints: [],
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "actor",
+            column: None,
+            privilege: "REFERENCES",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "actor",
+            column: None,
+            privilege: "SELECT",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "actor",
+            column: None,
+            privilege: "TRIGGER",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "actor",
+            column: None,
+            privilege: "TRUNCATE",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "actor",
+            column: None,
+            privilege: "UPDATE",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "actor_info",
+            column: None,
+            privilege: "DELETE",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "actor_info",
+            column: None,
+            privilege: "INSERT",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "actor_info",
+            column: None,
+            privilege: "REFERENCES",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "actor_info",
+            column: None,
+            privilege: "SELECT",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "actor_info",
+            column: None,
+            privilege: "TRIGGER",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "actor_info",
+            column: None,
+            privilege: "TRUNCATE",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "actor_info",
+            column: None,
+            privilege: "UPDATE",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "address",
+            column: None,
+            privilege: "DELETE",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "address",
+            column: None,
+            privilege: "INSERT",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "address",
+            column: None,
+            privilege: "REFERENCES",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "address",
+            column: None,
+            privilege: "SELECT",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "address",
+            column: None,
+            privilege: "TRIGGER",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "address",
+            column: None,
+            privilege: "TRUNCATE",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "address",
+            column: None,
+            privilege: "UPDATE",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "category",
+            column: None,
+            privilege: "DELETE",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "category",
+            column: None,
+            privilege: "INSERT",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "category",
+            column: None,
+            privilege: "REFERENCES",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "category",
+            column: None,
+            privilege: "SELECT",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "category",
+            column: None,
+            privilege: "TRIGGER",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "category",
+            column: None,
+            privilege: "TRUNCATE",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "category",
+            column: None,
+            privilege: "UPDATE",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "city",
+            column: None,
+            privilege: "DELETE",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "city",
+            column: None,
+            privilege: "INSERT",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "city",
+            column: None,
+            privilege: "REFERENCES",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "city",
+            column: None,
+            privilege: "SELECT",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "city",
+            column: None,
+            privilege: "TRIGGER",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "city",
+            column: None,
+            privilege: "TRUNCATE",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "city",
+            column: None,
+            privilege: "UPDATE",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "country",
+            column: None,
+            privilege: "DELETE",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "country",
+            column: None,
+            privilege: "INSERT",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "country",
+            column: None,
+            privilege: "REFERENCES",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "country",
+            column: None,
+            privilege: "SELECT",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "country",
+            column: None,
+            privilege: "TRIGGER",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "country",
+            column: None,
+            privilege: "TRUNCATE",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "country",
+            column: None,
+            privilege: "UPDATE",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "customer",
+            column: None,
+            privilege: "DELETE",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "customer",
+            column: None,
+            privilege: "INSERT",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "customer",
+            column: None,
+            privilege: "REFERENCES",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "customer",
+            column: None,
+            privilege: "SELECT",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "customer",
+            column: None,
+            privilege: "TRIGGER",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "customer",
+            column: None,
+            privilege: "TRUNCATE",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "customer",
+            column: None,
+            privilege: "UPDATE",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "customer_list",
+            column: None,
+            privilege: "DELETE",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "customer_list",
+            column: None,
+            privilege: "INSERT",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "customer_list",
+            column: None,
+            privilege: "REFERENCES",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "customer_list",
+            column: None,
+            privilege: "SELECT",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "customer_list",
+            column: None,
+            privilege: "TRIGGER",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "customer_list",
+            column: None,
+            privilege: "TRUNCATE",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "customer_list",
+            column: None,
+            privilege: "UPDATE",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "film",
+            column: None,
+            privilege: "DELETE",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "film",
+            column: None,
+            privilege: "INSERT",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "film",
+            column: None,
+            privilege: "REFERENCES",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "film",
+            column: None,
+            privilege: "SELECT",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "film",
+            column: None,
+            privilege: "TRIGGER",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "film",
+            column: None,
+            privilege: "TRUNCATE",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "film",
+            column: None,
+            privilege: "UPDATE",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "root",
+            table: "film_1000",
+            column: None,
+            privilege: "DELETE",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "root",
+            table: "film_1000",
+            column: None,
+            privilege: "INSERT",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "root",
+            table: "film_1000",
+            column: None,
+            privilege: "REFERENCES",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "root",
+            table: "film_1000",
+            column: None,
+            privilege: "SELECT",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "root",
+            table: "film_1000",
+            column: None,
+            privilege: "TRIGGER",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "root",
+            table: "film_1000",
+            column: None,
+            privilege: "TRUNCATE",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "root",
+            table: "film_1000",
+            column: None,
+            privilege: "UPDATE",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "root",
+            table: "film_2000",
+            column: None,
+            privilege: "DELETE",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "root",
+            table: "film_2000",
+            column: None,
+            privilege: "INSERT",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "root",
+            table: "film_2000",
+            column: None,
+            privilege: "REFERENCES",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "root",
+            table: "film_2000",
+            column: None,
+            privilege: "SELECT",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "root",
+            table: "film_2000",
+            column: None,
+            privilege: "TRIGGER",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "root",
+            table: "film_2000",
+            column: None,
+            privilege: "TRUNCATE",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "root",
+            table: "film_2000",
+            column: None,
+            privilege: "UPDATE",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "root",
+            table: "film_3000",
+            column: None,
+            privilege: "DELETE",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "root",
+            table: "film_3000",
+            column: None,
+            privilege: "INSERT",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "root",
+            table: "film_3000",
+            column: None,
+            privilege: "REFERENCES",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "root",
+            table: "film_3000",
+            column: None,
+            privilege: "SELECT",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "root",
+            table: "film_3000",
+            column: None,
+            privilege: "TRIGGER",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "root",
+            table: "film_3000",
+            column: None,
+            privilege: "TRUNCATE",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "root",
+            table: "film_3000",
+            column: None,
+            privilege: "UPDATE",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "film_actor",
+            column: None,
+            privilege: "DELETE",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "film_actor",
+            column: None,
+            privilege: "INSERT",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "film_actor",
+            column: None,
+            privilege: "REFERENCES",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "film_actor",
+            column: None,
+            privilege: "SELECT",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "film_actor",
+            column: None,
+            privilege: "TRIGGER",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "film_actor",
+            column: None,
+            privilege: "TRUNCATE",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "film_actor",
+            column: None,
+            privilege: "UPDATE",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "film_category",
+            column: None,
+            privilege: "DELETE",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "film_category",
+            column: None,
+            privilege: "INSERT",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "film_category",
+            column: None,
+            privilege: "REFERENCES",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "film_category",
+            column: None,
+            privilege: "SELECT",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "film_category",
+            column: None,
+            privilege: "TRIGGER",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "film_category",
+            column: None,
+            privilege: "TRUNCATE",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "film_category",
+            column: None,
+            privilege: "UPDATE",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "film_list",
+            column: None,
+            privilege: "DELETE",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "film_list",
+            column: None,
+            privilege: "INSERT",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "film_list",
+            column: None,
+            privilege: "REFERENCES",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "film_list",
+            column: None,
+            privilege: "SELECT",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "film_list",
+            column: None,
+            privilege: "TRIGGER",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "film_list",
+            column: None,
+            privilege: "TRUNCATE",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "film_list",
+            column: None,
+            privilege: "UPDATE",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "inventory",
+            column: None,
+            privilege: "DELETE",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "inventory",
+            column: None,
+            privilege: "INSERT",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "inventory",
+            column: None,
+            privilege: "REFERENCES",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "inventory",
+            column: None,
+            privilege: "SELECT",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "inventory",
+            column: None,
+            privilege: "TRIGGER",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "inventory",
+            column: None,
+            privilege: "TRUNCATE",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "inventory",
+            column: None,
+            privilege: "UPDATE",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "language",
+            column: None,
+            privilege: "DELETE",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "language",
+            column: None,
+            privilege: "INSERT",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "language",
+            column: None,
+            privilege: "REFERENCES",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "language",
+            column: None,
+            privilege: "SELECT",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "language",
+            column: None,
+            privilege: "TRIGGER",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "language",
+            column: None,
+            privilege: "TRUNCATE",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "language",
+            column: None,
+            privilege: "UPDATE",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "nicer_but_slower_film_list",
+            column: None,
+            privilege: "DELETE",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "nicer_but_slower_film_list",
+            column: None,
+            privilege: "INSERT",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "nicer_but_slower_film_list",
+            column: None,
+            privilege: "REFERENCES",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "nicer_but_slower_film_list",
+            column: None,
+            privilege: "SELECT",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "nicer_but_slower_film_list",
+            column: None,
+            privilege: "TRIGGER",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "nicer_but_slower_film_list",
+            column: None,
+            privilege: "TRUNCATE",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "nicer_but_slower_film_list",
+            column: None,
+            privilege: "UPDATE",
+            grantable: false,
         },
-        TableDef {
-            info: TableInfo {
-                name: "payment",
-                of_type: None,
-            },
-            columns: [
-                ColumnInfo {
-                    name: "payment_id",
-                    col_type: Integer,
-                    default: Some(
-                        ColumnExpression(
-                            "nextval(\'payment_payment_id_seq\'::regclass)",
-                        ),
-                    ),
-                    generated: None,
-                    not_null: Some(
-                        NotNull,
-                    ),
-                },
-                ColumnInfo {
-                    name: "customer_id",
-                    col_type: SmallInt,
-                    default: None,
-                    generated: None,
-                    not_null: Some(
-                        NotNull,
-                    ),
-                },
-                ColumnInfo {
-                    name: "staff_id",
-                    col_type: SmallInt,
-                    default: None,
-                    generated: None,
-                    not_null: Some(
-                        NotNull,
-                    ),
-                },
-                ColumnInfo {
-                    name: "rental_id",
-                    col_type: Integer,
-                    default: None,
-                    generated: None,
-                    not_null: Some(
-                        NotNull,
-                    ),
-                },
-                ColumnInfo {
-                    name: "amount",
-                    col_type: Numeric(
-                        ArbitraryPrecisionNumericAttr {
-                            precision: Some(
-                                5,
-                            ),
-                            scale: Some(
-                                2,
-                            ),
-                        },
-                    ),
-                    default: None,
-                    generated: None,
-                    not_null: Some(
-                        NotNull,
-                    ),
-                },
-                ColumnInfo {
-                    name: "payment_date",
-                    col_type: Timestamp(
-                        TimeAttr {
-                            precision: Some(
-                                6,
-                            ),
-                        },
-                    ),
-                    default: None,
-                    generated: None,
-                    not_null: Some(
-                        NotNull,
-                    ),
-                },
-            ],
-            check_constraints: [
-                Check {
-                    name: "2200_16505_1_not_null",
-                    expr: "payment_id IS NOT NULL",
-                    no_inherit: false,
-                },
-                Check {
-                    name: "2200_16505_2_not_null",
-                    expr: "customer_id IS NOT NULL",
-                    no_inherit: false,
-                },
-                Check {
-                    name: "2200_16505_3_not_null",
-                    expr: "staff_id IS NOT NULL",
-                    no_inherit: false,
-                },
-                Check {
-                    name: "2200_16505_4_not_null",
-                    expr: "rental_id IS NOT NULL",
-                    no_inherit: false,
-                },
-                Check {
-                    name: "2200_16505_5_not_null",
-                    expr: "amount IS NOT NULL",
-                    no_inherit: false,
-                },
-                Check {
-                    name: "2200_16505_6_not_null",
-                    expr: "payment_date IS NOT NULL",
-                    no_inherit: false,
-                },
-            ],
-            not_null_constraints: [],
-            unique_constraints: [],
-            primary_key_constraints: [
-                PrimaryKey {
-                    name: "payment_pkey",
-                    columns: [
-                        "payment_id",
-                    ],
-                },
-            ],
-            reference_constraints: [
-                References {
-                    name: "payment_customer_id_fkey",
-                    columns: [
-                        "customer_id",
-                    ],
-                    table: "customer",
-                    foreign_columns: [
-                        "customer_id",
-                    ],
-                    on_update: Some(
-                        Cascade,
-                    ),
-                    on_delete: Some(
-                        Restrict,
-                    ),
-                },
-                References {
-                    name: "payment_rental_id_fkey",
-                    columns: [
-                        "rental_id",
-                    ],
-                    table: "rental",
-                    foreign_columns: [
-                        "rental_id",
-                    ],
-                    on_update: Some(
-                        Cascade,
-                    ),
-                    on_delete: Some(
-                        SetNull,
-                    ),
-                },
-                References {
-                    name: "payment_staff_id_fkey",
-                    columns: [
-                        "staff_id",
-                    ],
-                    table: "staff",
-                    foreign_columns: [
-                        "staff_id",
-                    ],
-                    on_update: Some(
-                        Cascade,
-                    ),
-                    on_delete: Some(
-                        Restrict,
-                    ),
-                },
-            ],
-            exclusion_constraints: [],
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "payment",
+            column: None,
+            privilege: "DELETE",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "payment",
+            column: None,
+            privilege: "INSERT",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "payment",
+            column: None,
+            privilege: "REFERENCES",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "payment",
+            column: None,
+            privilege: "SELECT",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "payment",
+            column: None,
+            privilege: "TRIGGER",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "payment",
+            column: None,
+            privilege: "TRUNCATE",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "payment",
+            column: None,
+            privilege: "UPDATE",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "payment_p2007_01",
+            column: None,
+            privilege: "DELETE",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "payment_p2007_01",
+            column: None,
+            privilege: "INSERT",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "payment_p2007_01",
+            column: None,
+            privilege: "REFERENCES",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "payment_p2007_01",
+            column: None,
+            privilege: "SELECT",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "payment_p2007_01",
+            column: None,
+            privilege: "TRIGGER",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "payment_p2007_01",
+            column: None,
+            privilege: "TRUNCATE",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "payment_p2007_01",
+            column: None,
+            privilege: "UPDATE",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "payment_p2007_02",
+            column: None,
+            privilege: "DELETE",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "payment_p2007_02",
+            column: None,
+            privilege: "INSERT",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "payment_p2007_02",
+            column: None,
+            privilege: "REFERENCES",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "payment_p2007_02",
+            column: None,
+            privilege: "SELECT",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "payment_p2007_02",
+            column: None,
+            privilege: "TRIGGER",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "payment_p2007_02",
+            column: None,
+            privilege: "TRUNCATE",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "payment_p2007_02",
+            column: None,
+            privilege: "UPDATE",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "payment_p2007_03",
+            column: None,
+            privilege: "DELETE",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "payment_p2007_03",
+            column: None,
+            privilege: "INSERT",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "payment_p2007_03",
+            column: None,
+            privilege: "REFERENCES",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "payment_p2007_03",
+            column: None,
+            privilege: "SELECT",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "payment_p2007_03",
+            column: None,
+            privilege: "TRIGGER",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "payment_p2007_03",
+            column: None,
+            privilege: "TRUNCATE",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "payment_p2007_03",
+            column: None,
+            privilege: "UPDATE",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "payment_p2007_04",
+            column: None,
+            privilege: "DELETE",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "payment_p2007_04",
+            column: None,
+            privilege: "INSERT",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "payment_p2007_04",
+            column: None,
+            privilege: "REFERENCES",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "payment_p2007_04",
+            column: None,
+            privilege: "SELECT",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "payment_p2007_04",
+            column: None,
+            privilege: "TRIGGER",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "payment_p2007_04",
+            column: None,
+            privilege: "TRUNCATE",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "payment_p2007_04",
+            column: None,
+            privilege: "UPDATE",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "payment_p2007_05",
+            column: None,
+            privilege: "DELETE",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "payment_p2007_05",
+            column: None,
+            privilege: "INSERT",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "payment_p2007_05",
+            column: None,
+            privilege: "REFERENCES",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "payment_p2007_05",
+            column: None,
+            privilege: "SELECT",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "payment_p2007_05",
+            column: None,
+            privilege: "TRIGGER",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "payment_p2007_05",
+            column: None,
+            privilege: "TRUNCATE",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "payment_p2007_05",
+            column: None,
+            privilege: "UPDATE",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "payment_p2007_06",
+            column: None,
+            privilege: "DELETE",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "payment_p2007_06",
+            column: None,
+            privilege: "INSERT",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "payment_p2007_06",
+            column: None,
+            privilege: "REFERENCES",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "payment_p2007_06",
+            column: None,
+            privilege: "SELECT",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "payment_p2007_06",
+            column: None,
+            privilege: "TRIGGER",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "payment_p2007_06",
+            column: None,
+            privilege: "TRUNCATE",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "payment_p2007_06",
+            column: None,
+            privilege: "UPDATE",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "rental",
+            column: None,
+            privilege: "DELETE",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "rental",
+            column: None,
+            privilege: "INSERT",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "rental",
+            column: None,
+            privilege: "REFERENCES",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "rental",
+            column: None,
+            privilege: "SELECT",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "rental",
+            column: None,
+            privilege: "TRIGGER",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "rental",
+            column: None,
+            privilege: "TRUNCATE",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "rental",
+            column: None,
+            privilege: "UPDATE",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "sales_by_film_category",
+            column: None,
+            privilege: "DELETE",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "sales_by_film_category",
+            column: None,
+            privilege: "INSERT",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "sales_by_film_category",
+            column: None,
+            privilege: "REFERENCES",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "sales_by_film_category",
+            column: None,
+            privilege: "SELECT",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "sales_by_film_category",
+            column: None,
+            privilege: "TRIGGER",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "sales_by_film_category",
+            column: None,
+            privilege: "TRUNCATE",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "sales_by_film_category",
+            column: None,
+            privilege: "UPDATE",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "sales_by_store",
+            column: None,
+            privilege: "DELETE",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "sales_by_store",
+            column: None,
+            privilege: "INSERT",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "sales_by_store",
+            column: None,
+            privilege: "REFERENCES",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "sales_by_store",
+            column: None,
+            privilege: "SELECT",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "sales_by_store",
+            column: None,
+            privilege: "TRIGGER",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "sales_by_store",
+            column: None,
+            privilege: "TRUNCATE",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "sales_by_store",
+            column: None,
+            privilege: "UPDATE",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "staff",
+            column: None,
+            privilege: "DELETE",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "staff",
+            column: None,
+            privilege: "INSERT",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "staff",
+            column: None,
+            privilege: "REFERENCES",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "staff",
+            column: None,
+            privilege: "SELECT",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "staff",
+            column: None,
+            privilege: "TRIGGER",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "staff",
+            column: None,
+            privilege: "TRUNCATE",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "staff",
+            column: None,
+            privilege: "UPDATE",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "staff_list",
+            column: None,
+            privilege: "DELETE",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "staff_list",
+            column: None,
+            privilege: "INSERT",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "staff_list",
+            column: None,
+            privilege: "REFERENCES",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "staff_list",
+            column: None,
+            privilege: "SELECT",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "staff_list",
+            column: None,
+            privilege: "TRIGGER",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "staff_list",
+            column: None,
+            privilege: "TRUNCATE",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "staff_list",
+            column: None,
+            privilege: "UPDATE",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "store",
+            column: None,
+            privilege: "DELETE",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "store",
+            column: None,
+            privilege: "INSERT",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "store",
+            column: None,
+            privilege: "REFERENCES",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "store",
+            column: None,
+            privilege: "SELECT",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "store",
+            column: None,
+            privilege: "TRIGGER",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "store",
+            column: None,
+            privilege: "TRUNCATE",
+            grantable: false,
+        },
+        PrivilegeInfo {
+            grantee: "postgres",
+            table: "store",
+            column: None,
+            privilege: "UPDATE",
+            grantable: false,
         },
     ],
 }
@@ -15,7 +15,7 @@ async fn main() {
 
     let schema_discovery = SchemaDiscovery::new(connection, "public");
 
-    let schema = schema_discovery.discover().await;
+    let schema = schema_discovery.discover().await.unwrap();
 
     // println!("{}", serde_json::to_string_pretty(&schema).unwrap());
 
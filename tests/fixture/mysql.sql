@@ -0,0 +1,13 @@
+CREATE TABLE `bakery` (
+    `id` int NOT NULL AUTO_INCREMENT PRIMARY KEY,
+    `name` varchar(255) NOT NULL,
+    `profit_margin` double
+);
+
+CREATE TABLE `cake` (
+    `id` int NOT NULL AUTO_INCREMENT PRIMARY KEY,
+    `name` varchar(255) NOT NULL UNIQUE,
+    `price` decimal(16, 4),
+    `bakery_id` int,
+    CONSTRAINT `fk-cake-bakery` FOREIGN KEY (`bakery_id`) REFERENCES `bakery` (`id`) ON DELETE CASCADE
+);
@@ -0,0 +1,15 @@
+CREATE TYPE "mood" AS ENUM ('happy', 'sad');
+
+CREATE TABLE "bakery" (
+    "id" serial PRIMARY KEY,
+    "name" varchar(255) NOT NULL,
+    "profit_margin" double precision
+);
+
+CREATE TABLE "cake" (
+    "id" serial PRIMARY KEY,
+    "name" text NOT NULL UNIQUE,
+    "price" numeric(16, 4) CHECK ("price" > 0),
+    "mood" "mood",
+    "bakery_id" integer REFERENCES "bakery" ("id") ON DELETE CASCADE
+);
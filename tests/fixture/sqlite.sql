@@ -0,0 +1,14 @@
+CREATE TABLE "bakery" (
+    "id" integer NOT NULL PRIMARY KEY AUTOINCREMENT,
+    "name" varchar(255) NOT NULL,
+    "profit_margin" real
+);
+
+CREATE TABLE "cake" (
+    "id" integer NOT NULL PRIMARY KEY AUTOINCREMENT,
+    "name" text NOT NULL UNIQUE,
+    "price" decimal(16, 4) CHECK ("price" > 0),
+    "bakery_id" integer REFERENCES "bakery" ("id") ON DELETE CASCADE
+);
+
+CREATE INDEX "idx-cake-price" ON "cake" ("price");
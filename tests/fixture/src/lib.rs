@@ -0,0 +1,256 @@
+//! Replay the discovery of the schema in `<backend>.sql` from the fixture in `<backend>.json`,
+//! without a database. See the Readme to record the fixtures again
+
+#[cfg(test)]
+mod tests {
+    use sea_schema::sea_query::{MysqlQueryBuilder, PostgresQueryBuilder, SqliteQueryBuilder};
+    use sea_schema::{Fixture, FixtureExecutor, SchemaRow, SchemaValue};
+
+    fn load_fixture(backend: &str) -> Fixture {
+        let path = format!("{}/{}.json", env!("CARGO_MANIFEST_DIR"), backend);
+        Fixture::load(path).unwrap()
+    }
+
+    fn load(backend: &str) -> FixtureExecutor {
+        FixtureExecutor::new(load_fixture(backend))
+    }
+
+    #[async_std::test]
+    async fn test_mysql() {
+        use sea_schema::mysql::discovery::SchemaDiscovery;
+
+        let schema = SchemaDiscovery::new(load("mysql"), "fixture")
+            .discover()
+            .await
+            .unwrap();
+
+        assert_eq!(schema.schema, "fixture");
+        assert_eq!(schema.system.version, 80036);
+        assert!(schema.privileges.is_empty());
+        assert_eq!(
+            schema
+                .tables
+                .iter()
+                .map(|table| table.write().to_string(MysqlQueryBuilder))
+                .collect::<Vec<_>>(),
+            [
+                [
+                    "CREATE TABLE `bakery` (",
+                    "`id` int NOT NULL AUTO_INCREMENT,",
+                    "`name` varchar(255) NOT NULL,",
+                    "`profit_margin` double,",
+                    "PRIMARY KEY (`id`)",
+                    ")",
+                    "ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_0900_ai_ci",
+                ]
+                .join(" "),
+                [
+                    "CREATE TABLE `cake` (",
+                    "`id` int NOT NULL AUTO_INCREMENT,",
+                    "`name` varchar(255) NOT NULL,",
+                    "`price` decimal(16, 4),",
+                    "`bakery_id` int,",
+                    "KEY `fk-cake-bakery` (`bakery_id`),",
+                    "UNIQUE KEY `name` (`name`),",
+                    "PRIMARY KEY (`id`),",
+                    "CONSTRAINT `fk-cake-bakery` FOREIGN KEY (`bakery_id`) REFERENCES `bakery` (`id`)",
+                    "ON DELETE CASCADE ON UPDATE NO ACTION",
+                    ")",
+                    "ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_0900_ai_ci",
+                ]
+                .join(" "),
+            ]
+        );
+    }
+
+    #[async_std::test]
+    async fn test_mysql_server() {
+        use futures::future::BoxFuture;
+        use sea_schema::mysql::discovery::{ExecutorError, SchemaExecutor, ServerDiscovery};
+        use sea_schema::sea_query::SelectStatement;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        /// Counts the queries of the server version
+        struct VersionCounter {
+            executor: FixtureExecutor,
+            versions: AtomicUsize,
+        }
+
+        impl SchemaExecutor for VersionCounter {
+            fn fetch_all(
+                &self,
+                select: SelectStatement,
+            ) -> BoxFuture<'_, Result<Vec<SchemaRow>, ExecutorError>> {
+                if select.to_string(MysqlQueryBuilder).contains("version()") {
+                    self.versions.fetch_add(1, Ordering::SeqCst);
+                }
+                self.executor.fetch_all(select)
+            }
+        }
+
+        let executor = VersionCounter {
+            executor: load("mysql"),
+            versions: AtomicUsize::new(0),
+        };
+        let (server, warnings) = ServerDiscovery::new(&executor)
+            .with_table_filter(sea_schema::TableFilter::new().include(["cake"]))
+            .discover_lenient()
+            .await
+            .unwrap();
+
+        // The version is discovered once for the server, not once per schema
+        assert_eq!(executor.versions.load(Ordering::SeqCst), 1);
+        assert!(warnings.is_empty());
+        assert_eq!(server.system.version, 80036);
+        assert_eq!(server.schemas.len(), 1);
+        let schema = &server.schemas[0];
+        assert_eq!(schema.info.name, "fixture");
+        assert_eq!(schema.schema.system, server.system);
+        assert_eq!(schema.schema.tables.len(), 1);
+        assert_eq!(schema.schema.tables[0].info.name, "cake");
+    }
+
+    #[async_std::test]
+    async fn test_postgres() {
+        use sea_schema::postgres::def::{EnumDef, Type};
+        use sea_schema::postgres::discovery::SchemaDiscovery;
+
+        let schema = SchemaDiscovery::new(load("postgres"), "public")
+            .discover()
+            .await
+            .unwrap();
+
+        assert_eq!(schema.schema, "public");
+        assert_eq!(schema.extensions.len(), 1);
+        assert_eq!(schema.extensions[0].name, "plpgsql");
+        assert!(schema.write_extensions().is_empty());
+        assert_eq!(schema.privileges.len(), 14);
+
+        let cake = &schema.tables[1];
+        assert_eq!(
+            cake.columns[3].col_type,
+            Type::Enum(EnumDef {
+                values: vec!["happy".to_owned(), "sad".to_owned()],
+                typename: "mood".to_owned(),
+            })
+        );
+        assert!(cake
+            .check_constraints
+            .iter()
+            .any(|check| check.name == "cake_price_check"
+                && check.expr == "((price > (0)::numeric))"));
+
+        assert_eq!(
+            schema
+                .write()
+                .iter()
+                .map(|table| table.to_string(PostgresQueryBuilder))
+                .collect::<Vec<_>>(),
+            [
+                [
+                    r#"CREATE TABLE "bakery" ("#,
+                    r#""id" serial NOT NULL,"#,
+                    r#""name" varchar(255) NOT NULL,"#,
+                    r#""profit_margin" double precision,"#,
+                    r#"CONSTRAINT "bakery_pkey" PRIMARY KEY ("id")"#,
+                    r#")"#,
+                ]
+                .join(" "),
+                [
+                    r#"CREATE TABLE "cake" ("#,
+                    r#""id" serial NOT NULL,"#,
+                    r#""name" text NOT NULL,"#,
+                    r#""price" decimal(16, 4),"#,
+                    r#""mood" mood,"#,
+                    r#""bakery_id" integer,"#,
+                    r#"CONSTRAINT "cake_pkey" PRIMARY KEY ("id"),"#,
+                    r#"CONSTRAINT "cake_name_key" UNIQUE ("name"),"#,
+                    r#"CONSTRAINT "cake_bakery_id_fkey" FOREIGN KEY ("bakery_id") REFERENCES "bakery" ("id")"#,
+                    r#"ON DELETE CASCADE ON UPDATE NO ACTION"#,
+                    r#")"#,
+                ]
+                .join(" "),
+            ]
+        );
+    }
+
+    #[async_std::test]
+    async fn test_sqlite() {
+        use sea_schema::sqlite::discovery::SchemaDiscovery;
+
+        let schema = SchemaDiscovery::new(load("sqlite"))
+            .discover()
+            .await
+            .unwrap();
+
+        assert!(schema.virtual_tables.is_empty());
+        assert_eq!(
+            schema
+                .tables
+                .iter()
+                .map(|table| table.write().to_string(SqliteQueryBuilder))
+                .collect::<Vec<_>>(),
+            [
+                [
+                    r#"CREATE TABLE "bakery" ("#,
+                    r#""id" integer NOT NULL PRIMARY KEY AUTOINCREMENT,"#,
+                    r#""name" varchar(255) NOT NULL,"#,
+                    r#""profit_margin" real"#,
+                    r#")"#,
+                ]
+                .join(" "),
+                [
+                    r#"CREATE TABLE "cake" ("#,
+                    r#""id" integer NOT NULL PRIMARY KEY AUTOINCREMENT,"#,
+                    r#""name" text NOT NULL,"#,
+                    r#""price" real(16, 4),"#,
+                    r#""bakery_id" integer,"#,
+                    r#"UNIQUE ("name"),"#,
+                    r#"FOREIGN KEY ("bakery_id") REFERENCES "bakery" ("id") ON DELETE CASCADE ON UPDATE NO ACTION,"#,
+                    r#"CHECK ("price" > 0)"#,
+                    r#")"#,
+                ]
+                .join(" "),
+            ]
+        );
+        assert_eq!(
+            schema
+                .indexes
+                .iter()
+                .map(|index| index.write().to_string(SqliteQueryBuilder))
+                .collect::<Vec<_>>(),
+            [r#"CREATE INDEX "idx-cake-price" ON "cake" ("price")"#]
+        );
+    }
+
+    #[async_std::test]
+    async fn test_sqlite_lenient() {
+        use sea_schema::sqlite::discovery::SchemaDiscovery;
+
+        // A column of `cake` whose `cid` is not an integer
+        let mut fixture = load_fixture("sqlite");
+        let query = fixture
+            .queries
+            .iter_mut()
+            .find(|query| query.sql.contains("pragma_table_xinfo('cake'"))
+            .unwrap();
+        let mut values = query.rows[0].values().to_vec();
+        values[0] = SchemaValue::String("id".to_owned());
+        query.rows[0] = SchemaRow::new(values);
+
+        // A strict discovery running at the same time fails, and doesn't warn
+        let schema_discovery = SchemaDiscovery::new(FixtureExecutor::new(fixture));
+        let (strict, lenient) = futures::future::join(
+            schema_discovery.discover(),
+            schema_discovery.discover_lenient(),
+        )
+        .await;
+        assert!(strict.is_err());
+
+        let (schema, warnings) = lenient.unwrap();
+        assert_eq!(schema.tables.len(), 1);
+        assert_eq!(schema.tables[0].name, "bakery");
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].table.as_deref(), Some("cake"));
+    }
+}
@@ -1,10 +1,14 @@
 use pretty_assertions::assert_eq;
 use regex::Regex;
-use sea_schema::mysql::{def::TableDef, discovery::SchemaDiscovery};
+use sea_schema::mysql::{
+    def::{CharSet, TableDef},
+    discovery::{SchemaDiscovery, ServerDiscovery},
+};
 use sea_schema::sea_query::{
     Alias, ColumnDef, Expr, ForeignKey, ForeignKeyAction, Index, MysqlQueryBuilder, Table,
     TableCreateStatement, TableRef,
 };
+use sea_schema::TableFilter;
 use sqlx::{MySql, MySqlPool, Pool};
 use std::collections::HashMap;
 
@@ -42,7 +46,7 @@ async fn main() {
         sqlx::query(&sql).execute(&mut *executor).await.unwrap();
     }
 
-    let schema_discovery = SchemaDiscovery::new(connection, "sea-schema");
+    let schema_discovery = SchemaDiscovery::new(connection.clone(), "sea-schema");
 
     let schema = schema_discovery
         .discover()
@@ -73,6 +77,77 @@ async fn main() {
         println!();
         assert_eq!(expected_sql, sql);
     }
+
+    // A table of another schema references `bakery`
+    for sql in [
+        "DROP DATABASE IF EXISTS `sea-schema-franchise`",
+        "CREATE DATABASE `sea-schema-franchise` CHARACTER SET latin1 COLLATE latin1_swedish_ci",
+        "CREATE TABLE `sea-schema-franchise`.`outlet` (`id` int PRIMARY KEY, `bakery_id` int, CONSTRAINT `fk-outlet-bakery` FOREIGN KEY (`bakery_id`) REFERENCES `sea-schema`.`bakery` (`id`))",
+    ] {
+        sqlx::query(sql).execute(&mut *executor).await.unwrap();
+    }
+
+    let server = ServerDiscovery::new(connection.clone())
+        .with_filter(TableFilter::new().include_glob(["sea-schema*"]))
+        .discover()
+        .await
+        .expect("Error discovering server");
+    let names: Vec<_> = server
+        .schemas
+        .iter()
+        .map(|schema| schema.info.name.as_str())
+        .collect();
+    assert_eq!(names, ["sea-schema", "sea-schema-franchise"]);
+
+    let franchise = &server.schemas[1];
+    assert_eq!(franchise.info.char_set, CharSet::Latin1);
+    let outlet = &franchise.schema.tables[0];
+    assert_eq!(
+        outlet.foreign_keys[0].referenced_schema.as_deref(),
+        Some("sea-schema")
+    );
+    let sql = outlet.write().to_string(MysqlQueryBuilder);
+    assert!(
+        sql.contains("REFERENCES `sea-schema`.`bakery` (`id`)"),
+        "{}",
+        sql
+    );
+
+    // The table filter applies to every schema
+    let (server, warnings) = ServerDiscovery::new(connection.clone())
+        .with_filter(TableFilter::new().include_glob(["sea-schema*"]))
+        .with_table_filter(TableFilter::new().include(["outlet", "bakery"]))
+        .discover_lenient()
+        .await
+        .expect("Error discovering server");
+    assert!(warnings.is_empty(), "{:?}", warnings);
+    let names: Vec<_> = server
+        .schemas
+        .iter()
+        .flat_map(|schema| &schema.schema.tables)
+        .map(|table| table.info.name.as_str())
+        .collect();
+    assert_eq!(names, ["bakery", "outlet"]);
+
+    // Only the tables passing the filter and the predicate are discovered
+    let schema = SchemaDiscovery::new(connection, "sea-schema")
+        .with_filter(
+            TableFilter::new()
+                .include_glob(["ba*", "c*"])
+                .exclude(["cake"])
+                .exclude_glob(["*_bakers"]),
+        )
+        .with_table_predicate(|info| info.name != "bakery")
+        .discover()
+        .await
+        .expect("Error discovering schema");
+    let mut names: Vec<_> = schema
+        .tables
+        .iter()
+        .map(|table| table.info.name.as_str())
+        .collect();
+    names.sort();
+    assert_eq!(names, ["baker", "child", "customer"]);
 }
 
 fn strip_generated_sql(mut sql: String) -> String {
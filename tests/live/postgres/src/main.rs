@@ -1,10 +1,14 @@
 use pretty_assertions::assert_eq;
-use sea_schema::postgres::{def::TableDef, discovery::SchemaDiscovery};
+use sea_schema::postgres::{
+    def::{self, ArrayDef, EnumDef, TableDef},
+    discovery::{DatabaseDiscovery, SchemaDiscovery},
+};
 use sea_schema::sea_query::TableRef;
 use sea_schema::sea_query::{
     extension::postgres::Type, Alias, ColumnDef, ColumnType, Expr, ForeignKey, ForeignKeyAction,
-    Index, PostgresQueryBuilder, Table, TableCreateStatement,
+    Index, PostgresQueryBuilder, RcOrArc, Table, TableCreateStatement,
 };
+use sea_schema::TableFilter;
 use sqlx::{PgPool, Pool, Postgres};
 use std::collections::HashMap;
 
@@ -66,7 +70,23 @@ async fn main() {
         sqlx::query(&sql).execute(&mut *executor).await.unwrap();
     }
 
-    let schema_discovery = SchemaDiscovery::new(connection, "public");
+    let rls_stmts = vec![
+        r#"ALTER TABLE "customer" ENABLE ROW LEVEL SECURITY"#.to_owned(),
+        r#"CREATE POLICY "customer_with_notes" ON "customer" AS PERMISSIVE FOR SELECT TO PUBLIC USING ((notes IS NOT NULL))"#.to_owned(),
+    ];
+
+    let grant_stmts = vec![
+        r#"GRANT SELECT ON "customer" TO PUBLIC"#.to_owned(),
+        r#"GRANT UPDATE ("notes") ON "customer" TO PUBLIC"#.to_owned(),
+    ];
+
+    for sql in rls_stmts.iter().chain(grant_stmts.iter()) {
+        println!("{};", sql);
+        println!();
+        sqlx::query(sql).execute(&mut *executor).await.unwrap();
+    }
+
+    let schema_discovery = SchemaDiscovery::new(connection.clone(), "public");
 
     let schema = schema_discovery
         .discover()
@@ -97,6 +117,16 @@ async fn main() {
         assert_eq!(expected_sql, sql);
     }
 
+    assert_eq!(
+        map.get("customer").unwrap().write_row_level_security(),
+        rls_stmts
+    );
+
+    let privileges = schema.write_privileges();
+    for sql in grant_stmts.iter() {
+        assert!(privileges.contains(sql), "missing {}", sql);
+    }
+
     let enum_defs = schema_discovery
         .discover_enums()
         .await
@@ -113,6 +143,105 @@ async fn main() {
     dbg!(&enum_create_statements);
 
     assert_eq!(create_enum_stmt, enum_create_statements[0]);
+
+    // A table of another schema references `bakery`
+    for sql in [
+        r#"CREATE SCHEMA "franchise""#,
+        // An enum of the same name as one of `public`, with other values
+        r#"CREATE TYPE "franchise"."crazy_enum" AS ENUM ('Open', 'Closed')"#,
+        r#"CREATE TABLE "franchise"."outlet" ("id" integer PRIMARY KEY, "bakery_id" integer, "status" "franchise"."crazy_enum", "statuses" "franchise"."crazy_enum"[], CONSTRAINT "fk-outlet-bakery" FOREIGN KEY ("bakery_id") REFERENCES "public"."bakery" ("id"))"#,
+    ] {
+        sqlx::query(sql).execute(&mut *executor).await.unwrap();
+    }
+
+    let database = DatabaseDiscovery::new(connection.clone())
+        .discover()
+        .await
+        .expect("Error discovering database");
+    let names: Vec<_> = database
+        .schemas
+        .iter()
+        .map(|schema| schema.schema.as_str())
+        .collect();
+    assert_eq!(names, ["franchise", "public"]);
+    // The extensions belong to the database, not to each of its schemas
+    assert!(database
+        .extensions
+        .iter()
+        .any(|extension| extension.name == "plpgsql"));
+    assert!(database
+        .schemas
+        .iter()
+        .all(|schema| schema.extensions.is_empty()));
+
+    let outlet = &database.schemas[0].tables[0];
+    assert_eq!(
+        outlet.reference_constraints[0].schema.as_deref(),
+        Some("public")
+    );
+    // The enum is resolved in the schema of the column
+    let franchise_enum = def::Type::Enum(EnumDef {
+        values: vec!["Closed".to_owned(), "Open".to_owned()],
+        typename: "crazy_enum".to_owned(),
+    });
+    assert_eq!(outlet.columns[2].col_type, franchise_enum);
+    assert_eq!(
+        outlet.columns[3].col_type,
+        def::Type::Array(ArrayDef {
+            col_type: Some(RcOrArc::new(franchise_enum)),
+            dimensions: 1,
+        })
+    );
+    let sql = database.write()[0].to_string(PostgresQueryBuilder);
+    assert!(
+        sql.starts_with(r#"CREATE TABLE "franchise"."outlet""#),
+        "{}",
+        sql
+    );
+    assert!(
+        sql.contains(r#"REFERENCES "public"."bakery" ("id")"#),
+        "{}",
+        sql
+    );
+
+    // The table filter applies to every schema
+    let (database, warnings) = DatabaseDiscovery::new(connection.clone())
+        .with_table_filter(TableFilter::new().include(["outlet", "bakery"]))
+        .discover_lenient()
+        .await
+        .expect("Error discovering database");
+    assert!(warnings.is_empty(), "{:?}", warnings);
+    let names: Vec<_> = database
+        .schemas
+        .iter()
+        .flat_map(|schema| &schema.tables)
+        .map(|table| table.info.name.as_str())
+        .collect();
+    assert_eq!(names, ["outlet", "bakery"]);
+
+    // Only the tables passing the filter and the predicate are discovered
+    let schema = SchemaDiscovery::new(connection, "public")
+        .with_filter(
+            TableFilter::new()
+                .include_glob(["ba*", "c*"])
+                .exclude(["cake"])
+                .exclude_glob(["*_bakers"]),
+        )
+        .with_table_predicate(|info| info.name != "bakery")
+        .discover()
+        .await
+        .expect("Error discovering schema");
+    let mut names: Vec<_> = schema
+        .tables
+        .iter()
+        .map(|table| table.info.name.as_str())
+        .collect();
+    names.sort();
+    assert_eq!(names, ["baker", "child", "collection", "customer"]);
+    assert!(schema
+        .privileges
+        .iter()
+        .all(|privilege| names.contains(&privilege.table.as_str())));
 }
 
 async fn setup(base_url: &str, db_name: &str) -> Pool<Postgres> {
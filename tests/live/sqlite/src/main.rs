@@ -1,16 +1,21 @@
+use futures::future::BoxFuture;
 use pretty_assertions::assert_eq;
 use sqlx::sqlite::SqlitePoolOptions;
 use sqlx::SqlitePool;
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
 
 use sea_schema::sea_query::{
-    Alias, ColumnDef, Expr, ForeignKey, ForeignKeyAction, ForeignKeyCreateStatement, Index, Query,
-    SqliteQueryBuilder, Table, TableCreateStatement, TableRef,
+    Alias, ColumnDef, Expr, ForeignKey, ForeignKeyAction, ForeignKeyCreateStatement, Index,
+    Keyword, Query, SelectStatement, SqliteQueryBuilder, Table, TableCreateStatement, TableRef,
 };
 use sea_schema::sqlite::{
-    def::TableDef,
-    discovery::{DiscoveryResult, SchemaDiscovery},
+    def::{Affinity, Deferrable, MatchAction, TableDef},
+    discovery::{DiscoveryResult, ExecutorError, SchemaDiscovery, SchemaExecutor},
 };
+use sea_schema::TableFilter;
+use sea_schema::{Fixture, FixtureExecutor, FixtureRecorder, SchemaRow, SchemaValue};
 
 #[cfg_attr(test, async_std::test)]
 #[cfg_attr(not(test), async_std::main)]
@@ -22,6 +27,14 @@ async fn main() -> DiscoveryResult<()> {
 
     test_001().await?;
     test_002().await?;
+    test_003().await?;
+    test_004().await?;
+    test_005().await?;
+    test_006().await?;
+    test_007().await?;
+    test_008().await?;
+    test_009().await?;
+    test_010().await?;
 
     Ok(())
 }
@@ -98,6 +111,13 @@ async fn test_001() -> DiscoveryResult<()> {
         .col(Alias::new("SemVer"))
         .to_owned();
 
+    let create_partial_index = [
+        r#"CREATE INDEX "idx-suppliers-name" ON "suppliers""#,
+        r#"(lower("supplier_name") COLLATE NOCASE, "group_id" DESC)"#,
+        r#"WHERE "group_id" IS NOT NULL"#,
+    ]
+    .join(" ");
+
     // Create a table with a PRIMARY KEY constraint that results in an index.
     let create_table_inventors = Table::create()
         .table(Alias::new("Inventors"))
@@ -205,6 +225,11 @@ async fn test_001() -> DiscoveryResult<()> {
         .await
         .unwrap();
 
+    sqlx::query(&create_partial_index)
+        .fetch_all(&mut *sqlite_pool.acquire().await.unwrap())
+        .await
+        .unwrap();
+
     let schema = SchemaDiscovery::new(sqlite_pool.clone()).discover().await?;
 
     let expected_sql = [
@@ -225,9 +250,12 @@ async fn test_001() -> DiscoveryResult<()> {
         assert_eq!(sql, expected_sql[i]);
     }
 
-    let expected_sql = [create_index.to_string(SqliteQueryBuilder)]
-        .into_iter()
-        .collect::<Vec<_>>();
+    let expected_sql = [
+        create_index.to_string(SqliteQueryBuilder),
+        create_partial_index,
+    ]
+    .into_iter()
+    .collect::<Vec<_>>();
     assert_eq!(schema.indexes.len(), expected_sql.len());
 
     for (i, index) in schema.indexes.into_iter().enumerate() {
@@ -258,6 +286,9 @@ async fn test_002() -> DiscoveryResult<()> {
         create_parent_table(),
         create_child_table(),
         create_strange_table(),
+        create_product_table(),
+        create_setting_table(),
+        create_event_table(),
     ];
 
     for tbl_create_stmt in tbl_create_stmts.iter() {
@@ -322,6 +353,559 @@ async fn test_002() -> DiscoveryResult<()> {
         assert_eq!(expected_sql, sql);
     }
 
+    let strange = map.get("strange").unwrap();
+    let affinities: Vec<_> = strange
+        .columns
+        .iter()
+        .filter(|column| ["int2", "string2", "decimal2", "binary2"].contains(&column.name.as_str()))
+        .map(|column| (column.declared_type.as_str(), column.affinity))
+        .collect();
+    assert_eq!(
+        affinities,
+        [
+            ("tinyint", Affinity::Integer),
+            ("varchar(24)", Affinity::Text),
+            ("real(12, 4)", Affinity::Real),
+            ("blob(1024)", Affinity::Blob),
+        ]
+    );
+
+    Ok(())
+}
+
+async fn test_003() -> DiscoveryResult<()> {
+    // Databases are attached per connection
+    let sqlite_pool = SqlitePoolOptions::new()
+        .max_connections(1)
+        .connect("sqlite::memory:")
+        .await
+        .unwrap();
+
+    let create_main_table = create_bakery_table();
+    let create_temp_table = Table::create()
+        .table(Alias::new("session"))
+        .col(ColumnDef::new(Alias::new("token")).text().not_null())
+        .primary_key(Index::create().col(Alias::new("token")))
+        .to_owned();
+    let create_shard_table = create_customer_table();
+    let create_shard_index = Index::create()
+        .name("idx-customer-name")
+        .table(Alias::new("customer"))
+        .col(Alias::new("name"))
+        .to_owned();
+
+    for sql in [
+        "ATTACH DATABASE ':memory:' AS \"shard 1\"".to_owned(),
+        create_main_table.to_string(SqliteQueryBuilder),
+        create_temp_table.to_string(SqliteQueryBuilder).replacen(
+            "CREATE TABLE",
+            "CREATE TEMP TABLE",
+            1,
+        ),
+        create_shard_table.to_string(SqliteQueryBuilder).replacen(
+            r#""customer""#,
+            r#""shard 1"."customer""#,
+            1,
+        ),
+        create_shard_index.to_string(SqliteQueryBuilder).replacen(
+            r#""idx-customer-name""#,
+            r#""shard 1"."idx-customer-name""#,
+            1,
+        ),
+    ] {
+        sqlx::query(&sql).execute(&sqlite_pool).await.unwrap();
+    }
+
+    let schemas = SchemaDiscovery::new(sqlite_pool).discover_all().await?;
+
+    let names: Vec<_> = schemas.iter().map(|schema| schema.name.as_str()).collect();
+    assert_eq!(names, ["main", "temp", "shard 1"]);
+
+    for (schema, table, indexes) in [
+        (&schemas[0], create_main_table, vec![]),
+        (&schemas[1], create_temp_table, vec![]),
+        (&schemas[2], create_shard_table, vec![create_shard_index]),
+    ] {
+        assert_eq!(schema.tables.len(), 1);
+        let sql = schema.tables[0].write().to_string(SqliteQueryBuilder);
+        assert_eq!(sql, table.to_string(SqliteQueryBuilder));
+        println!("[OK] {} {sql}", schema.name);
+
+        let sql: Vec<_> = schema
+            .indexes
+            .iter()
+            .map(|index| index.write().to_string(SqliteQueryBuilder))
+            .collect();
+        let expected_sql: Vec<_> = indexes
+            .iter()
+            .map(|index| index.to_string(SqliteQueryBuilder))
+            .collect();
+        assert_eq!(sql, expected_sql);
+    }
+
+    Ok(())
+}
+
+async fn test_004() -> DiscoveryResult<()> {
+    let sqlite_pool = SqlitePoolOptions::new()
+        .connect("sqlite::memory:")
+        .await
+        .unwrap();
+
+    let create_virtual_tables = [
+        r#"CREATE VIRTUAL TABLE "docs" USING fts5(title, body UNINDEXED, tokenize = 'porter ascii')"#,
+        r#"CREATE VIRTUAL TABLE "bounds" USING rtree(id, min_x, max_x, min_y, max_y)"#,
+    ];
+    let create_table = Table::create()
+        .table(Alias::new("docs_meta"))
+        .col(ColumnDef::new(Alias::new("doc_id")).integer().not_null())
+        .primary_key(Index::create().col(Alias::new("doc_id")))
+        .to_owned();
+
+    for sql in create_virtual_tables {
+        sqlx::query(sql).execute(&sqlite_pool).await.unwrap();
+    }
+    sqlx::query(&create_table.to_string(SqliteQueryBuilder))
+        .execute(&sqlite_pool)
+        .await
+        .unwrap();
+
+    let schema = SchemaDiscovery::new(sqlite_pool).discover().await?;
+
+    let names: Vec<_> = schema
+        .tables
+        .iter()
+        .map(|table| table.name.as_str())
+        .collect();
+    assert_eq!(names, ["docs_meta"]);
+    assert_eq!(
+        schema.tables[0].write().to_string(SqliteQueryBuilder),
+        create_table.to_string(SqliteQueryBuilder)
+    );
+
+    let names: Vec<_> = schema
+        .virtual_tables
+        .iter()
+        .map(|table| table.name.as_str())
+        .collect();
+    assert_eq!(names, ["docs", "bounds"]);
+    for (table, expected_sql) in schema.virtual_tables.iter().zip(create_virtual_tables) {
+        let sql = table.write();
+        assert_eq!(sql, expected_sql);
+        println!("[OK] {sql}");
+    }
+    let hidden: Vec<_> = schema.virtual_tables[0]
+        .columns
+        .iter()
+        .filter(|column| column.hidden)
+        .map(|column| column.name.as_str())
+        .collect();
+    assert_eq!(hidden, ["docs", "rank"]);
+
+    Ok(())
+}
+
+async fn test_005() -> DiscoveryResult<()> {
+    let sqlite_pool = SqlitePoolOptions::new()
+        .connect("sqlite::memory:")
+        .await
+        .unwrap();
+
+    let create_tables = [
+        r#"CREATE TABLE "warehouse" ("id" integer NOT NULL PRIMARY KEY, "code" text NOT NULL, UNIQUE ("id", "code"))"#,
+        r#"CREATE TABLE "shipment" (
+            "id" integer NOT NULL PRIMARY KEY,
+            "warehouse_id" integer NOT NULL,
+            "warehouse_code" text NOT NULL,
+            "origin_id" integer CONSTRAINT "fk-shipment-origin" REFERENCES "warehouse" ("id")
+                ON DELETE SET NULL MATCH FULL DEFERRABLE INITIALLY DEFERRED,
+            CONSTRAINT "fk-shipment-warehouse" FOREIGN KEY ("warehouse_id", "warehouse_code")
+                REFERENCES "warehouse" ("id", "code") ON DELETE CASCADE
+        )"#,
+    ];
+    for sql in create_tables {
+        sqlx::query(sql).execute(&sqlite_pool).await.unwrap();
+    }
+
+    let schema = SchemaDiscovery::new(sqlite_pool.clone()).discover().await?;
+    let shipment = schema
+        .tables
+        .iter()
+        .find(|table| table.name == "shipment")
+        .unwrap();
+
+    let origin = shipment
+        .foreign_keys
+        .iter()
+        .find(|fk| fk.from == ["origin_id"])
+        .unwrap();
+    assert_eq!(origin.name.as_deref(), Some("fk-shipment-origin"));
+    assert_eq!(origin.r#match, MatchAction::Full);
+    assert_eq!(origin.deferrable, Some(Deferrable::InitiallyDeferred));
+
+    let warehouse = shipment
+        .foreign_keys
+        .iter()
+        .find(|fk| fk.from.len() == 2)
+        .unwrap();
+    assert_eq!(warehouse.name.as_deref(), Some("fk-shipment-warehouse"));
+    assert_eq!(warehouse.deferrable, None);
+
+    // The composite foreign key is written as a table constraint to keep its name
+    let sql = shipment.write_sql();
+    let expected_sql = [
+        r#"CREATE TABLE "shipment" ("#,
+        r#""id" integer NOT NULL,"#,
+        r#""warehouse_id" integer NOT NULL,"#,
+        r#""warehouse_code" text NOT NULL,"#,
+        r#""origin_id" integer CONSTRAINT "fk-shipment-origin" REFERENCES "warehouse" ("id") ON DELETE SET NULL ON UPDATE NO ACTION MATCH FULL DEFERRABLE INITIALLY DEFERRED,"#,
+        r#"PRIMARY KEY ("id"),"#,
+        r#"CONSTRAINT "fk-shipment-warehouse" FOREIGN KEY ("warehouse_id", "warehouse_code") REFERENCES "warehouse" ("id", "code") ON DELETE CASCADE ON UPDATE NO ACTION"#,
+        r#")"#,
+    ]
+    .join(" ");
+    assert_eq!(sql, expected_sql);
+    println!("[OK] {sql}");
+
+    // The written statement is valid and discovered the same way
+    sqlx::query("DROP TABLE shipment")
+        .execute(&sqlite_pool)
+        .await
+        .unwrap();
+    sqlx::query(&sql).execute(&sqlite_pool).await.unwrap();
+    let schema = SchemaDiscovery::new(sqlite_pool.clone()).discover().await?;
+    let shipment = schema
+        .tables
+        .iter()
+        .find(|table| table.name == "shipment")
+        .unwrap();
+    assert_eq!(shipment.write_sql(), expected_sql);
+
+    // Only the tables passing the filter are discovered
+    let schema = SchemaDiscovery::new(sqlite_pool.clone())
+        .with_filter(TableFilter::new().include(["shipment"]))
+        .discover()
+        .await?;
+    let names: Vec<_> = schema
+        .tables
+        .iter()
+        .map(|table| table.name.as_str())
+        .collect();
+    assert_eq!(names, ["shipment"]);
+
+    let schema = SchemaDiscovery::new(sqlite_pool.clone())
+        .with_filter(TableFilter::new().exclude(["shipment"]))
+        .discover()
+        .await?;
+    let names: Vec<_> = schema
+        .tables
+        .iter()
+        .map(|table| table.name.as_str())
+        .collect();
+    assert_eq!(names, ["warehouse"]);
+
+    // Globs are case sensitive, although SQLite's LIKE is not
+    for (glob, expected) in [("*house", vec!["warehouse"]), ("WARE*", vec![])] {
+        let schema = SchemaDiscovery::new(sqlite_pool.clone())
+            .with_filter(TableFilter::new().include_glob([glob]))
+            .discover()
+            .await?;
+        let names: Vec<_> = schema
+            .tables
+            .iter()
+            .map(|table| table.name.as_str())
+            .collect();
+        assert_eq!(names, expected);
+    }
+
+    Ok(())
+}
+
+async fn test_006() -> DiscoveryResult<()> {
+    // Names that break a pragma built by concatenation
+    let sqlite_pool = SqlitePoolOptions::new()
+        .max_connections(1)
+        .connect("sqlite::memory:")
+        .await
+        .unwrap();
+
+    let parent = "it's";
+    let child = "child'); DROP TABLE \"it's\"; --";
+    let create_parent_table = Table::create()
+        .table(Alias::new(parent))
+        .col(
+            ColumnDef::new(Alias::new("id"))
+                .integer()
+                .not_null()
+                .auto_increment()
+                .primary_key(),
+        )
+        .col(ColumnDef::new(Alias::new("o'clock")).text().not_null())
+        .index(Index::create().unique().col(Alias::new("o'clock")))
+        .to_owned();
+    let create_child_table = Table::create()
+        .table(Alias::new(child))
+        .col(ColumnDef::new(Alias::new("it's_id")).integer().not_null())
+        .col(ColumnDef::new(Alias::new("\"quoted\"")).text())
+        .foreign_key(
+            ForeignKey::create()
+                .from(Alias::new(child), Alias::new("it's_id"))
+                .to(Alias::new(parent), Alias::new("id"))
+                .on_delete(ForeignKeyAction::Cascade)
+                .on_update(ForeignKeyAction::Cascade),
+        )
+        .to_owned();
+    let create_child_index = Index::create()
+        .name("idx'); --")
+        .table(Alias::new(child))
+        .col(Alias::new("\"quoted\""))
+        .to_owned();
+
+    for sql in [
+        r#"ATTACH DATABASE ':memory:' AS "a'b""#.to_owned(),
+        create_parent_table.to_string(SqliteQueryBuilder),
+        create_child_table.to_string(SqliteQueryBuilder).replacen(
+            "CREATE TABLE ",
+            r#"CREATE TABLE "a'b"."#,
+            1,
+        ),
+        create_child_index.to_string(SqliteQueryBuilder).replacen(
+            "CREATE INDEX ",
+            r#"CREATE INDEX "a'b"."#,
+            1,
+        ),
+    ] {
+        sqlx::query(&sql).execute(&sqlite_pool).await.unwrap();
+    }
+
+    let schema_discovery = SchemaDiscovery::new(sqlite_pool);
+    let main = schema_discovery.discover().await?;
+    assert_eq!(main.tables.len(), 1);
+    assert_eq!(
+        main.tables[0].write().to_string(SqliteQueryBuilder),
+        create_parent_table.to_string(SqliteQueryBuilder)
+    );
+
+    // The foreign key references a table in another database, which SQLite allows
+    // but does not enforce; the discovery only has to report it
+    let attached = schema_discovery.discover_schema("a'b").await?;
+    assert_eq!(attached.tables.len(), 1);
+    let table = &attached.tables[0];
+    assert_eq!(table.name, child);
+    let columns: Vec<_> = table
+        .columns
+        .iter()
+        .map(|column| column.name.as_str())
+        .collect();
+    assert_eq!(columns, ["it's_id", "\"quoted\""]);
+    assert_eq!(
+        table.write().to_string(SqliteQueryBuilder),
+        create_child_table.to_string(SqliteQueryBuilder)
+    );
+    let indexes: Vec<_> = attached
+        .indexes
+        .iter()
+        .map(|index| index.write().to_string(SqliteQueryBuilder))
+        .collect();
+    assert_eq!(indexes, [create_child_index.to_string(SqliteQueryBuilder)]);
+    println!("[OK] {}", table.write().to_string(SqliteQueryBuilder));
+
+    Ok(())
+}
+
+/// Wraps a pool to count the queries the discovery runs
+struct CountingExecutor {
+    pool: SqlitePool,
+    queries: Arc<AtomicUsize>,
+}
+
+impl SchemaExecutor for CountingExecutor {
+    fn fetch_all(
+        &self,
+        select: SelectStatement,
+    ) -> BoxFuture<'_, Result<Vec<SchemaRow>, ExecutorError>> {
+        self.queries.fetch_add(1, Ordering::SeqCst);
+        self.pool.fetch_all(select)
+    }
+
+    fn fetch_all_raw(&self, sql: String) -> BoxFuture<'_, Result<Vec<SchemaRow>, ExecutorError>> {
+        self.queries.fetch_add(1, Ordering::SeqCst);
+        self.pool.fetch_all_raw(sql)
+    }
+}
+
+async fn test_007() -> DiscoveryResult<()> {
+    let sqlite_pool = SqlitePoolOptions::new()
+        .connect("sqlite::memory:")
+        .await
+        .unwrap();
+
+    let create_table = create_bakery_table();
+    sqlx::query(&create_table.to_string(SqliteQueryBuilder))
+        .execute(&sqlite_pool)
+        .await
+        .unwrap();
+
+    let queries = Arc::new(AtomicUsize::new(0));
+    let executor = CountingExecutor {
+        pool: sqlite_pool,
+        queries: queries.clone(),
+    };
+    let schema = SchemaDiscovery::new(executor).discover().await?;
+
+    assert_eq!(
+        schema.tables[0].write().to_string(SqliteQueryBuilder),
+        create_table.to_string(SqliteQueryBuilder)
+    );
+    assert!(queries.load(Ordering::SeqCst) > 0);
+    println!("[OK] {} queries", queries.load(Ordering::SeqCst));
+
+    Ok(())
+}
+
+async fn test_008() -> DiscoveryResult<()> {
+    let sqlite_pool = SqlitePoolOptions::new()
+        .connect("sqlite::memory:")
+        .await
+        .unwrap();
+
+    // Tables created in a transaction and temp tables are only visible to its connection
+    let mut transaction = sqlite_pool.begin().await.unwrap();
+    let create_table = create_bakery_table();
+    let create_temp_table = Table::create()
+        .table(Alias::new("staging"))
+        .col(ColumnDef::new(Alias::new("id")).integer().not_null())
+        .to_owned();
+    for sql in [
+        create_table.to_string(SqliteQueryBuilder),
+        create_temp_table.to_string(SqliteQueryBuilder).replacen(
+            "CREATE TABLE",
+            "CREATE TEMP TABLE",
+            1,
+        ),
+    ] {
+        sqlx::query(&sql).execute(&mut *transaction).await.unwrap();
+    }
+
+    let schema_discovery = SchemaDiscovery::new(&mut transaction);
+    let schema = schema_discovery.discover().await?;
+    let temp = schema_discovery.discover_schema("temp").await?;
+    drop(schema_discovery);
+
+    assert_eq!(schema.tables.len(), 1);
+    assert_eq!(
+        schema.tables[0].write().to_string(SqliteQueryBuilder),
+        create_table.to_string(SqliteQueryBuilder)
+    );
+    assert_eq!(temp.tables.len(), 1);
+    assert_eq!(
+        temp.tables[0].write().to_string(SqliteQueryBuilder),
+        create_temp_table.to_string(SqliteQueryBuilder)
+    );
+    println!("[OK] discovered in a transaction");
+
+    transaction.rollback().await.unwrap();
+    let schema = SchemaDiscovery::new(sqlite_pool).discover().await?;
+    assert!(schema.tables.is_empty());
+
+    Ok(())
+}
+
+async fn test_009() -> DiscoveryResult<()> {
+    let sqlite_pool = SqlitePoolOptions::new()
+        .connect("sqlite::memory:")
+        .await
+        .unwrap();
+
+    let create_table = create_bakery_table();
+    let create_index = Index::create()
+        .name("idx-bakery-name")
+        .table(Alias::new("bakery"))
+        .col(Alias::new("name"))
+        .to_owned();
+    for sql in [
+        create_table.to_string(SqliteQueryBuilder),
+        create_index.to_string(SqliteQueryBuilder),
+    ] {
+        sqlx::query(&sql).execute(&sqlite_pool).await.unwrap();
+    }
+
+    let recorder = FixtureRecorder::new(sqlite_pool);
+    let schema = SchemaDiscovery::new(&recorder).discover().await?;
+    let fixture = serde_json::to_string(&recorder.into_fixture()).unwrap();
+
+    // Replay the discovery without a database
+    let fixture: Fixture = serde_json::from_str(&fixture).unwrap();
+    let schema_discovery = SchemaDiscovery::new(FixtureExecutor::new(fixture));
+    let replayed = schema_discovery.discover().await?;
+
+    assert_eq!(replayed.tables.len(), 1);
+    assert_eq!(
+        replayed.tables[0].write().to_string(SqliteQueryBuilder),
+        schema.tables[0].write().to_string(SqliteQueryBuilder)
+    );
+    assert_eq!(
+        replayed.indexes[0].write().to_string(SqliteQueryBuilder),
+        create_index.to_string(SqliteQueryBuilder)
+    );
+    assert!(schema_discovery.discover_schema("other").await.is_err());
+    println!("[OK] replayed from a fixture");
+
+    Ok(())
+}
+
+async fn test_010() -> DiscoveryResult<()> {
+    let sqlite_pool = SqlitePoolOptions::new()
+        .connect("sqlite::memory:")
+        .await
+        .unwrap();
+
+    let create_table = create_bakery_table();
+    for sql in [
+        create_table.to_string(SqliteQueryBuilder),
+        create_cake_table().to_string(SqliteQueryBuilder),
+    ] {
+        sqlx::query(&sql).execute(&sqlite_pool).await.unwrap();
+    }
+
+    // Corrupt a column of `cake`, so that it can't be discovered
+    let recorder = FixtureRecorder::new(sqlite_pool);
+    SchemaDiscovery::new(&recorder).discover().await?;
+    let fixture = recorder.into_fixture();
+    let mut corrupted = fixture.clone();
+    let query = corrupted
+        .queries
+        .iter_mut()
+        .find(|query| query.sql.contains("pragma_table_xinfo('cake'"))
+        .unwrap();
+    let mut values = query.rows[0].values().to_vec();
+    values[0] = SchemaValue::String("id".to_owned());
+    query.rows[0] = SchemaRow::new(values);
+
+    let schema_discovery = SchemaDiscovery::new(FixtureExecutor::new(corrupted));
+    assert!(schema_discovery.discover().await.is_err());
+
+    let (schema, warnings) = schema_discovery.discover_lenient().await?;
+    assert_eq!(schema.tables.len(), 1);
+    assert_eq!(
+        schema.tables[0].write().to_string(SqliteQueryBuilder),
+        create_table.to_string(SqliteQueryBuilder)
+    );
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0].table.as_deref(), Some("cake"));
+    println!("[OK] {}", warnings[0]);
+
+    // Strict again afterwards
+    assert!(schema_discovery.discover().await.is_err());
+
+    // A failing executor fails a lenient discovery too
+    let mut lost = fixture;
+    lost.queries.retain(|query| !query.sql.contains("'cake'"));
+    assert!(SchemaDiscovery::new(FixtureExecutor::new(lost))
+        .discover_lenient()
+        .await
+        .is_err());
+
     Ok(())
 }
 
@@ -602,3 +1186,84 @@ fn create_strange_table() -> TableCreateStatement {
         .col(ColumnDef::new(Alias::new("binary3")).var_binary(1024))
         .to_owned()
 }
+
+fn create_product_table() -> TableCreateStatement {
+    Table::create()
+        .table(Alias::new("product"))
+        .col(
+            ColumnDef::new(Alias::new("id"))
+                .integer()
+                .not_null()
+                .auto_increment()
+                .primary_key(),
+        )
+        .col(
+            ColumnDef::new(Alias::new("code"))
+                .text()
+                .not_null()
+                .extra("COLLATE NOCASE"),
+        )
+        .col(ColumnDef::new(Alias::new("price")).double().not_null())
+        .col(ColumnDef::new(Alias::new("quantity")).integer().not_null())
+        .col(
+            ColumnDef::new(Alias::new("total"))
+                .double()
+                .generated(Expr::cust(r#""price" * "quantity""#), true),
+        )
+        .col(
+            ColumnDef::new(Alias::new("label"))
+                .text()
+                .generated(Expr::cust(r#"upper("code")"#), false),
+        )
+        .check(Expr::cust(r#""price" >= 0"#))
+        .check(Expr::cust(r#"length("code") BETWEEN 1 AND 16"#))
+        .to_owned()
+}
+
+fn create_setting_table() -> TableCreateStatement {
+    Table::create()
+        .table(Alias::new("setting"))
+        .col(ColumnDef::new(Alias::new("scope")).text().not_null())
+        .col(ColumnDef::new(Alias::new("key")).text().not_null())
+        .col(ColumnDef::new(Alias::new("value")).custom(Alias::new("ANY")))
+        .primary_key(
+            Index::create()
+                .col(Alias::new("scope"))
+                .col(Alias::new("key")),
+        )
+        .extra("WITHOUT ROWID, STRICT")
+        .to_owned()
+}
+
+fn create_event_table() -> TableCreateStatement {
+    Table::create()
+        .table(Alias::new("event"))
+        .col(
+            ColumnDef::new(Alias::new("id"))
+                .big_integer()
+                .default(9007199254740993i64),
+        )
+        .col(ColumnDef::new(Alias::new("ratio")).double().default(0.1f64))
+        .col(ColumnDef::new(Alias::new("label")).text().default("it's"))
+        .col(
+            ColumnDef::new(Alias::new("magic"))
+                .blob()
+                .default(vec![0xcau8, 0xfe]),
+        )
+        .col(
+            ColumnDef::new(Alias::new("day"))
+                .date()
+                .default(Keyword::CurrentDate),
+        )
+        .col(
+            ColumnDef::new(Alias::new("at"))
+                .time()
+                .default(Keyword::CurrentTime),
+        )
+        .col(
+            ColumnDef::new(Alias::new("created"))
+                .date_time()
+                .default(Expr::cust("(datetime('now', 'localtime'))")),
+        )
+        .to_owned()
+}
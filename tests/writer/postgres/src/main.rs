@@ -21,6 +21,11 @@ async fn main() {
         .await
         .expect("Error discovering schema");
 
+    for extension in schema.write_extensions() {
+        println!("{};", extension.to_string(PostgresQueryBuilder));
+        println!();
+    }
+
     for table in schema.tables.iter() {
         println!("{};", table.write().to_string(PostgresQueryBuilder));
         println!();